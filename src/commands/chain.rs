@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use crate::commands::sub_process::current_files;
+use crate::error::AppResult;
+use crate::traits::app_execute::AppExecute;
+use crate::types::command::Command;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::state::AppState;
+use crate::ui::AppBackend;
+
+/// How a command in a sequence is joined to the command before it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChainOperator {
+    // `;`: always run
+    Always,
+    // `&&`: only run if all commands since the last `;` succeeded
+    OnSuccess,
+}
+
+impl ChainOperator {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Always => ";",
+            Self::OnSuccess => "&&",
+        }
+    }
+}
+
+/// Variables that are substituted in command arguments right before execution
+pub const VARIABLES: [&str; 3] = ["%cwd", "%selected", "%tab_index"];
+
+pub fn contains_variables(s: &str) -> bool {
+    VARIABLES.iter().any(|var| s.contains(var))
+}
+
+pub fn expand_variables(app_state: &AppState, s: &str) -> String {
+    let tab_state = app_state.state.tab_state_ref();
+    let mut s = s.to_string();
+    if s.contains("%cwd") {
+        let cwd = tab_state.curr_tab_ref().get_cwd().to_string_lossy();
+        s = s.replace("%cwd", &shell_words::quote(&cwd));
+    }
+    if s.contains("%selected") {
+        let selected: Vec<String> = current_files(app_state)
+            .iter()
+            .map(|(_, path)| shell_words::quote(&path.to_string_lossy()).to_string())
+            .collect();
+        s = s.replace("%selected", &selected.join(" "));
+    }
+    if s.contains("%tab_index") {
+        s = s.replace("%tab_index", &(tab_state.index + 1).to_string());
+    }
+    s
+}
+
+pub fn execute_sequence(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+    steps: &[(ChainOperator, Command)],
+) -> AppResult {
+    let mut result = Ok(());
+    let mut failed = false;
+    for (operator, command) in steps {
+        match operator {
+            ChainOperator::Always => failed = false,
+            ChainOperator::OnSuccess if failed => continue,
+            ChainOperator::OnSuccess => {}
+        }
+        if let Err(e) = command.execute(app_state, backend, keymap_t) {
+            failed = true;
+            // report every failure, but only the last one is returned to the caller
+            if let Err(prev) = std::mem::replace(&mut result, Err(e)) {
+                app_state
+                    .state
+                    .message_queue_mut()
+                    .push_error(prev.to_string());
+            }
+        }
+    }
+    result
+}
+
+pub fn execute_expanded(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+    template: &str,
+) -> AppResult {
+    let s = expand_variables(app_state, template);
+    let command = Command::from_str(&s)?;
+    command.execute(app_state, backend, keymap_t)
+}
@@ -3,6 +3,7 @@ use std::path;
 use crate::commands::{reload, zoxide};
 use crate::error::AppResult;
 use crate::history::{generate_entries_to_root, DirectoryHistory};
+use crate::types::option::hooks::Hook;
 use crate::types::state::AppState;
 use crate::utils::cwd;
 
@@ -10,6 +11,7 @@ use crate::utils::cwd;
 pub fn cd(path: &path::Path, app_state: &mut AppState) -> std::io::Result<()> {
     cwd::set_current_dir(path)?;
     app_state.state.tab_state_mut().curr_tab_mut().set_cwd(path);
+    app_state.config.hooks.run(Hook::Cd, [path]);
     if app_state.config.zoxide_update {
         debug_assert!(path.is_absolute());
         zoxide::zoxide_add(path.to_str().expect("cannot convert path to string"))?;
@@ -64,6 +66,7 @@ pub fn parent_directory(app_state: &mut AppState) -> AppResult {
             .tab_state_mut()
             .curr_tab_mut()
             .set_cwd(parent.as_path());
+        app_state.config.hooks.run(Hook::Cd, [&parent]);
         reload::soft_reload_curr_tab(app_state)?;
     }
     Ok(())
@@ -84,6 +87,7 @@ pub fn previous_directory(app_state: &mut AppState) -> AppResult {
             .tab_state_mut()
             .curr_tab_mut()
             .set_cwd(path.as_path());
+        app_state.config.hooks.run(Hook::Cd, [&path]);
         reload::soft_reload_curr_tab(app_state)?;
     }
     Ok(())
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use crate::error::AppResult;
@@ -23,29 +24,51 @@ pub fn read_and_execute(
         .suffix(suffix)
         .get_input(app_state, backend, &mut listener);
 
-    if let Some(mut s) = user_input {
-        let mut trimmed = s.trim_start();
+    if let Some(s) = user_input {
+        let trimmed = s.trim_start();
         let _ = app_state
             .state
             .commandline_state_mut()
             .history_mut()
             .add(trimmed);
 
-        let (command, arg) = match trimmed.find(' ') {
-            Some(i) => (&trimmed[..i], &trimmed[i..]),
-            None => (trimmed, ""),
-        };
-
-        if let Some(alias) = app_state.config.cmd_aliases.get(trimmed) {
-            trimmed = alias;
-        } else if let Some(alias) = app_state.config.cmd_aliases.get(command) {
-            s.replace_range(..s.len() - arg.len(), alias);
-            trimmed = &s;
-        }
-
-        let command = Command::from_str(trimmed)?;
+        let expanded = expand_alias(&app_state.config.cmd_aliases, trimmed);
+        let command = Command::from_str(&expanded)?;
         command.execute(app_state, backend, keymap_t)
     } else {
         Ok(())
     }
 }
+
+/// Replaces an alias at the beginning of the input.
+/// If the alias contains positional parameters (`$1` to `$9` or `$@`), they are substituted
+/// by the arguments given to the alias, otherwise the arguments are appended.
+fn expand_alias(aliases: &HashMap<String, String>, input: &str) -> String {
+    if let Some(alias) = aliases.get(input) {
+        return alias.clone();
+    }
+
+    let (command, arg) = match input.find(' ') {
+        Some(i) => (&input[..i], &input[i..]),
+        None => (input, ""),
+    };
+    let alias = match aliases.get(command) {
+        Some(alias) => alias,
+        None => return input.to_string(),
+    };
+
+    let has_parameters =
+        alias.contains("$@") || (1..=9).any(|i| alias.contains(&format!("${}", i)));
+    if !has_parameters {
+        return format!("{}{}", alias, arg);
+    }
+
+    let args = shell_words::split(arg)
+        .unwrap_or_else(|_| arg.split_whitespace().map(|s| s.to_string()).collect());
+    let mut expanded = alias.replace("$@", arg.trim());
+    for i in 1..=9 {
+        let value = args.get(i - 1).map(|s| s.as_str()).unwrap_or_default();
+        expanded = expanded.replace(&format!("${}", i), value);
+    }
+    expanded
+}
@@ -0,0 +1,48 @@
+use std::path;
+
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::types::state::AppState;
+
+/// Computes the recursive sizes of the selected directories,
+/// or of all directories in the current directory if none are selected
+pub fn compute_sizes(app_state: &mut AppState) -> AppResult {
+    let dirs: Vec<path::PathBuf> = match app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+    {
+        Some(curr_list) => {
+            let selected: Vec<_> = curr_list
+                .iter_selected()
+                .filter(|e| e.metadata.is_dir())
+                .collect();
+            let entries = if selected.is_empty() {
+                curr_list.iter().filter(|e| e.metadata.is_dir()).collect()
+            } else {
+                selected
+            };
+            entries
+                .into_iter()
+                .filter(|e| e.metadata.recursive_size().is_none())
+                .map(|e| e.file_path().to_path_buf())
+                .collect()
+        }
+        None => Vec::new(),
+    };
+
+    if dirs.is_empty() {
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            "No directories to compute the size of".to_string(),
+        ));
+    }
+    let count = app_state.state.dir_size_state_mut().compute(dirs);
+    if count > 0 {
+        app_state
+            .state
+            .message_queue_mut()
+            .push_info(format!("Computing the size of {} directories", count));
+    }
+    Ok(())
+}
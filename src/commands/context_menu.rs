@@ -0,0 +1,192 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use ratatui::layout::Rect;
+use ratatui::widgets::Clear;
+use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+use crate::commands::open_file;
+use crate::error::AppResult;
+use crate::fs::JoshutoDirEntry;
+use crate::run::process_event;
+use crate::traits::app_execute::AppExecute;
+use crate::types::command::Command;
+use crate::types::event::AppEvent;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::mimetype::ProgramEntry;
+use crate::types::state::AppState;
+use crate::ui::views::TuiView;
+use crate::ui::widgets::TuiMenu;
+use crate::ui::AppBackend;
+use crate::utils::mimetype::get_mimetype;
+
+const MENU_KEYS: &str = "123456789abcdefghijklmnopqrstuvwxyz";
+
+#[derive(Clone, Debug)]
+enum MenuAction {
+    Command(Command),
+    // a program of `mimetype.toml` chosen for the file the menu was opened for
+    OpenWith(ProgramEntry, PathBuf),
+}
+
+/// Lists the actions available for `entry`: the programs configured for its type in
+/// `mimetype.toml`, the matching user-defined entries and the common file operations
+fn menu_items(app_state: &mut AppState, entry: &JoshutoDirEntry) -> Vec<(String, MenuAction)> {
+    let mut items = vec![("open".to_string(), MenuAction::Command(Command::OpenFile))];
+
+    let path = entry.file_path();
+    if !path.is_dir() {
+        let options = open_file::get_options(path, &app_state.config);
+        items.extend(options.into_iter().map(|option| {
+            (
+                format!("open with {}", option),
+                MenuAction::OpenWith(option, path.to_path_buf()),
+            )
+        }));
+    }
+
+    let config = &app_state.config;
+    if !config.context_menu.is_empty() {
+        let mimetype = if config.context_menu.iter().any(|e| !e.mimetype.is_empty()) {
+            get_mimetype(path).ok()
+        } else {
+            None
+        };
+        let mut errors = Vec::new();
+        for menu_entry in config
+            .context_menu
+            .iter()
+            .filter(|e| e.matches(entry.ext(), mimetype.as_ref(), config.case_insensitive_ext))
+        {
+            match Command::from_str(&menu_entry.command) {
+                Ok(command) => items.push((menu_entry.label.clone(), MenuAction::Command(command))),
+                Err(err) => errors.push(err.to_string()),
+            }
+        }
+        for err in errors {
+            app_state.state.message_queue_mut().push_error(err);
+        }
+    }
+
+    let commands = [
+        ("copy", Command::CopyFiles { other_pane: false }),
+        ("cut", Command::CutFiles { other_pane: false }),
+        ("rename", Command::RenameFileAppend),
+        (
+            "delete",
+            Command::DeleteFiles {
+                background: false,
+                permanently: false,
+                noconfirm: false,
+            },
+        ),
+        ("properties", Command::Properties),
+    ];
+    items.extend(
+        commands
+            .into_iter()
+            .map(|(label, command)| (label.to_string(), MenuAction::Command(command))),
+    );
+    items
+}
+
+pub fn context_menu(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+) -> AppResult {
+    let entry = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+        .and_then(|list| list.curr_entry_ref())
+        .cloned();
+    let Some(entry) = entry else {
+        return Ok(());
+    };
+
+    let items: Vec<(String, MenuAction)> = menu_items(app_state, &entry)
+        .into_iter()
+        .take(MENU_KEYS.len())
+        .collect();
+    match poll_for_menu_item(app_state, backend, &items) {
+        Some(MenuAction::Command(command)) => command.execute(app_state, backend, keymap_t),
+        Some(MenuAction::OpenWith(program, path)) => {
+            open_file::open_with_program(app_state, backend, &program, &path)
+        }
+        None => Ok(()),
+    }
+}
+
+fn poll_for_menu_item(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    items: &[(String, MenuAction)],
+) -> Option<MenuAction> {
+    app_state.flush_event();
+
+    let labels: Vec<String> = items
+        .iter()
+        .zip(MENU_KEYS.chars())
+        .map(|((label, _), key)| format!("  {}  {}", key, label))
+        .collect();
+    let labels_str: Vec<&str> = labels.iter().map(|s| s.as_str()).collect();
+
+    let mut menu_rect = Rect::default();
+    loop {
+        let _ = backend.terminal_mut().draw(|frame| {
+            let area = frame.area();
+            if area.height < 5 {
+                return;
+            }
+            {
+                let mut view = TuiView::new(app_state);
+                view.show_bottom_status = false;
+                frame.render_widget(view, area);
+            }
+
+            let len = labels_str.len().min(area.height as usize - 1);
+            let menu_widget = TuiMenu::new(&labels_str[..len]);
+            menu_rect = Rect {
+                x: 0,
+                y: area.height - len as u16 - 1,
+                width: area.width,
+                height: len as u16 + 1,
+            };
+            frame.render_widget(Clear, menu_rect);
+            frame.render_widget(menu_widget, menu_rect);
+        });
+
+        if let Ok(event) = app_state.poll_event() {
+            let event = match event {
+                AppEvent::Termion(event) => event,
+                event => {
+                    process_event::process_noninteractive(event, app_state);
+                    continue;
+                }
+            };
+            app_state.flush_event();
+            match event {
+                Event::Key(Key::Char(c)) => {
+                    return MENU_KEYS
+                        .find(c)
+                        .and_then(|i| items.get(i))
+                        .map(|(_, action)| action.clone());
+                }
+                Event::Mouse(MouseEvent::Press(MouseButton::Left, _, y)) => {
+                    // the first row of the menu is its border, items start below it
+                    let y = y.saturating_sub(1);
+                    if y <= menu_rect.y || y >= menu_rect.y + menu_rect.height {
+                        return None;
+                    }
+                    return items
+                        .get((y - menu_rect.y - 1) as usize)
+                        .map(|(_, action)| action.clone());
+                }
+                Event::Mouse(_) => {}
+                _ => return None,
+            }
+        }
+    }
+}
@@ -37,7 +37,7 @@ fn prompt(app_state: &mut AppState, backend: &mut AppBackend, paths_len: usize)
     }
 }
 
-fn delete_files(
+pub fn delete_files(
     app_state: &mut AppState,
     paths: Vec<path::PathBuf>,
     background: bool,
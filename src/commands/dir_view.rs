@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use crate::error::AppResult;
+use crate::types::option::dir_view::DirView;
+use crate::types::state::{AppState, MatchState};
+
+use super::reload;
+
+fn depreciate_dir(app_state: &mut AppState, dir: &PathBuf) {
+    for (_, tab) in app_state.state.tab_state_mut().iter_mut() {
+        if let Some(list) = tab.history_mut().get_mut(dir) {
+            list.depreciate();
+        }
+    }
+}
+
+/// Changes the view of the current directory if directory views are remembered.
+/// Returns whether they are, the settings of the tab are changed otherwise.
+pub fn update_curr_dir_view<F>(app_state: &mut AppState, update: F) -> AppResult<bool>
+where
+    F: FnOnce(&mut DirView),
+{
+    if !app_state.config.display_options.dir_views.is_enabled() {
+        return Ok(false);
+    }
+    let cwd = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .get_cwd()
+        .to_path_buf();
+    app_state
+        .config
+        .display_options
+        .dir_views
+        .update(&cwd, update)?;
+    depreciate_dir(app_state, &cwd);
+    Ok(true)
+}
+
+/// Forgets the view settings remembered for the current directory and removes its filter
+pub fn view_reset(app_state: &mut AppState) -> AppResult {
+    let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
+    let cwd = curr_tab.get_cwd().to_path_buf();
+    if let Some(options) = curr_tab.option_ref().dirlist_options_ref(&cwd) {
+        if !options.filter_state_ref().is_none() {
+            curr_tab
+                .option_mut()
+                .dirlist_options_mut(&cwd)
+                .set_filter_state(MatchState::None);
+        }
+    }
+    app_state.config.display_options.dir_views.remove(&cwd)?;
+    depreciate_dir(app_state, &cwd);
+    reload::soft_reload_curr_tab(app_state)?;
+    Ok(())
+}
@@ -0,0 +1,291 @@
+use std::path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use termion::event::{Event, Key};
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::fs::{scan_disk_usage, DiskUsageNode};
+use crate::ipc;
+use crate::run::process_event;
+use crate::types::command::Command;
+use crate::types::event::AppEvent;
+use crate::types::keybind::CommandKeybind;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::state::remove_external_preview;
+use crate::types::state::AppState;
+use crate::ui::views::TuiDiskUsageView;
+use crate::ui::AppBackend;
+use crate::utils::format;
+
+use super::{delete_files, tab_ops};
+
+// how far page up and page down move the cursor
+const PAGE_SIZE: usize = 10;
+
+struct DiskUsageScan {
+    id: Uuid,
+    cancel: Arc<AtomicBool>,
+}
+
+impl DiskUsageScan {
+    fn start(app_state: &AppState, path: &path::Path) -> Self {
+        let id = Uuid::new_v4();
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let event_tx = app_state.clone_event_tx();
+        let path = path.to_path_buf();
+        let thread_cancel = cancel.clone();
+        let _ = thread::spawn(move || {
+            let res = scan_disk_usage(&path, &thread_cancel, |progress| {
+                let _ = event_tx.send(AppEvent::DiskUsageProgress { id, progress });
+            });
+            let _ = event_tx.send(AppEvent::DiskUsageScanned {
+                id,
+                res: Box::new(res),
+            });
+        });
+        Self { id, cancel }
+    }
+}
+
+impl Drop for DiskUsageScan {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+struct DiskUsageView {
+    root: path::PathBuf,
+    tree: Option<DiskUsageNode>,
+    // indices of the directories from the root down to the shown directory
+    indices: Vec<usize>,
+    cursor: usize,
+    status: String,
+    // options of a deletion waiting for confirmation
+    pending_delete: Option<(bool, bool)>,
+}
+
+impl DiskUsageView {
+    fn dir(&self) -> Option<&DiskUsageNode> {
+        self.tree.as_ref()?.descendant(&self.indices)
+    }
+
+    fn dir_path(&self) -> path::PathBuf {
+        let mut path = self.root.clone();
+        let mut node = self.tree.as_ref();
+        for i in &self.indices {
+            node = node.and_then(|n| n.children.get(*i));
+            if let Some(n) = node {
+                path.push(&n.name);
+            }
+        }
+        path
+    }
+
+    fn selected(&self) -> Option<&DiskUsageNode> {
+        self.dir()?.children.get(self.cursor)
+    }
+
+    fn move_cursor(&mut self, offset: isize) {
+        let len = self.dir().map(|d| d.children.len()).unwrap_or(0);
+        let cursor = self.cursor as isize + offset;
+        self.cursor = cursor.clamp(0, len.saturating_sub(1) as isize) as usize;
+    }
+
+    fn enter(&mut self) {
+        if self.selected().map_or(false, |n| n.is_dir) {
+            self.indices.push(self.cursor);
+            self.cursor = 0;
+        }
+    }
+
+    fn leave(&mut self) {
+        if let Some(i) = self.indices.pop() {
+            self.cursor = i;
+        }
+    }
+
+    fn scanned(&mut self, tree: DiskUsageNode) {
+        self.tree = Some(tree);
+        self.indices.clear();
+        self.cursor = 0;
+        self.status = "Done".to_string();
+    }
+}
+
+/// Scans the current directory in the background and shows what takes up the most space
+pub fn disk_usage_loop(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+) -> AppResult {
+    app_state.flush_event();
+    remove_external_preview(app_state);
+
+    let root = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .get_cwd()
+        .to_path_buf();
+    let mut scan = DiskUsageScan::start(app_state, &root);
+    let mut view = DiskUsageView {
+        root,
+        tree: None,
+        indices: Vec::new(),
+        cursor: 0,
+        status: "Scanning...".to_string(),
+        pending_delete: None,
+    };
+
+    loop {
+        let dir_path = view.dir_path();
+        backend.render(TuiDiskUsageView::new(
+            app_state,
+            &dir_path,
+            view.dir(),
+            view.cursor,
+            &view.status,
+        ));
+
+        let event = match app_state.poll_event() {
+            Ok(event) => event,
+            Err(_) => return Ok(()),
+        };
+
+        let commands = match event {
+            AppEvent::DiskUsageProgress { id, progress } if id == scan.id => {
+                view.status = format!(
+                    "Scanning... {} files, {}",
+                    progress.files,
+                    format::file_size_to_string(progress.bytes)
+                );
+                continue;
+            }
+            AppEvent::DiskUsageScanned { id, res } if id == scan.id => {
+                match *res {
+                    Ok(tree) => view.scanned(tree),
+                    Err(e) => view.status = e.to_string(),
+                }
+                continue;
+            }
+            AppEvent::Termion(Event::Key(key)) if view.pending_delete.is_some() => {
+                let (background, permanently) = view.pending_delete.take().unwrap_or_default();
+                match key {
+                    Key::Char('Y') | Key::Char('y') | Key::Char('\n') => {
+                        delete_selected(app_state, &mut view, background, permanently)
+                    }
+                    _ => view.status.clear(),
+                }
+                app_state.flush_event();
+                continue;
+            }
+            AppEvent::Termion(Event::Key(Key::Esc)) => break,
+            AppEvent::Termion(event) => {
+                let commands = match keymap_t.default_view.get(&event) {
+                    Some(CommandKeybind::SimpleKeybind { commands, .. }) => Some(commands),
+                    Some(CommandKeybind::CompositeKeybind(m)) => {
+                        process_event::poll_event_until_simple_keybind(app_state, backend, m)
+                    }
+                    None => None,
+                };
+                app_state.flush_event();
+                match commands {
+                    Some(commands) => commands.clone(),
+                    None => continue,
+                }
+            }
+            // commands sent through the IPC socket control the view as well
+            AppEvent::IpcCommand { command, reply } => {
+                let _ = reply.send(ipc::ok_response());
+                vec![command]
+            }
+            event => {
+                process_event::process_noninteractive(event, app_state);
+                continue;
+            }
+        };
+
+        view.pending_delete = None;
+        for command in &commands {
+            match command {
+                Command::CursorMoveUp { offset } => view.move_cursor(-(*offset as isize)),
+                Command::CursorMoveDown { offset } => view.move_cursor(*offset as isize),
+                Command::CursorMovePageUp(_) => view.move_cursor(-(PAGE_SIZE as isize)),
+                Command::CursorMovePageDown(_) => view.move_cursor(PAGE_SIZE as isize),
+                Command::CursorMoveHome => view.cursor = 0,
+                Command::CursorMoveEnd => view.move_cursor(isize::MAX / 2),
+                Command::OpenFile => view.enter(),
+                Command::ParentDirectory => view.leave(),
+                Command::ChangeDirectory { path } if path == path::Path::new("..") => view.leave(),
+                Command::ReloadDirList => {
+                    scan = DiskUsageScan::start(app_state, &view.root);
+                    view.tree = None;
+                    view.status = "Scanning...".to_string();
+                }
+                Command::DeleteFiles {
+                    background,
+                    permanently,
+                    noconfirm,
+                } => {
+                    let name = match view.selected() {
+                        Some(node) => node.name.clone(),
+                        None => continue,
+                    };
+                    if *noconfirm {
+                        delete_selected(app_state, &mut view, *background, *permanently);
+                    } else {
+                        view.pending_delete = Some((*background, *permanently));
+                        view.status = format!("Delete {}? (Y/n)", name);
+                    }
+                }
+                Command::DiskUsage | Command::Quit(_) | Command::CloseTab => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+// deletes the file under the cursor and updates the totals of the directories above it
+fn delete_selected(
+    app_state: &mut AppState,
+    view: &mut DiskUsageView,
+    background: bool,
+    permanently: bool,
+) {
+    let path = match view.selected() {
+        Some(node) => view.dir_path().join(&node.name),
+        None => return,
+    };
+    if let Err(e) = delete_files::delete_files(app_state, vec![path], background, permanently) {
+        view.status = e.to_string();
+        return;
+    }
+
+    let mut indices = view.indices.clone();
+    indices.push(view.cursor);
+    if let Some(removed) = view
+        .tree
+        .as_mut()
+        .and_then(|tree| tree.remove_descendant(&indices))
+    {
+        view.status = format!(
+            "Deleted {} ({})",
+            removed.name,
+            format::file_size_to_string(removed.size)
+        );
+    }
+    view.move_cursor(0);
+
+    let curr_path = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .get_cwd()
+        .to_path_buf();
+    let _ = tab_ops::reload_all_tabs(app_state, &curr_path);
+}
@@ -1,7 +1,19 @@
 use crate::error::AppResult;
 use crate::types::state::AppState;
 
+use super::find;
+
 pub fn escape(app_state: &mut AppState) -> AppResult {
+    let in_visual_mode = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+        .map_or(false, |list| list.get_visual_mode_anchor_index().is_some());
+    // leave the find results once nothing else is left to cancel
+    if !in_visual_mode && find::find_exit(app_state)? {
+        return Ok(());
+    }
     if let Some(curr_dir_list) = app_state
         .state
         .tab_state_mut()
@@ -1,8 +1,11 @@
-use std::process::{Command, Stdio};
+use std::path;
 
 use crate::error::{AppError, AppErrorKind, AppResult};
 use crate::types::io::{FileOperation, FileOperationOptions, IoTask};
 use crate::types::state::{AppState, LocalStateState};
+use crate::utils::clipboard;
+
+use super::{find, tab_ops};
 
 fn new_local_state(app_state: &mut AppState, file_op: FileOperation) -> Option<()> {
     let list = app_state
@@ -20,16 +23,47 @@ fn new_local_state(app_state: &mut AppState, file_op: FileOperation) -> Option<(
     Some(())
 }
 
-pub fn cut(app_state: &mut AppState) -> AppResult {
+pub fn cut(app_state: &mut AppState, other_pane: bool) -> AppResult {
+    if other_pane {
+        return to_other_pane(app_state, FileOperation::Cut);
+    }
     new_local_state(app_state, FileOperation::Cut);
     Ok(())
 }
 
-pub fn copy(app_state: &mut AppState) -> AppResult {
+pub fn copy(app_state: &mut AppState, other_pane: bool) -> AppResult {
+    if other_pane {
+        return to_other_pane(app_state, FileOperation::Copy);
+    }
     new_local_state(app_state, FileOperation::Copy);
     Ok(())
 }
 
+// moves or copies the selected files right away, leaving the yanked files as they are
+fn to_other_pane(app_state: &mut AppState, file_op: FileOperation) -> AppResult {
+    let other_tab = tab_ops::other_pane_tab(app_state)?;
+    let dest = other_tab.get_cwd().to_path_buf();
+    find::ensure_not_find_results(other_tab, &dest)?;
+    let paths = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+        .map(|list| list.get_selected_paths())
+        .unwrap_or_default();
+    if paths.is_empty() {
+        let err_msg = "No files selected";
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            err_msg.to_string(),
+        ));
+    }
+
+    let io_task = IoTask::new(file_op, paths, dest, FileOperationOptions::default());
+    app_state.state.worker_state_mut().push_task(io_task);
+    Ok(())
+}
+
 pub fn create_io_task(
     app_state: &mut AppState,
     operation: FileOperation,
@@ -58,7 +92,18 @@ pub fn create_io_task(
     Ok(())
 }
 
-pub fn create_io_paste_task(app_state: &mut AppState, options: FileOperationOptions) -> AppResult {
+pub fn create_io_paste_task(
+    app_state: &mut AppState,
+    options: FileOperationOptions,
+    other_pane: bool,
+) -> AppResult {
+    let dest_tab = if other_pane {
+        tab_ops::other_pane_tab(app_state)?
+    } else {
+        app_state.state.tab_state_ref().curr_tab_ref()
+    };
+    let dest = dest_tab.get_cwd().to_path_buf();
+    find::ensure_not_find_results(dest_tab, &dest)?;
     let local_state = app_state.state.take_local_state().ok_or_else(|| {
         let err_msg = "No files selected";
         AppError::new(AppErrorKind::InvalidParameters, err_msg.to_string())
@@ -70,12 +115,6 @@ pub fn create_io_paste_task(app_state: &mut AppState, options: FileOperationOpti
         return Err(err);
     }
 
-    let dest = app_state
-        .state
-        .tab_state_ref()
-        .curr_tab_ref()
-        .get_cwd()
-        .to_path_buf();
     let worker_thread = IoTask::new(local_state.file_op, local_state.paths, dest, options);
     app_state.state.worker_state_mut().push_task(worker_thread);
 
@@ -92,7 +131,7 @@ pub fn copy_filename(app_state: &mut AppState) -> AppResult {
         .map(|entry| entry.file_name().to_string());
 
     if let Some(file_name) = entry_file_name {
-        copy_string_to_buffer(file_name)?;
+        copy_string_to_buffer(app_state, file_name)?;
     }
     Ok(())
 }
@@ -113,7 +152,7 @@ pub fn copy_filename_without_extension(app_state: &mut AppState) -> AppResult {
         });
 
     if let Some(file_name) = entry_file_name {
-        copy_string_to_buffer(file_name)?;
+        copy_string_to_buffer(app_state, file_name)?;
     }
     Ok(())
 }
@@ -143,7 +182,7 @@ pub fn copy_filepath(app_state: &mut AppState, all: bool) -> AppResult {
         }
     };
     if let Some(file_path) = entry_file_path {
-        copy_string_to_buffer(file_path)?;
+        copy_string_to_buffer(app_state, file_path)?;
     }
     Ok(())
 }
@@ -157,42 +196,39 @@ pub fn copy_dirpath(app_state: &mut AppState) -> AppResult {
         .map(|dirlist| dirlist.file_path());
 
     if let Some(s) = opt_entry.and_then(|p| p.to_str().map(String::from)) {
-        copy_string_to_buffer(s)?
+        copy_string_to_buffer(app_state, s)?
     };
     Ok(())
 }
 
-fn copy_string_to_buffer(string: String) -> AppResult {
-    let clipboards = [
-        (
-            "wl-copy",
-            format!("printf '%s' '{}' | {}", string, "wl-copy"),
-        ),
-        ("xsel", format!("printf '%s' '{}' | {} -ib", string, "xsel")),
-        ("pbcopy", format!("printf '%s' '{}' | {}", string, "pbcopy")),
-        (
-            "xclip",
-            format!(
-                "printf '%s' '{}' | {} -selection clipboard",
-                string, "xclip"
-            ),
-        ),
-    ];
-
-    for (_, cmd) in clipboards.iter() {
-        let status = Command::new("sh")
-            .args(["-c", cmd.as_str()])
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status();
-
-        match status {
-            Ok(s) if s.success() => return Ok(()),
-            _ => {}
-        }
+fn copy_string_to_buffer(app_state: &AppState, string: String) -> AppResult {
+    clipboard::copy_to_clipboard(&app_state.config.clipboard_options.backends, &string)
+}
+
+/// Copies the files listed in the clipboard, e.g. by a graphical file manager, into the current directory
+pub fn paste_from_clipboard(app_state: &mut AppState) -> AppResult {
+    let text = clipboard::paste_from_clipboard(&app_state.config.clipboard_options.backends)?;
+    let paths: Vec<path::PathBuf> = clipboard::parse_uri_list(&text)
+        .into_iter()
+        .filter(|p| p.symlink_metadata().is_ok())
+        .collect();
+    if paths.is_empty() {
+        let err_msg = "No files in clipboard";
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            err_msg.to_string(),
+        ));
     }
-    Err(AppError::new(
-        AppErrorKind::Clipboard,
-        "Failed to copy to clipboard".to_string(),
-    ))
+
+    let curr_tab = app_state.state.tab_state_ref().curr_tab_ref();
+    let dest = curr_tab.get_cwd().to_path_buf();
+    find::ensure_not_find_results(curr_tab, &dest)?;
+    let io_task = IoTask::new(
+        FileOperation::Copy,
+        paths,
+        dest,
+        FileOperationOptions::default(),
+    );
+    app_state.state.worker_state_mut().push_task(io_task);
+    Ok(())
 }
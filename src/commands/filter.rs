@@ -1,21 +1,27 @@
 use crate::error::AppResult;
-use crate::types::state::{AppState, MatchState};
+use crate::types::option::dir_view::DirViewFilter;
+use crate::types::state::AppState;
 
+use super::dir_view::update_curr_dir_view;
 use super::reload;
 
-pub fn filter(app_state: &mut AppState, filter_state: MatchState) -> AppResult {
+pub fn filter(app_state: &mut AppState, filter: DirViewFilter) -> AppResult {
     let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
     let path = curr_tab.get_cwd().to_path_buf();
 
     curr_tab
         .option_mut()
         .dirlist_options_mut(&path)
-        .set_filter_state(filter_state);
+        .set_filter_state(filter.state.clone());
 
     if let Some(list) = curr_tab.curr_list_mut() {
         list.depreciate();
     }
 
+    // an empty pattern removes the filter
+    let remembered = (!filter.pattern.is_empty()).then_some(filter);
+    update_curr_dir_view(app_state, |view| view.filter = remembered)?;
+
     reload::soft_reload_curr_tab(app_state)?;
     Ok(())
 }
@@ -0,0 +1,12 @@
+use crate::error::AppResult;
+use crate::types::option::dir_view::{DirViewFilter, FilterKind};
+use crate::types::option::search::CaseSensitivity;
+use crate::types::state::AppState;
+
+use super::filter;
+
+pub fn filter_expr(app_state: &mut AppState, expr: &str) -> AppResult {
+    // expressions are always case sensitive
+    let filter = DirViewFilter::new(FilterKind::Expr, expr.trim(), CaseSensitivity::Sensitive)?;
+    filter::filter(app_state, filter)
+}
@@ -1,11 +1,12 @@
 use crate::error::AppResult;
-use crate::types::state::{AppState, MatchState};
+use crate::types::option::dir_view::{DirViewFilter, FilterKind};
+use crate::types::state::AppState;
 
 use super::filter;
 
 pub fn filter_string(app_state: &mut AppState, pattern: &str) -> AppResult {
     let case_sensitivity = app_state.config.search_options.string_case_sensitivity;
 
-    let filter_state = MatchState::new_string(pattern, case_sensitivity);
-    filter::filter(app_state, filter_state)
+    let filter = DirViewFilter::new(FilterKind::String, pattern, case_sensitivity)?;
+    filter::filter(app_state, filter)
 }
@@ -0,0 +1,177 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::thread;
+
+use uuid::Uuid;
+
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::fs::{find_files, FindResults, JoshutoDirEntry};
+use crate::tab::JoshutoTab;
+use crate::types::event::AppEvent;
+use crate::types::state::{AppState, MatchState};
+
+use super::reload;
+
+/// Searches the tree below the current directory for names matching `pattern` in the background
+/// and lists the matches instead of the contents of the current directory as they are found
+pub fn find(app_state: &mut AppState, pattern: &str, glob: bool, regex: bool) -> AppResult {
+    let search_options = &app_state.config.search_options;
+    let matcher = if glob {
+        MatchState::new_glob(pattern, search_options.glob_case_sensitivity)?
+    } else if regex {
+        MatchState::new_regex(pattern, search_options.regex_case_sensitivity)?
+    } else {
+        MatchState::new_string(pattern, search_options.string_case_sensitivity)
+    };
+
+    let id = Uuid::new_v4();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let root = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .get_cwd()
+        .to_path_buf();
+
+    let event_tx = app_state.clone_event_tx();
+    let display_options = &app_state.config.display_options;
+    let (show_hidden, show_ignored) = (display_options.show_hidden, display_options.show_ignored);
+    let thread_root = root.clone();
+    let thread_cancel = cancel.clone();
+    let _ = thread::spawn(move || {
+        let res = find_files(
+            &thread_root,
+            &matcher,
+            show_hidden,
+            show_ignored,
+            &thread_cancel,
+            |paths| {
+                let _ = event_tx.send(AppEvent::FindMatches { id, paths });
+            },
+        );
+        let _ = event_tx.send(AppEvent::FindDone { id, res });
+    });
+
+    let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
+    curr_tab
+        .option_mut()
+        .dirlist_options_mut(&root)
+        .set_find_results(Some(FindResults::new(id, pattern.to_string(), cancel)));
+    if let Some(list) = curr_tab.curr_list_mut() {
+        list.depreciate();
+    }
+    reload::soft_reload_curr_tab(app_state)?;
+    Ok(())
+}
+
+/// Goes back from the find results to the contents of the current directory.
+/// Returns whether the current directory showed find results.
+pub fn find_exit(app_state: &mut AppState) -> AppResult<bool> {
+    let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
+    let cwd = curr_tab.get_cwd().to_path_buf();
+    let options = curr_tab.option_mut().dirlist_options_mut(&cwd);
+    if options.find_results_ref().is_none() {
+        return Ok(false);
+    }
+    options.set_find_results(None);
+    if let Some(list) = curr_tab.curr_list_mut() {
+        list.depreciate();
+    }
+    reload::soft_reload_curr_tab(app_state)?;
+    Ok(true)
+}
+
+/// Adds paths found in the background to the find results they belong to.
+/// Only the new paths are read, the entries listed so far are kept.
+pub fn process_find_matches(app_state: &mut AppState, id: Uuid, paths: Vec<PathBuf>) {
+    let (tab_id, dir) = match find_results_dir(app_state, id) {
+        Some(target) => target,
+        None => return,
+    };
+    let display_options = &app_state.config.display_options;
+    let ui_state = app_state.state.ui_state_ref().clone();
+    let tab = match app_state.state.tab_state_mut().tab_mut(&tab_id) {
+        Some(tab) => tab,
+        None => return,
+    };
+
+    let entries: Vec<JoshutoDirEntry> = paths
+        .iter()
+        .filter_map(|p| JoshutoDirEntry::from_path(p, &dir, display_options).ok())
+        .collect();
+    if let Some(find_results) = tab
+        .option_mut()
+        .dirlist_options_mut(&dir)
+        .find_results_mut()
+    {
+        find_results.paths.extend(paths);
+    }
+
+    let sort_options = display_options
+        .dir_views
+        .sort_options(&dir, tab.option_ref().sort_options_ref())
+        .into_owned();
+    // a list that is not loaded yet reads all paths once it is
+    if let Some(list) = tab.history_mut().get_mut(&dir) {
+        list.insert_entries(entries, |f1, f2| sort_options.compare(f1, f2));
+        if !ui_state.layout.is_empty() {
+            list.update_viewport(&ui_state, display_options);
+        }
+    }
+}
+
+pub fn process_find_done(app_state: &mut AppState, id: Uuid, res: std::io::Result<()>) {
+    if let Err(e) = res {
+        if e.kind() != std::io::ErrorKind::Interrupted {
+            app_state
+                .state
+                .message_queue_mut()
+                .push_error(e.to_string());
+        }
+    }
+    let (tab_id, dir) = match find_results_dir(app_state, id) {
+        Some(target) => target,
+        None => return,
+    };
+    if let Some(tab) = app_state.state.tab_state_mut().tab_mut(&tab_id) {
+        if let Some(find_results) = tab
+            .option_mut()
+            .dirlist_options_mut(&dir)
+            .find_results_mut()
+        {
+            find_results.done = true;
+        }
+    }
+}
+
+// the tab and directory showing the find results of the search `id`
+fn find_results_dir(app_state: &AppState, id: Uuid) -> Option<(Uuid, PathBuf)> {
+    app_state
+        .state
+        .tab_state_ref()
+        .iter()
+        .find_map(|(tab_id, tab)| {
+            tab.option_ref()
+                .dirlist_options
+                .iter()
+                .find(|(_, opts)| opts.find_results_ref().map_or(false, |f| f.id == id))
+                .map(|(dir, _)| (*tab_id, dir.clone()))
+        })
+}
+
+/// Find results are not a real directory, so nothing can be created in them.
+/// `tab` is the tab showing `dir`.
+pub fn ensure_not_find_results(tab: &JoshutoTab, dir: &Path) -> AppResult {
+    let is_find_results = tab
+        .option_ref()
+        .dirlist_options_ref(&dir.to_path_buf())
+        .map_or(false, |opts| opts.find_results_ref().is_some());
+    if is_find_results {
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            "Find results are read-only, press escape to leave them".to_string(),
+        ));
+    }
+    Ok(())
+}
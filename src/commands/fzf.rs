@@ -1,92 +1,200 @@
-use std::io::{BufWriter, Write};
-use std::process::{Command, Stdio};
-use std::str::from_utf8;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
-use crate::error::{AppError, AppResult};
-use crate::types::option::search::CaseSensitivity;
+use termion::event::{Event, Key};
+use uuid::Uuid;
+
+use crate::run::process_event;
+use crate::types::event::AppEvent;
 use crate::types::state::remove_external_preview;
 use crate::types::state::AppState;
+use crate::ui::views::TuiFuzzyPickerView;
 use crate::ui::AppBackend;
+use crate::utils::fuzzy::{fuzzy_filter, fuzzy_filter_indices, sort_matches};
+
+// how far page up and page down move the cursor
+const PAGE_SIZE: usize = 10;
 
+/// Lets the user pick one of the items with the built-in fuzzy finder.
+/// Returns the index of the picked item, or `None` if cancelled.
 pub fn fzf(
     app_state: &mut AppState,
     backend: &mut AppBackend,
-    items: Vec<String>,
-) -> AppResult<String> {
-    let mut args = Vec::new();
-
-    let case_sensitivity = app_state.config.search_options.fzf_case_sensitivity;
-
-    match case_sensitivity {
-        CaseSensitivity::Insensitive => args.push("-i".to_owned()),
-        CaseSensitivity::Sensitive => args.push("+i".to_owned()),
-        CaseSensitivity::Smart => {}
-    }
+    title: &str,
+    items: &[String],
+) -> Option<usize> {
+    fuzzy_pick(app_state, backend, title, &mut items.to_vec(), None, false)
+        .into_iter()
+        .next()
+}
 
-    fzf_impl(app_state, backend, items, args)
+/// Like `fzf`, but picks one of the paths found by the find thread `id` below `root`,
+/// which are added to the list as they come in.
+/// Returns the picked path, or `None` if cancelled.
+pub fn fzf_find_results(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    title: &str,
+    id: Uuid,
+    root: &Path,
+) -> Option<PathBuf> {
+    let mut items = Vec::new();
+    fuzzy_pick(
+        app_state,
+        backend,
+        title,
+        &mut items,
+        Some((id, root)),
+        false,
+    )
+    .into_iter()
+    .next()
+    .map(|index| root.join(&items[index]))
 }
 
+/// Like `fzf`, but any number of items can be marked with tab.
+/// Returns the indices of the marked items, or of the item under the cursor if none are marked.
 pub fn fzf_multi(
     app_state: &mut AppState,
     backend: &mut AppBackend,
-    items: Vec<String>,
-) -> AppResult<String> {
-    let mut args = Vec::new();
-
-    let case_sensitivity = app_state.config.search_options.fzf_case_sensitivity;
-
-    match case_sensitivity {
-        CaseSensitivity::Insensitive => args.push("-i".to_owned()),
-        CaseSensitivity::Sensitive => args.push("+i".to_owned()),
-        CaseSensitivity::Smart => {}
-    }
-
-    args.push("-m".to_owned());
-    fzf_impl(app_state, backend, items, args)
+    title: &str,
+    items: &[String],
+) -> Vec<usize> {
+    fuzzy_pick(app_state, backend, title, &mut items.to_vec(), None, true)
 }
 
-fn fzf_impl(
+fn fuzzy_pick(
     app_state: &mut AppState,
     backend: &mut AppBackend,
-    items: Vec<String>,
-    args: Vec<String>,
-) -> AppResult<String> {
+    title: &str,
+    items: &mut Vec<String>,
+    stream: Option<(Uuid, &Path)>,
+    multi: bool,
+) -> Vec<usize> {
+    app_state.flush_event();
     remove_external_preview(app_state);
-    backend.terminal_drop();
-
-    let mut cmd = Command::new("fzf");
-    cmd.stdout(Stdio::piped()).args(&args);
-
-    if !items.is_empty() {
-        cmd.stdin(Stdio::piped());
-    }
-
-    let mut fzf = match cmd.spawn() {
-        Ok(child) => child,
-        Err(e) => {
-            backend.terminal_restore()?;
-            return Err(AppError::from(e));
-        }
-    };
 
-    if let Some(fzf_stdin) = fzf.stdin.as_mut() {
-        let mut writer = BufWriter::new(fzf_stdin);
-
-        for item in items {
-            writer.write_all(item.as_bytes())?;
-        }
-    }
-
-    let fzf_output = fzf.wait_with_output();
-    backend.terminal_restore()?;
-
-    if let Ok(output) = fzf_output {
-        if output.status.success() {
-            if let Ok(output) = from_utf8(&output.stdout) {
-                return Ok(output.to_owned());
+    let case_sensitivity = app_state.config.search_options.fzf_case_sensitivity;
+    let mut query = String::new();
+    let mut matches = fuzzy_filter(&query, items, case_sensitivity);
+    let mut cursor: usize = 0;
+    let mut marked: HashSet<usize> = HashSet::new();
+    let stream_id = stream.map(|(id, _)| id);
+    let root = stream.map_or(Path::new(""), |(_, root)| root);
+
+    loop {
+        backend.render(TuiFuzzyPickerView::new(
+            app_state,
+            title,
+            &query,
+            items,
+            &matches,
+            (cursor, &marked),
+        ));
+
+        let event = match app_state.poll_event() {
+            Ok(event) => event,
+            Err(_) => return Vec::new(),
+        };
+        let key = match event {
+            AppEvent::Termion(Event::Key(key)) => key,
+            AppEvent::Termion(_) => {
+                app_state.flush_event();
+                continue;
+            }
+            // only the new items need to be matched
+            AppEvent::FindMatches { id, paths } if Some(id) == stream_id => {
+                let start = items.len();
+                items.extend(paths.iter().map(|path| {
+                    path.strip_prefix(root)
+                        .unwrap_or(path)
+                        .to_string_lossy()
+                        .to_string()
+                }));
+                matches.extend(fuzzy_filter_indices(
+                    &query,
+                    items,
+                    start..items.len(),
+                    case_sensitivity,
+                ));
+                sort_matches(&mut matches, items);
+                continue;
+            }
+            AppEvent::FindDone { id, .. } if Some(id) == stream_id => continue,
+            event => {
+                process_event::process_noninteractive(event, app_state);
+                continue;
+            }
+        };
+        app_state.flush_event();
+
+        let last = matches.len().saturating_sub(1);
+        let prev_query = query.clone();
+        let query_changed = match key {
+            Key::Esc | Key::Ctrl('c') | Key::Ctrl('g') => return Vec::new(),
+            Key::Char('\n') => {
+                if !marked.is_empty() {
+                    let mut picked: Vec<usize> = marked.into_iter().collect();
+                    picked.sort_unstable();
+                    return picked;
+                }
+                return matches.get(cursor).map(|(i, _)| *i).into_iter().collect();
             }
+            Key::Up | Key::Ctrl('p') | Key::Ctrl('k') => {
+                cursor = cursor.saturating_sub(1);
+                false
+            }
+            Key::Down | Key::Ctrl('n') | Key::Ctrl('j') => {
+                cursor = (cursor + 1).min(last);
+                false
+            }
+            Key::PageUp => {
+                cursor = cursor.saturating_sub(PAGE_SIZE);
+                false
+            }
+            Key::PageDown => {
+                cursor = (cursor + PAGE_SIZE).min(last);
+                false
+            }
+            Key::Char('\t') | Key::BackTab if multi => {
+                if let Some((i, _)) = matches.get(cursor) {
+                    if !marked.remove(i) {
+                        marked.insert(*i);
+                    }
+                }
+                cursor = if key == Key::BackTab {
+                    cursor.saturating_sub(1)
+                } else {
+                    (cursor + 1).min(last)
+                };
+                false
+            }
+            Key::Backspace => query.pop().is_some(),
+            Key::Ctrl('u') => {
+                query.clear();
+                true
+            }
+            Key::Ctrl('w') => {
+                let trimmed = query.trim_end().len();
+                let word_start = query[..trimmed].rfind(' ').map_or(0, |i| i + 1);
+                query.truncate(word_start);
+                true
+            }
+            Key::Char(c) if c != '\t' => {
+                query.push(c);
+                true
+            }
+            _ => false,
+        };
+        if query_changed {
+            // whatever matches the longer query also matched the shorter one
+            matches = if query.starts_with(&prev_query) {
+                let candidates: Vec<usize> = matches.iter().map(|(i, _)| *i).collect();
+                fuzzy_filter_indices(&query, items, candidates, case_sensitivity)
+            } else {
+                fuzzy_filter(&query, items, case_sensitivity)
+            };
+            cursor = 0;
         }
     }
-
-    Ok(String::new())
 }
@@ -0,0 +1,259 @@
+use std::path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use globset::GlobBuilder;
+use regex::RegexBuilder;
+use termion::event::{Event, Key};
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::fs::{grep_dir, GrepMatch, GrepQuery};
+use crate::ipc;
+use crate::run::process_event;
+use crate::types::command::Command;
+use crate::types::event::AppEvent;
+use crate::types::keybind::CommandKeybind;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::option::search::CaseSensitivity;
+use crate::types::state::remove_external_preview;
+use crate::types::state::AppState;
+use crate::ui::views::TuiGrepView;
+use crate::ui::AppBackend;
+
+use super::open_file;
+use super::subdir_fzf::fzf_change_dir;
+
+// how far page up and page down move the cursor
+const PAGE_SIZE: usize = 10;
+
+struct GrepSearch {
+    id: Uuid,
+    cancel: Arc<AtomicBool>,
+}
+
+impl GrepSearch {
+    fn start(app_state: &AppState, root: &path::Path, query: GrepQuery) -> Self {
+        let id = Uuid::new_v4();
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let event_tx = app_state.clone_event_tx();
+        let root = root.to_path_buf();
+        let thread_cancel = cancel.clone();
+        let _ = thread::spawn(move || {
+            let res = grep_dir(&root, &query, &thread_cancel, |matches, progress| {
+                let _ = event_tx.send(AppEvent::GrepMatches {
+                    id,
+                    matches,
+                    progress,
+                });
+            });
+            let _ = event_tx.send(AppEvent::GrepDone { id, res });
+        });
+        Self { id, cancel }
+    }
+}
+
+impl Drop for GrepSearch {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+struct GrepView {
+    matches: Vec<GrepMatch>,
+    cursor: usize,
+    status: String,
+}
+
+impl GrepView {
+    fn selected(&self) -> Option<&GrepMatch> {
+        self.matches.get(self.cursor)
+    }
+
+    fn move_cursor(&mut self, offset: isize) {
+        let cursor = self.cursor as isize + offset;
+        self.cursor = cursor.clamp(0, self.matches.len().saturating_sub(1) as isize) as usize;
+    }
+
+    fn restart(&mut self) {
+        self.matches.clear();
+        self.cursor = 0;
+        self.status = "Searching...".to_string();
+    }
+}
+
+fn build_query(
+    app_state: &AppState,
+    pattern: &str,
+    glob: Option<&str>,
+    regex: bool,
+) -> AppResult<GrepQuery> {
+    let search_options = &app_state.config.search_options;
+    let (pattern, case_sensitivity) = if regex {
+        (pattern.to_string(), search_options.regex_case_sensitivity)
+    } else {
+        (
+            regex::escape(pattern),
+            search_options.string_case_sensitivity,
+        )
+    };
+    let case_insensitive = match case_sensitivity {
+        CaseSensitivity::Insensitive => true,
+        CaseSensitivity::Sensitive => false,
+        CaseSensitivity::Smart => !pattern.chars().any(|c| c.is_uppercase()),
+    };
+    let regex = RegexBuilder::new(&pattern)
+        .case_insensitive(case_insensitive)
+        .build()?;
+    let glob = match glob {
+        Some(glob) => Some(GlobBuilder::new(glob).build()?.compile_matcher()),
+        None => None,
+    };
+
+    let display_options = &app_state.config.display_options;
+    Ok(GrepQuery {
+        regex,
+        glob,
+        show_hidden: display_options.show_hidden,
+        show_ignored: display_options.show_ignored,
+    })
+}
+
+fn is_leave(command: &Command) -> bool {
+    match command {
+        Command::ParentDirectory => true,
+        Command::ChangeDirectory { path } => path == path::Path::new(".."),
+        _ => false,
+    }
+}
+
+/// Searches the contents of the files below the current directory in the background
+/// and lists the matching lines
+pub fn grep_loop(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+    pattern: &str,
+    glob: Option<&str>,
+    regex: bool,
+) -> AppResult {
+    let query = build_query(app_state, pattern, glob, regex)?;
+
+    app_state.flush_event();
+    remove_external_preview(app_state);
+
+    let root = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .get_cwd()
+        .to_path_buf();
+    let mut search = GrepSearch::start(app_state, &root, query.clone());
+    let mut view = GrepView {
+        matches: Vec::new(),
+        cursor: 0,
+        status: String::new(),
+    };
+    view.restart();
+
+    loop {
+        backend.render(TuiGrepView::new(
+            app_state,
+            &root,
+            pattern,
+            &view.matches,
+            view.cursor,
+            &view.status,
+        ));
+
+        let event = match app_state.poll_event() {
+            Ok(event) => event,
+            Err(_) => return Ok(()),
+        };
+
+        let commands = match event {
+            AppEvent::GrepMatches {
+                id,
+                matches,
+                progress,
+            } if id == search.id => {
+                view.matches.extend(matches);
+                view.status = format!(
+                    "Searching... {} matches in {} files",
+                    progress.matches, progress.files
+                );
+                continue;
+            }
+            AppEvent::GrepDone { id, res } if id == search.id => {
+                view.status = match res {
+                    Ok(progress) => {
+                        format!("{} matches in {} files", progress.matches, progress.files)
+                    }
+                    Err(e) => e.to_string(),
+                };
+                continue;
+            }
+            AppEvent::Termion(Event::Key(Key::Esc)) => break,
+            AppEvent::Termion(event) => {
+                let commands = match keymap_t.default_view.get(&event) {
+                    Some(CommandKeybind::SimpleKeybind { commands, .. }) => Some(commands),
+                    Some(CommandKeybind::CompositeKeybind(m)) => {
+                        process_event::poll_event_until_simple_keybind(app_state, backend, m)
+                    }
+                    None => None,
+                };
+                app_state.flush_event();
+                match commands {
+                    Some(commands) => commands.clone(),
+                    None => continue,
+                }
+            }
+            // commands sent through the IPC socket control the view as well
+            AppEvent::IpcCommand { command, reply } => {
+                let _ = reply.send(ipc::ok_response());
+                vec![command]
+            }
+            event => {
+                process_event::process_noninteractive(event, app_state);
+                continue;
+            }
+        };
+
+        for command in &commands {
+            match command {
+                Command::CursorMoveUp { offset } => view.move_cursor(-(*offset as isize)),
+                Command::CursorMoveDown { offset } => view.move_cursor(*offset as isize),
+                Command::CursorMovePageUp(_) => view.move_cursor(-(PAGE_SIZE as isize)),
+                Command::CursorMovePageDown(_) => view.move_cursor(PAGE_SIZE as isize),
+                Command::CursorMoveHome => view.cursor = 0,
+                Command::CursorMoveEnd => view.move_cursor(isize::MAX / 2),
+                // open the file at the matching line
+                Command::OpenFile => {
+                    if let Some((path, line)) =
+                        view.selected().map(|m| (root.join(&m.path), m.line))
+                    {
+                        if let Err(e) = open_file::open_at_line(app_state, backend, &path, line) {
+                            view.status = e.to_string();
+                        }
+                    }
+                }
+                // leave the results for the file in the folder view
+                command if is_leave(command) => {
+                    if let Some(path) = view.selected().map(|m| root.join(&m.path)) {
+                        fzf_change_dir(app_state, &path)?;
+                        return Ok(());
+                    }
+                }
+                Command::ReloadDirList => {
+                    search = GrepSearch::start(app_state, &root, query.clone());
+                    view.restart();
+                }
+                Command::Grep { .. } | Command::Quit(_) | Command::CloseTab => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use super::dir_view::update_curr_dir_view;
 use super::reload;
 use crate::error::AppResult;
 use crate::history::DirectoryHistory;
@@ -5,6 +6,11 @@ use crate::types::option::line_mode::LineMode;
 use crate::types::state::AppState;
 
 pub fn set_linemode(app_state: &mut AppState, linemode: LineMode) -> AppResult {
+    if update_curr_dir_view(app_state, |view| view.linemode = Some(linemode))? {
+        reload::soft_reload_curr_tab(app_state)?;
+        return Ok(());
+    }
+
     let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
     curr_tab.option_mut().linemode = linemode;
     curr_tab.history_mut().depreciate_all_entries();
@@ -0,0 +1,117 @@
+use std::{fs, process};
+
+use crate::config::macros::MacrosRaw;
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::traits::app_execute::AppExecute;
+use crate::traits::config::search_directories;
+use crate::types::config_type::ConfigType;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::state::AppState;
+use crate::ui::AppBackend;
+use crate::{CONFIG_HIERARCHY, MACROS_T};
+
+// guards against macros that (indirectly) play themselves
+const MAX_PLAYBACK_DEPTH: usize = 16;
+
+fn find_macros_file() -> Option<std::path::PathBuf> {
+    match search_directories(ConfigType::Macros.as_filename(), &CONFIG_HIERARCHY) {
+        Some(file_path) => Some(file_path),
+        None => CONFIG_HIERARCHY
+            .iter()
+            .find(|p| p.exists())
+            .map(|p| p.join(ConfigType::Macros.as_filename())),
+    }
+}
+
+fn save_macros() -> AppResult {
+    if let Some(macros_path) = find_macros_file() {
+        let macros_raw = match MACROS_T.lock() {
+            Ok(macros) => MacrosRaw::from(&*macros),
+            Err(_) => return Ok(()),
+        };
+        let content = toml::to_string(&macros_raw)
+            .map_err(|e| AppError::new(AppErrorKind::Parse, e.to_string()))?;
+
+        // replace the file at once, so it is never seen half written
+        let file_name = ConfigType::Macros.as_filename();
+        let temp_path = macros_path.with_file_name(format!(".{}.{}", file_name, process::id()));
+        fs::write(&temp_path, content)?;
+        if let Err(e) = fs::rename(&temp_path, &macros_path) {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e.into());
+        }
+    }
+    Ok(())
+}
+
+pub fn macro_record(app_state: &mut AppState, register: char) -> AppResult {
+    if let Some(curr) = app_state.state.macro_state_ref().recording_register() {
+        return Err(AppError::new(
+            AppErrorKind::StateError,
+            format!("Already recording macro '{}'", curr),
+        ));
+    }
+    app_state.state.macro_state_mut().start_recording(register);
+    app_state
+        .state
+        .message_queue_mut()
+        .push_info(format!("Recording macro '{}'", register));
+    Ok(())
+}
+
+pub fn macro_stop(app_state: &mut AppState) -> AppResult {
+    let (register, commands) = match app_state.state.macro_state_mut().stop_recording() {
+        Some(recording) => recording,
+        None => {
+            return Err(AppError::new(
+                AppErrorKind::StateError,
+                "Not recording a macro".to_string(),
+            ))
+        }
+    };
+    let len = commands.len();
+    if let Ok(mut macros) = MACROS_T.lock() {
+        macros.insert(register, commands);
+    }
+    save_macros()?;
+
+    app_state.state.message_queue_mut().push_success(format!(
+        "Recorded {} command(s) to macro '{}'",
+        len, register
+    ));
+    Ok(())
+}
+
+pub fn macro_play(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+    register: char,
+    count: usize,
+) -> AppResult {
+    let commands = MACROS_T
+        .lock()
+        .ok()
+        .and_then(|macros| macros.get(&register).cloned())
+        .ok_or_else(|| {
+            AppError::new(
+                AppErrorKind::InvalidParameters,
+                format!("Macro '{}' is empty", register),
+            )
+        })?;
+    if app_state.state.macro_state_ref().playback_depth() >= MAX_PLAYBACK_DEPTH {
+        return Err(AppError::new(
+            AppErrorKind::StateError,
+            format!("Macro '{}' is nested too deeply", register),
+        ));
+    }
+
+    app_state.state.macro_state_mut().enter_playback();
+    let res = (0..count).try_for_each(|_| {
+        commands
+            .iter()
+            .try_for_each(|command| command.execute(app_state, backend, keymap_t))
+    });
+    app_state.state.macro_state_mut().leave_playback();
+    res
+}
@@ -1,35 +1,49 @@
 pub mod bookmark;
 pub mod bulk_rename;
 pub mod case_sensitivity;
+pub mod chain;
 pub mod change_directory;
 pub mod command_line;
+pub mod compute_sizes;
+pub mod context_menu;
 pub mod cursor_move;
 pub mod custom_search;
 pub mod delete_files;
+pub mod dir_view;
+pub mod disk_usage;
 pub mod escape;
 pub mod file_ops;
 pub mod filter;
+pub mod filter_expr;
 pub mod filter_glob;
 pub mod filter_regex;
 pub mod filter_string;
+pub mod find;
 pub mod flat;
 pub mod fzf;
+pub mod grep;
 pub mod line_nums;
 pub mod linemode;
+pub mod macros;
 pub mod new_directory;
 pub mod numbered_command;
 pub mod open_file;
 pub mod parent_cursor_move;
 pub mod preview_cursor_move;
+pub mod properties;
 pub mod quit;
 pub mod reload;
+pub mod reload_config;
 pub mod rename_file;
+pub mod script;
 pub mod search;
+pub mod search_expr;
 pub mod search_fzf;
 pub mod search_glob;
 pub mod search_regex;
 pub mod search_string;
 pub mod select;
+pub mod select_expr;
 pub mod select_fzf;
 pub mod select_glob;
 pub mod select_regex;
@@ -38,6 +52,7 @@ pub mod set_display_mode;
 pub mod set_mode;
 pub mod show_help;
 pub mod show_hidden;
+pub mod show_ignored;
 pub mod show_tasks;
 pub mod signal;
 pub mod sort;
@@ -46,5 +61,6 @@ pub mod sub_process;
 pub mod subdir_fzf;
 pub mod tab_ops;
 pub mod touch_file;
+pub mod tree;
 pub mod uimodes;
 pub mod zoxide;
@@ -55,7 +55,7 @@ pub fn numbered_command(
                     Event::Key(Key::Char(c)) if c.is_numeric() => {
                         prefix.push(c);
                     }
-                    key => match keymap.default_view.get(&key) {
+                    key => match keymap.get(app_state, &key) {
                         Some(CommandKeybind::SimpleKeybind { commands, .. }) => {
                             for command in commands {
                                 let _ = command
@@ -4,6 +4,7 @@ use crate::commands::{quit, reload};
 use crate::config::app::AppConfig;
 use crate::error::{AppError, AppErrorKind, AppResult};
 use crate::types::mimetype::ProgramEntry;
+use crate::types::option::hooks::Hook;
 use crate::types::state::AppState;
 use crate::ui::views::DummyListener;
 use crate::ui::views::TuiTextField;
@@ -15,34 +16,36 @@ use super::change_directory;
 
 use crate::MIMETYPE_T;
 
-fn _get_options<'a>(path: &path::Path, config: &AppConfig) -> Vec<&'a ProgramEntry> {
-    let mut options: Vec<&ProgramEntry> = Vec::new();
+/// Returns the programs configured in `mimetype.toml` for the given file
+pub fn get_options(path: &path::Path, config: &AppConfig) -> Vec<ProgramEntry> {
+    let mimetype_t = MIMETYPE_T.get();
+    let mut options: Vec<ProgramEntry> = Vec::new();
 
     if let Some(entries) = path
         .extension()
         .and_then(|ext| ext.to_str())
         .and_then(|ext| {
             if config.case_insensitive_ext {
-                MIMETYPE_T.app_list_for_ext(&ext.to_lowercase())
+                mimetype_t.app_list_for_ext(&ext.to_lowercase())
             } else {
-                MIMETYPE_T.app_list_for_ext(ext)
+                mimetype_t.app_list_for_ext(ext)
             }
         })
     {
-        options.extend(entries);
+        options.extend(entries.iter().cloned());
         return options;
     }
 
     if let Ok(file_mimetype) = get_mimetype(path) {
-        if let Some(entry) = MIMETYPE_T.app_list_for_mimetype(file_mimetype.get_type()) {
+        if let Some(entry) = mimetype_t.app_list_for_mimetype(file_mimetype.get_type()) {
             match entry.subtypes.get(file_mimetype.get_subtype()) {
                 Some(entries) => {
-                    options.extend(entries);
+                    options.extend(entries.iter().cloned());
                     return options;
                 }
                 None => {
                     let entries = &entry.app_list;
-                    options.extend(entries);
+                    options.extend(entries.iter().cloned());
                     return options;
                 }
             }
@@ -94,7 +97,7 @@ fn _open_with_xdg(
 fn _open_with_helper<S>(
     app_state: &mut AppState,
     backend: &mut AppBackend,
-    options: Vec<&ProgramEntry>,
+    options: Vec<ProgramEntry>,
     files: &[S],
 ) -> std::io::Result<()>
 where
@@ -180,7 +183,14 @@ pub fn open(app_state: &mut AppState, backend: &mut AppBackend) -> AppResult {
                     paths.iter().map(|e| e.file_name()).collect(),
                 )
             };
-            let options = _get_options(path, &app_state.config);
+            let hook_paths: Vec<&path::Path> = if paths.is_empty() {
+                vec![entry.file_path()]
+            } else {
+                paths.iter().map(|e| e.file_path()).collect()
+            };
+            app_state.config.hooks.run(Hook::Open, hook_paths);
+
+            let options = get_options(path, &app_state.config);
             let option = options.iter().find(|option| option.program_exists());
 
             if let Some(option) = option {
@@ -195,12 +205,35 @@ pub fn open(app_state: &mut AppState, backend: &mut AppBackend) -> AppResult {
     Ok(())
 }
 
+/// Opens a file with the first program configured for it, at `line` if its args contain `%l`
+pub fn open_at_line(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    path: &path::Path,
+    line: usize,
+) -> AppResult {
+    app_state.config.hooks.run(Hook::Open, [path]);
+
+    let options = get_options(path, &app_state.config);
+    let option = options.iter().find(|option| option.program_exists());
+
+    if let Some(option) = option {
+        _open_with_entry(app_state, backend, &option.at_line(line), &[path])?;
+    } else if app_state.config.xdg_open {
+        _open_with_xdg(app_state, backend, path)?;
+    } else {
+        let options: Vec<ProgramEntry> = options.iter().map(|o| o.at_line(line)).collect();
+        _open_with_helper(app_state, backend, options, &[path])?;
+    }
+    Ok(())
+}
+
 pub fn open_with_index(
     app_state: &mut AppState,
     backend: &mut AppBackend,
     index: usize,
 ) -> AppResult {
-    let paths = app_state
+    let mut paths = app_state
         .state
         .tab_state_ref()
         .curr_tab_ref()
@@ -208,13 +241,24 @@ pub fn open_with_index(
         .map_or(vec![], |s| s.iter_selected().cloned().collect());
 
     if paths.is_empty() {
-        return Err(AppError::new(
-            AppErrorKind::Io,
-            String::from("No files selected"),
-        ));
+        match app_state
+            .state
+            .tab_state_ref()
+            .curr_tab_ref()
+            .curr_list_ref()
+            .and_then(|s| s.curr_entry_ref())
+        {
+            Some(entry) => {
+                paths.push(entry.clone());
+            }
+            None => {
+                let err = AppError::new(AppErrorKind::Io, "No files selected".to_string());
+                return Err(err);
+            }
+        }
     }
     let files: Vec<&str> = paths.iter().map(|e| e.file_name()).collect();
-    let options = _get_options(paths[0].file_path(), &app_state.config);
+    let options = get_options(paths[0].file_path(), &app_state.config);
 
     if index >= options.len() {
         return Err(AppError::new(
@@ -223,11 +267,27 @@ pub fn open_with_index(
         ));
     }
 
+    app_state
+        .config
+        .hooks
+        .run(Hook::Open, paths.iter().map(|e| e.file_path()));
     let option = &options[index];
     _open_with_entry(app_state, backend, option, &files)?;
     Ok(())
 }
 
+/// Opens `path` with a program chosen for it, regardless of the selection
+pub fn open_with_program(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    program: &ProgramEntry,
+    path: &path::Path,
+) -> AppResult {
+    app_state.config.hooks.run(Hook::Open, [path]);
+    _open_with_entry(app_state, backend, program, &[path])?;
+    Ok(())
+}
+
 pub fn open_with_interactive(app_state: &mut AppState, backend: &mut AppBackend) -> AppResult {
     let mut paths = app_state
         .state
@@ -254,8 +314,12 @@ pub fn open_with_interactive(app_state: &mut AppState, backend: &mut AppBackend)
         }
     }
     let files: Vec<&str> = paths.iter().map(|e| e.file_name()).collect();
-    let options = _get_options(paths[0].file_path(), &app_state.config);
+    let options = get_options(paths[0].file_path(), &app_state.config);
 
+    app_state
+        .config
+        .hooks
+        .run(Hook::Open, paths.iter().map(|e| e.file_path()));
     _open_with_helper(app_state, backend, options, &files)?;
     Ok(())
 }
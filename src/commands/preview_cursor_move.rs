@@ -24,7 +24,36 @@ fn preview_cursor_move(app_state: &mut AppState, new_index: usize) -> AppResult
     Ok(())
 }
 
+/// Scrolls the preview of a directory by moving its cursor,
+/// returns whether a directory is previewed
+fn child_cursor_move(app_state: &mut AppState, offset: isize) -> bool {
+    let ui_state = app_state.state.ui_state_ref().clone();
+    let display_options = &app_state.config.display_options;
+    match app_state
+        .state
+        .tab_state_mut()
+        .curr_tab_mut()
+        .child_list_mut()
+    {
+        Some(child_list) => {
+            if !child_list.is_empty() {
+                let new_index = child_list
+                    .get_index()
+                    .unwrap_or(0)
+                    .saturating_add_signed(offset)
+                    .min(child_list.len() - 1);
+                child_list.set_index(Some(new_index), &ui_state, display_options);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
 pub fn preview_up(app_state: &mut AppState, u: usize) -> AppResult {
+    if child_cursor_move(app_state, -(u as isize)) {
+        return Ok(());
+    }
     let new_index = {
         let file_path = app_state
             .state
@@ -58,6 +87,9 @@ pub fn preview_up(app_state: &mut AppState, u: usize) -> AppResult {
 }
 
 pub fn preview_down(app_state: &mut AppState, u: usize) -> AppResult {
+    if child_cursor_move(app_state, u as isize) {
+        return Ok(());
+    }
     let new_index = {
         let file_path = app_state
             .state
@@ -69,7 +101,6 @@ pub fn preview_down(app_state: &mut AppState, u: usize) -> AppResult {
 
         let preview_state = app_state.state.preview_state_ref();
         if let Some(file_path) = file_path {
-            // TODO: scroll in child list
             if let Some(PreviewFileState::Success(data)) =
                 preview_state.previews_ref().get(file_path)
             {
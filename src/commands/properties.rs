@@ -0,0 +1,366 @@
+use std::path;
+
+use nix::sys::stat::Mode;
+use termion::event::{Event, Key};
+
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::fs::FileProperties;
+use crate::ipc;
+use crate::run::process_event;
+use crate::types::command::Command;
+use crate::types::event::AppEvent;
+use crate::types::keybind::CommandKeybind;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::state::remove_external_preview;
+use crate::types::state::AppState;
+use crate::ui::views::TuiPropertiesView;
+use crate::ui::AppBackend;
+use crate::utils::{format, unix};
+
+use super::{set_mode, tab_ops};
+
+/// How times are shown in the properties view and how a new modification time is entered
+pub const MTIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// The mode bits shown as checkboxes, by owner, group, others and special bits
+pub const MODE_BITS: [[Mode; 3]; 4] = [
+    [Mode::S_IRUSR, Mode::S_IWUSR, Mode::S_IXUSR],
+    [Mode::S_IRGRP, Mode::S_IWGRP, Mode::S_IXGRP],
+    [Mode::S_IROTH, Mode::S_IWOTH, Mode::S_IXOTH],
+    [Mode::S_ISUID, Mode::S_ISGID, Mode::S_ISVTX],
+];
+
+/// The editable fields of the properties view
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PropertiesField {
+    Owner,
+    Group,
+    Modified,
+    // a checkbox of `MODE_BITS`
+    Mode { row: usize, col: usize },
+}
+
+impl PropertiesField {
+    fn up(self) -> Self {
+        match self {
+            Self::Owner | Self::Group => Self::Owner,
+            Self::Modified => Self::Group,
+            Self::Mode { row: 0, .. } => Self::Modified,
+            Self::Mode { row, col } => Self::Mode { row: row - 1, col },
+        }
+    }
+
+    fn down(self) -> Self {
+        match self {
+            Self::Owner => Self::Group,
+            Self::Group => Self::Modified,
+            Self::Modified => Self::Mode { row: 0, col: 0 },
+            Self::Mode { row, col } => Self::Mode {
+                row: (row + 1).min(MODE_BITS.len() - 1),
+                col,
+            },
+        }
+    }
+
+    fn left(self) -> Self {
+        match self {
+            Self::Mode { row, col } => Self::Mode {
+                row,
+                col: col.saturating_sub(1),
+            },
+            field => field,
+        }
+    }
+
+    fn right(self) -> Self {
+        match self {
+            Self::Mode { row, col } => Self::Mode {
+                row,
+                col: (col + 1).min(2),
+            },
+            field => field,
+        }
+    }
+}
+
+/// Returns the value all entries have in common, if any
+pub fn shared_value<T, F>(entries: &[FileProperties], f: F) -> Option<T>
+where
+    T: PartialEq,
+    F: Fn(&FileProperties) -> T,
+{
+    let mut values = entries.iter().map(f);
+    let first = values.next()?;
+    if values.all(|v| v == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+struct PropertiesView {
+    paths: Vec<path::PathBuf>,
+    entries: Vec<FileProperties>,
+    field: PropertiesField,
+    // the input of the field being edited
+    input: Option<String>,
+    status: String,
+    changed: bool,
+}
+
+impl PropertiesView {
+    fn reload(&mut self) {
+        self.entries = self
+            .paths
+            .iter()
+            .filter_map(|p| FileProperties::read(p).ok())
+            .collect();
+        if let [entry] = self.entries.as_mut_slice() {
+            entry.read_mimetype();
+        }
+    }
+
+    fn field_value(&self, field: PropertiesField) -> String {
+        match field {
+            PropertiesField::Owner => shared_value(&self.entries, |e| e.uid())
+                .map(|uid| unix::uid_to_string(uid).unwrap_or_else(|| uid.to_string()))
+                .unwrap_or_default(),
+            PropertiesField::Group => shared_value(&self.entries, |e| e.gid())
+                .map(|gid| unix::gid_to_string(gid).unwrap_or_else(|| gid.to_string()))
+                .unwrap_or_default(),
+            PropertiesField::Modified => shared_value(&self.entries, |e| e.modified())
+                .map(|mtime| format::time_to_string_with_format(mtime, MTIME_FORMAT))
+                .unwrap_or_default(),
+            PropertiesField::Mode { .. } => String::new(),
+        }
+    }
+
+    // toggles a mode bit, if only some entries have it set it is set for all of them
+    fn toggle_mode(&mut self, bit: Mode) -> AppResult {
+        let all_set = self.entries.iter().all(|e| e.mode().contains(bit));
+        for entry in &self.entries {
+            let mut mode = entry.mode();
+            mode.set(bit, !all_set);
+            set_mode::chmod(&entry.path, mode)?;
+        }
+        Ok(())
+    }
+
+    fn apply_input(&mut self, field: PropertiesField, input: &str) -> AppResult {
+        let input = input.trim();
+        match field {
+            PropertiesField::Owner => {
+                let uid = unix::string_to_uid(input).ok_or_else(|| {
+                    AppError::new(
+                        AppErrorKind::InvalidParameters,
+                        format!("Unknown user: {}", input),
+                    )
+                })?;
+                for entry in &self.entries {
+                    set_mode::chown(&entry.path, Some(uid), None)?;
+                }
+            }
+            PropertiesField::Group => {
+                let gid = unix::string_to_gid(input).ok_or_else(|| {
+                    AppError::new(
+                        AppErrorKind::InvalidParameters,
+                        format!("Unknown group: {}", input),
+                    )
+                })?;
+                for entry in &self.entries {
+                    set_mode::chown(&entry.path, None, Some(gid))?;
+                }
+            }
+            PropertiesField::Modified => {
+                let mtime = format::string_to_time(input).ok_or_else(|| {
+                    AppError::new(
+                        AppErrorKind::InvalidParameters,
+                        format!("Invalid time, expected {}: {}", MTIME_FORMAT, input),
+                    )
+                })?;
+                for entry in &self.entries {
+                    set_mode::set_mtime(&entry.path, mtime)?;
+                }
+            }
+            PropertiesField::Mode { .. } => {}
+        }
+        Ok(())
+    }
+
+    // checkboxes are toggled right away, the other fields are edited in place
+    fn activate(&mut self) {
+        match self.field {
+            PropertiesField::Mode { row, col } => {
+                let res = self.toggle_mode(MODE_BITS[row][col]);
+                self.finish_edit(res);
+            }
+            field => self.input = Some(self.field_value(field)),
+        }
+    }
+
+    fn commit_input(&mut self) {
+        if let Some(input) = self.input.take() {
+            let res = self.apply_input(self.field, &input);
+            self.finish_edit(res);
+        }
+    }
+
+    fn finish_edit(&mut self, res: AppResult) {
+        self.status = match res {
+            Ok(()) => String::new(),
+            Err(e) => e.to_string(),
+        };
+        self.changed = true;
+        self.reload();
+    }
+}
+
+/// Shows the full stat of the file under the cursor or of the selected files
+/// and allows changing their owner, group, mode and modification time
+pub fn properties_loop(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+) -> AppResult {
+    let paths: Vec<path::PathBuf> = match app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+    {
+        Some(curr_list) if curr_list.selected_count() > 0 => curr_list
+            .iter_selected()
+            .map(|e| e.file_path_buf())
+            .collect(),
+        Some(curr_list) => curr_list
+            .curr_entry_ref()
+            .map(|e| vec![e.file_path_buf()])
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+    if paths.is_empty() {
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            "No files selected".to_string(),
+        ));
+    }
+
+    app_state.flush_event();
+    remove_external_preview(app_state);
+
+    let dirs: Vec<path::PathBuf> = paths.iter().filter(|p| p.is_dir()).cloned().collect();
+    if !dirs.is_empty() {
+        app_state.state.dir_size_state_mut().compute(dirs);
+    }
+
+    let mut view = PropertiesView {
+        paths,
+        entries: Vec::new(),
+        field: PropertiesField::Owner,
+        input: None,
+        status: String::new(),
+        changed: false,
+    };
+    view.reload();
+
+    loop {
+        backend.render(TuiPropertiesView::new(
+            app_state,
+            &view.entries,
+            view.field,
+            view.input.as_deref(),
+            &view.status,
+        ));
+
+        let event = match app_state.poll_event() {
+            Ok(event) => event,
+            Err(_) => break,
+        };
+
+        let commands = match event {
+            AppEvent::Termion(Event::Key(key)) if view.input.is_some() => {
+                match key {
+                    Key::Char('\n') => view.commit_input(),
+                    Key::Esc => view.input = None,
+                    Key::Backspace => {
+                        if let Some(input) = view.input.as_mut() {
+                            input.pop();
+                        }
+                    }
+                    Key::Char(c) => {
+                        if let Some(input) = view.input.as_mut() {
+                            input.push(c);
+                        }
+                    }
+                    _ => {}
+                }
+                app_state.flush_event();
+                continue;
+            }
+            AppEvent::Termion(Event::Key(Key::Esc)) => break,
+            AppEvent::Termion(Event::Key(Key::Char(' ')))
+            | AppEvent::Termion(Event::Key(Key::Char('\n'))) => {
+                view.activate();
+                app_state.flush_event();
+                continue;
+            }
+            AppEvent::Termion(event) => {
+                let commands = match keymap_t.default_view.get(&event) {
+                    Some(CommandKeybind::SimpleKeybind { commands, .. }) => Some(commands),
+                    Some(CommandKeybind::CompositeKeybind(m)) => {
+                        process_event::poll_event_until_simple_keybind(app_state, backend, m)
+                    }
+                    None => None,
+                };
+                app_state.flush_event();
+                match commands {
+                    Some(commands) => commands.clone(),
+                    None => continue,
+                }
+            }
+            // commands sent through the IPC socket control the view as well
+            AppEvent::IpcCommand { command, reply } => {
+                let _ = reply.send(ipc::ok_response());
+                vec![command]
+            }
+            event => {
+                process_event::process_noninteractive(event, app_state);
+                continue;
+            }
+        };
+
+        for command in &commands {
+            match command {
+                Command::CursorMoveUp { .. } => view.field = view.field.up(),
+                Command::CursorMoveDown { .. } => view.field = view.field.down(),
+                Command::CursorMoveHome => view.field = PropertiesField::Owner,
+                Command::ParentDirectory => view.field = view.field.left(),
+                Command::ChangeDirectory { path } if path == path::Path::new("..") => {
+                    view.field = view.field.left()
+                }
+                Command::OpenFile => view.field = view.field.right(),
+                Command::ReloadDirList => view.reload(),
+                Command::Properties | Command::Quit(_) | Command::CloseTab => {
+                    return finish(app_state, &view)
+                }
+                _ => {}
+            }
+        }
+    }
+    finish(app_state, &view)
+}
+
+// reloads the directory lists showing the changed files
+fn finish(app_state: &mut AppState, view: &PropertiesView) -> AppResult {
+    if !view.changed {
+        return Ok(());
+    }
+    let curr_path = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .get_cwd()
+        .to_path_buf();
+    tab_ops::reload_all_tabs(app_state, &curr_path)?;
+    Ok(())
+}
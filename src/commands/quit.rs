@@ -1,4 +1,5 @@
 use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::types::option::hooks::Hook;
 use crate::types::state::AppState;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -24,6 +25,7 @@ impl QuitAction {
 
 pub fn quit_with_action(app_state: &mut AppState, quit_action: QuitAction) -> AppResult {
     if quit_action == QuitAction::Force {
+        run_quit_hook(app_state);
         app_state.quit = quit_action;
         return Ok(());
     }
@@ -35,7 +37,13 @@ pub fn quit_with_action(app_state: &mut AppState, quit_action: QuitAction) -> Ap
             String::from("operations running in background, use `quit --force` to quit"),
         ))
     } else {
+        run_quit_hook(app_state);
         app_state.quit = quit_action;
         Ok(())
     }
 }
+
+fn run_quit_hook(app_state: &AppState) {
+    let cwd = app_state.state.tab_state_ref().curr_tab_ref().get_cwd();
+    app_state.config.hooks.run_and_wait(Hook::Quit, [cwd]);
+}
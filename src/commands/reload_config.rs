@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use crate::config::app::AppConfig;
+use crate::config::bookmarks::Bookmarks;
+use crate::config::check::{self, Diagnostic, Severity};
+use crate::config::icon::AppIcons;
+use crate::config::macros::Macros;
+use crate::config::mimetype::AppProgramRegistry;
+use crate::config::preview::FileEntryPreview;
+use crate::config::theme::AppTheme;
+use crate::error::AppResult;
+use crate::traits::config::TomlConfigFile;
+use crate::types::config_type::ConfigType;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::state::AppState;
+use crate::{BOOKMARKS_T, ICONS_T, MACROS_T, MIMETYPE_T, PREVIEW_T, THEME_T};
+
+/// Re-reads all config files and swaps them in.
+/// If any of them can not be loaded, the current configs are kept.
+/// Other problems found by `check_config`, which startup accepts as well,
+/// are shown as warnings.
+pub fn reload_config(app_state: &mut AppState) -> AppResult {
+    let config = AppConfig::get_config_res()?;
+    let keymap = AppKeyMapping::get_config_res()?;
+    let theme = AppTheme::get_config_res()?;
+    let mimetype = AppProgramRegistry::get_config_res()?;
+    let preview = FileEntryPreview::get_config_res()?;
+    let icons = AppIcons::get_config_res()?;
+    let bookmarks = Bookmarks::get_config_res()?;
+    let macros = Macros::get_config_res()?;
+
+    THEME_T.set(theme);
+    MIMETYPE_T.set(mimetype);
+    PREVIEW_T.set(preview);
+    ICONS_T.set(icons);
+    if let Ok(mut curr_bookmarks) = BOOKMARKS_T.lock() {
+        *curr_bookmarks = bookmarks;
+    }
+    if let Ok(mut curr_macros) = MACROS_T.lock() {
+        *curr_macros = macros;
+    }
+    app_state.state.watch_config_dirs(config.watch_config);
+    app_state.config = config;
+    app_state.pending_keymap = Some(keymap);
+
+    let report = check::check_config();
+    for diagnostic in report.diagnostics {
+        let warning = Diagnostic {
+            severity: Severity::Warning,
+            ..diagnostic
+        };
+        app_state
+            .state
+            .message_queue_mut()
+            .push_info(warning.to_string());
+    }
+    app_state
+        .state
+        .message_queue_mut()
+        .push_success("Config reloaded".to_string());
+    Ok(())
+}
+
+/// Whether a changed path is one of the config files joshuto reads
+pub fn is_config_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map_or(false, |name| {
+            ConfigType::enumerate()
+                .iter()
+                .any(|config_type| config_type.as_filename() == name)
+        })
+}
@@ -63,13 +63,27 @@ fn _get_current_file_name(app_state: &mut AppState) -> Option<String> {
         .and_then(|list| list.curr_entry_ref().map(|s| s.file_name().to_string()))
 }
 
+// the command line for renaming to `before` + `after` with the cursor in between,
+// quoting the name if it contains characters that would split the command
+fn rename_command_line(before: &str, after: &str) -> (String, String) {
+    let name = format!("{}{}", before, after);
+    if shell_words::quote(&name) == name {
+        return (format!("rename {}", before), after.to_string());
+    }
+    let escape = |s: &str| s.replace('\'', "'\\''");
+    (
+        format!("rename '{}", escape(before)),
+        format!("{}'", escape(after)),
+    )
+}
+
 pub fn rename_file_append(
     app_state: &mut AppState,
     backend: &mut AppBackend,
     keymap_t: &AppKeyMapping,
 ) -> AppResult {
     if let Some(file_name) = _get_current_file_name(app_state) {
-        let (prefix, suffix) = (format!("rename {}", file_name), "".to_string());
+        let (prefix, suffix) = rename_command_line(&file_name, "");
         command_line::read_and_execute(app_state, backend, keymap_t, &prefix, &suffix)?;
     }
     Ok(())
@@ -81,12 +95,9 @@ pub fn rename_file_append_base(
     keymap_t: &AppKeyMapping,
 ) -> AppResult {
     if let Some(file_name) = _get_current_file_name(app_state) {
-        let (prefix, suffix): (String, String) = match file_name.rfind('.') {
-            Some(ext) => (
-                format!("rename {}", &file_name[0..ext]),
-                file_name[ext..].to_string(),
-            ),
-            None => (format!("rename {}", file_name), "".to_string()),
+        let (prefix, suffix) = match file_name.rfind('.') {
+            Some(ext) => rename_command_line(&file_name[0..ext], &file_name[ext..]),
+            None => rename_command_line(&file_name, ""),
         };
         command_line::read_and_execute(app_state, backend, keymap_t, &prefix, &suffix)?;
     }
@@ -99,7 +110,7 @@ pub fn rename_file_prepend(
     keymap_t: &AppKeyMapping,
 ) -> AppResult {
     if let Some(file_name) = _get_current_file_name(app_state) {
-        let (prefix, suffix) = ("rename ".to_string(), file_name);
+        let (prefix, suffix) = rename_command_line("", &file_name);
         command_line::read_and_execute(app_state, backend, keymap_t, &prefix, &suffix)?;
     }
     Ok(())
@@ -111,9 +122,9 @@ pub fn rename_file_keep_ext(
     keymap_t: &AppKeyMapping,
 ) -> AppResult {
     if let Some(file_name) = _get_current_file_name(app_state) {
-        let (prefix, suffix): (String, String) = match file_name.rfind('.') {
-            Some(ext) => ("rename ".to_string(), file_name[ext..].to_string()),
-            None => ("rename ".to_string(), "".to_string()),
+        let (prefix, suffix) = match file_name.rfind('.') {
+            Some(ext) => rename_command_line("", &file_name[ext..]),
+            None => rename_command_line("", ""),
         };
         command_line::read_and_execute(app_state, backend, keymap_t, &prefix, &suffix)?;
     }
@@ -0,0 +1,203 @@
+use std::path::PathBuf;
+
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::traits::config::search_config_directories;
+use crate::types::keymap::AppKeyMapping;
+use crate::types::state::AppState;
+use crate::ui::AppBackend;
+
+/// Directory inside the config directories that scripts are loaded from
+pub const SCRIPT_DIR: &str = "scripts";
+pub const SCRIPT_EXTENSION: &str = "rhai";
+
+/// Finds `scripts/<name>.rhai` in the config directories
+pub fn find_script(name: &str) -> Option<PathBuf> {
+    if name.is_empty() || name.contains('/') {
+        return None;
+    }
+    search_config_directories(&format!("{}/{}.{}", SCRIPT_DIR, name, SCRIPT_EXTENSION))
+}
+
+#[cfg(feature = "scripting")]
+pub fn run_script(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+    name: &str,
+    args: &[String],
+) -> AppResult {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{mpsc, Arc};
+    use std::thread;
+
+    use signal_hook::consts::signal::SIGINT;
+    use termion::event::{Event, Key};
+
+    use crate::run::process_event;
+    use crate::types::event::AppEvent;
+    use crate::ui::views::TuiView;
+
+    let path = find_script(name).ok_or_else(|| {
+        AppError::new(
+            AppErrorKind::InvalidParameters,
+            format!("Script '{}' not found", name),
+        )
+    })?;
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    // SIGINT cancels the script instead of ending joshuto while it runs
+    let sigint = signal_hook::flag::register(SIGINT, cancel.clone()).ok();
+
+    let (request_tx, request_rx) = mpsc::channel();
+    let handle = crate::scripting::spawn(path, args.to_vec(), request_tx, cancel.clone());
+    // requests are passed on as app events, so input is handled while the script runs
+    let event_tx = app_state.clone_event_tx();
+    let _ = thread::spawn(move || {
+        for request in request_rx {
+            let _ = event_tx.send(AppEvent::ScriptRequest(request));
+        }
+        let _ = event_tx.send(AppEvent::ScriptDone);
+    });
+
+    // serve the script until it finishes
+    app_state.flush_event();
+    loop {
+        backend.render(TuiView::new(app_state));
+        let event = match app_state.poll_event() {
+            Ok(event) => event,
+            Err(_) => break,
+        };
+        match event {
+            AppEvent::ScriptRequest(request) => {
+                handle_request(app_state, backend, keymap_t, request)
+            }
+            AppEvent::ScriptDone => break,
+            AppEvent::Termion(event) => {
+                if let Event::Key(Key::Ctrl('c')) | Event::Key(Key::Esc) = event {
+                    cancel.store(true, Ordering::Relaxed);
+                }
+                app_state.flush_event();
+            }
+            event => process_event::process_noninteractive(event, app_state),
+        }
+    }
+    if let Some(id) = sigint {
+        signal_hook::low_level::unregister(id);
+    }
+
+    match handle.join() {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(_)) if cancel.load(Ordering::Relaxed) => Err(AppError::new(
+            AppErrorKind::Script,
+            format!("Script '{}' cancelled", name),
+        )),
+        Ok(Err(msg)) => Err(AppError::new(
+            AppErrorKind::Script,
+            format!("{}: {}", name, msg),
+        )),
+        Err(_) => Err(AppError::new(
+            AppErrorKind::InternalError,
+            format!("Script '{}' panicked", name),
+        )),
+    }
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn run_script(
+    _app_state: &mut AppState,
+    _backend: &mut AppBackend,
+    _keymap_t: &AppKeyMapping,
+    _name: &str,
+    _args: &[String],
+) -> AppResult {
+    Err(AppError::new(
+        AppErrorKind::Script,
+        String::from("joshuto was built without scripting support"),
+    ))
+}
+
+#[cfg(feature = "scripting")]
+fn handle_request(
+    app_state: &mut AppState,
+    backend: &mut AppBackend,
+    keymap_t: &AppKeyMapping,
+    request: crate::scripting::ScriptRequest,
+) {
+    use std::str::FromStr;
+
+    use termion::event::Key;
+
+    use crate::scripting::{ScriptMessage, ScriptRequest};
+    use crate::traits::app_execute::AppExecute;
+    use crate::types::command::Command;
+    use crate::ui::views::{DummyListener, TuiTextField};
+    use crate::ui::widgets::TuiPrompt;
+
+    let curr_tab = app_state.state.tab_state_ref().curr_tab_ref();
+    // the script may have stopped waiting for a reply, so send errors are ignored
+    match request {
+        ScriptRequest::Cwd(reply) => {
+            let _ = reply.send(curr_tab.get_cwd().to_string_lossy().to_string());
+        }
+        ScriptRequest::TabIndex(reply) => {
+            let _ = reply.send(app_state.state.tab_state_ref().index);
+        }
+        ScriptRequest::Entries(reply) => {
+            let entries = curr_tab
+                .curr_list_ref()
+                .map(|list| {
+                    list.iter()
+                        .map(|entry| entry.file_name().to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let _ = reply.send(entries);
+        }
+        ScriptRequest::Selection(reply) => {
+            let paths = curr_tab
+                .curr_list_ref()
+                .map(|list| {
+                    list.get_selected_paths()
+                        .iter()
+                        .map(|path| path.to_string_lossy().to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let _ = reply.send(paths);
+        }
+        ScriptRequest::Current(reply) => {
+            let path = curr_tab
+                .curr_list_ref()
+                .and_then(|list| list.curr_entry_ref())
+                .map(|entry| entry.file_path().to_string_lossy().to_string());
+            let _ = reply.send(path);
+        }
+        ScriptRequest::Message(kind, msg) => {
+            let message_queue = app_state.state.message_queue_mut();
+            match kind {
+                ScriptMessage::Info => message_queue.push_info(msg),
+                ScriptMessage::Success => message_queue.push_success(msg),
+                ScriptMessage::Error => message_queue.push_error(msg),
+            }
+        }
+        ScriptRequest::Prompt(text, reply) => {
+            app_state.flush_event();
+            let mut listener = DummyListener {};
+            let input =
+                TuiTextField::default()
+                    .prompt(&text)
+                    .get_input(app_state, backend, &mut listener);
+            let _ = reply.send(input);
+        }
+        ScriptRequest::Confirm(text, reply) => {
+            let key = TuiPrompt::new(&text).get_key(app_state, backend);
+            let _ = reply.send(matches!(key, Key::Char('y') | Key::Char('Y')));
+        }
+        ScriptRequest::Execute(s, reply) => {
+            let res = Command::from_str(&s)
+                .and_then(|command| command.execute(app_state, backend, keymap_t))
+                .map_err(|e| e.to_string());
+            let _ = reply.send(res);
+        }
+    }
+}
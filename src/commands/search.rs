@@ -35,9 +35,9 @@ pub(super) fn search_next_impl(
     let contents_len = curr_list.contents.len();
 
     for i in 0..contents_len {
-        let file_name = curr_list.contents[(offset + i) % contents_len].file_name();
+        let entry = &curr_list.contents[(offset + i) % contents_len];
 
-        if match_state.is_match(file_name) {
+        if match_state.is_match_entry(entry) {
             return Some((offset + i) % contents_len);
         }
     }
@@ -76,9 +76,9 @@ fn search_prev_impl(
     let contents_len = curr_list.contents.len();
 
     for i in (0..contents_len).rev() {
-        let file_name = curr_list.contents[(offset + i) % contents_len].file_name();
+        let entry = &curr_list.contents[(offset + i) % contents_len];
 
-        if match_state.is_match(file_name) {
+        if match_state.is_match_entry(entry) {
             return Some((offset + i) % contents_len);
         }
     }
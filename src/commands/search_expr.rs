@@ -0,0 +1,24 @@
+use crate::error::AppResult;
+use crate::types::state::{AppState, MatchState};
+
+use super::cursor_move;
+use super::search;
+
+pub fn search_expr(app_state: &mut AppState, expr: &str) -> AppResult {
+    let search_state = MatchState::new_expr(expr)?;
+
+    let curr_tab = &app_state.state.tab_state_ref().curr_tab_ref();
+    let index = curr_tab.curr_list_ref().and_then(|c| c.get_index());
+
+    let offset = match index {
+        Some(index) => index + 1,
+        None => return Ok(()),
+    };
+
+    if let Some(new_index) = search::search_next_impl(curr_tab, &search_state, offset) {
+        cursor_move::cursor_move(app_state, new_index);
+    }
+
+    app_state.state.set_search_state(search_state);
+    Ok(())
+}
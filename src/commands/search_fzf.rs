@@ -12,8 +12,7 @@ pub fn search_fzf(app_state: &mut AppState, backend: &mut AppBackend) -> AppResu
         .map(|list| {
             let v: Vec<String> = list
                 .iter()
-                .enumerate()
-                .map(|(i, entry)| format!("{} {}\n", i, entry.file_name()))
+                .map(|entry| entry.file_name().to_string())
                 .collect();
             v
         })
@@ -26,13 +25,8 @@ pub fn search_fzf(app_state: &mut AppState, backend: &mut AppBackend) -> AppResu
         ));
     }
 
-    let fzf_output = fzf::fzf(app_state, backend, items)?;
-    let selected_idx_str = fzf_output.split_once(' ');
-
-    if let Some((selected_idx_str, _)) = selected_idx_str {
-        if let Ok(index) = selected_idx_str.parse::<usize>() {
-            cursor_move::cursor_move(app_state, index);
-        }
+    if let Some(index) = fzf::fzf(app_state, backend, "Search", &items) {
+        cursor_move::cursor_move(app_state, index);
     }
 
     Ok(())
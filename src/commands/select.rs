@@ -1,4 +1,5 @@
 use crate::error::AppResult;
+use crate::types::option::hooks::Hook;
 use crate::types::state::{AppState, MatchState};
 
 use super::cursor_move;
@@ -36,9 +37,26 @@ pub fn select_files(
     options: &SelectOption,
 ) -> AppResult {
     if pattern.is_none() {
-        select_without_pattern(app_state, options)
+        select_without_pattern(app_state, options)?;
     } else {
-        select_with_pattern(app_state, pattern, options)
+        select_with_pattern(app_state, pattern, options)?;
+    }
+    run_select_hook(app_state);
+    Ok(())
+}
+
+/// Passes the currently selected files to the `on_select` hook
+pub fn run_select_hook(app_state: &AppState) {
+    if let Some(curr_list) = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+    {
+        app_state.config.hooks.run(
+            Hook::Select,
+            curr_list.iter_selected().map(|e| e.file_path()),
+        );
     }
 }
 
@@ -93,7 +111,7 @@ fn select_with_pattern(
         let mut found = 0;
         curr_list
             .iter_mut()
-            .filter(|e| pattern.is_match(e.file_name()))
+            .filter(|e| pattern.is_match_entry(e))
             .for_each(|e| {
                 found += 1;
                 if options.reverse {
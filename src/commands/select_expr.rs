@@ -0,0 +1,9 @@
+use crate::error::AppResult;
+use crate::types::state::{AppState, MatchState};
+
+use super::select::{self, SelectOption};
+
+pub fn select_expr(app_state: &mut AppState, expr: &str, options: &SelectOption) -> AppResult {
+    let select_state = MatchState::new_expr(expr)?;
+    select::select_files(app_state, &select_state, options)
+}
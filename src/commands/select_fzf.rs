@@ -3,7 +3,7 @@ use crate::types::state::AppState;
 use crate::ui::AppBackend;
 
 use super::fzf;
-use super::select::SelectOption;
+use super::select::{self, SelectOption};
 
 pub fn select_fzf(
     app_state: &mut AppState,
@@ -18,8 +18,7 @@ pub fn select_fzf(
         .map(|list| {
             let v: Vec<String> = list
                 .iter()
-                .enumerate()
-                .map(|(i, entry)| format!("{} {}\n", i, entry.file_name()))
+                .map(|entry| entry.file_name().to_string())
                 .collect();
             v
         })
@@ -32,7 +31,7 @@ pub fn select_fzf(
         ));
     }
 
-    let fzf_output = fzf::fzf_multi(app_state, backend, items)?;
+    let picked = fzf::fzf_multi(app_state, backend, "Select", &items);
 
     if let Some(curr_list) = app_state
         .state
@@ -42,19 +41,16 @@ pub fn select_fzf(
     {
         let mut found = 0;
 
-        for selected in fzf_output.lines() {
-            if let Some((selected_idx_str, _)) = selected.split_once(' ') {
-                if let Ok(index) = selected_idx_str.parse::<usize>() {
-                    let entry = curr_list.contents.get_mut(index).unwrap();
-                    found += 1;
+        for index in picked {
+            if let Some(entry) = curr_list.contents.get_mut(index) {
+                found += 1;
 
-                    if options.reverse {
-                        entry.set_permanent_selected(false);
-                    } else if options.toggle {
-                        entry.set_permanent_selected(!entry.is_selected());
-                    } else {
-                        entry.set_permanent_selected(true);
-                    }
+                if options.reverse {
+                    entry.set_permanent_selected(false);
+                } else if options.toggle {
+                    entry.set_permanent_selected(!entry.is_selected());
+                } else {
+                    entry.set_permanent_selected(true);
                 }
             }
         }
@@ -64,6 +60,7 @@ pub fn select_fzf(
             .message_queue_mut()
             .push_info(format!("{} files selected", found));
     }
+    select::run_select_hook(app_state);
 
     Ok(())
 }
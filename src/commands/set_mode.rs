@@ -1,4 +1,10 @@
+use std::path::Path;
+use std::time::SystemTime;
+use std::{fs, io};
+
+use filetime::FileTime;
 use nix::sys::stat::{fchmodat, FchmodatFlags, Mode};
+use nix::unistd::{fchownat, FchownatFlags, Gid, Uid};
 
 use crate::error::{AppError, AppErrorKind, AppResult};
 use crate::types::state::AppState;
@@ -19,6 +25,42 @@ pub fn str_to_mode(s: &str) -> Mode {
     mode
 }
 
+/// Changes the permissions of a file, symlinks are not followed
+pub fn chmod(path: &Path, mode: Mode) -> AppResult {
+    fchmodat(None, path, mode, FchmodatFlags::NoFollowSymlink).map_err(|err| {
+        let error_msg = format!("Failed to set file permissions: {err}");
+        AppError::new(AppErrorKind::Io, error_msg)
+    })
+}
+
+/// Changes the owner and/or group of a file, symlinks are not followed
+pub fn chown(path: &Path, uid: Option<u32>, gid: Option<u32>) -> AppResult {
+    fchownat(
+        None,
+        path,
+        uid.map(Uid::from_raw),
+        gid.map(Gid::from_raw),
+        FchownatFlags::NoFollowSymlink,
+    )
+    .map_err(|err| {
+        let error_msg = format!("Failed to set file owner: {err}");
+        AppError::new(AppErrorKind::Io, error_msg)
+    })
+}
+
+/// Changes the modification time of a file and keeps its access time,
+/// symlinks are not followed
+pub fn set_mtime(path: &Path, mtime: SystemTime) -> AppResult {
+    let res: io::Result<()> = fs::symlink_metadata(path).and_then(|metadata| {
+        let atime = FileTime::from_last_access_time(&metadata);
+        filetime::set_symlink_file_times(path, atime, FileTime::from_system_time(mtime))
+    });
+    res.map_err(|err| {
+        let error_msg = format!("Failed to set modification time: {err}");
+        AppError::new(AppErrorKind::Io, error_msg)
+    })
+}
+
 pub fn set_mode(app_state: &mut AppState, backend: &mut AppBackend) -> AppResult {
     const PREFIX: &str = "set_mode ";
     let entry = app_state
@@ -55,29 +97,11 @@ pub fn set_mode(app_state: &mut AppState, backend: &mut AppBackend) -> AppResult
             {
                 if curr_list.selected_count() > 0 {
                     for entry in curr_list.iter_selected_mut() {
-                        fchmodat(
-                            None,
-                            entry.file_path(),
-                            mode,
-                            FchmodatFlags::NoFollowSymlink,
-                        )
-                        .map_err(|err| {
-                            let error_msg = format!("Failed to set file permissions: {err}");
-                            AppError::new(AppErrorKind::Io, error_msg)
-                        })?;
+                        chmod(entry.file_path(), mode)?;
                         entry.metadata.mode = mode;
                     }
                 } else if let Some(entry) = curr_list.curr_entry_mut() {
-                    fchmodat(
-                        None,
-                        entry.file_path(),
-                        mode,
-                        FchmodatFlags::NoFollowSymlink,
-                    )
-                    .map_err(|err| {
-                        let error_msg = format!("Failed to set file permissions: {err}");
-                        AppError::new(AppErrorKind::Io, error_msg)
-                    })?;
+                    chmod(entry.file_path(), mode)?;
                     entry.metadata.mode = mode;
                     cursor_move::down(app_state, 1)?;
                 }
@@ -2,6 +2,7 @@ use crate::error::AppResult;
 use crate::history::DirectoryHistory;
 use crate::types::state::AppState;
 
+use super::dir_view::update_curr_dir_view;
 use super::reload;
 
 pub fn _toggle_hidden(app_state: &mut AppState) {
@@ -17,7 +18,15 @@ pub fn _toggle_hidden(app_state: &mut AppState) {
 }
 
 pub fn toggle_hidden(app_state: &mut AppState) -> AppResult {
-    _toggle_hidden(app_state);
+    let display_options = &app_state.config.display_options;
+    let cwd = app_state.state.tab_state_ref().curr_tab_ref().get_cwd();
+    let show_hidden = !display_options
+        .dir_views
+        .show_hidden(cwd)
+        .unwrap_or(display_options.show_hidden);
+    if !update_curr_dir_view(app_state, |view| view.show_hidden = Some(show_hidden))? {
+        _toggle_hidden(app_state);
+    }
     reload::soft_reload_curr_tab(app_state)?;
     Ok(())
 }
@@ -3,9 +3,20 @@ use crate::history::DirectoryHistory;
 use crate::types::option::sort::SortMethod;
 use crate::types::state::AppState;
 
+use super::dir_view::update_curr_dir_view;
 use super::reload;
 
 pub fn set_sort(app_state: &mut AppState, method: SortMethod, reverse: Option<bool>) -> AppResult {
+    let is_dir_view = update_curr_dir_view(app_state, |view| {
+        view.sort_method = Some(method);
+        if reverse.is_some() {
+            view.reverse = reverse;
+        }
+    })?;
+    if is_dir_view {
+        return refresh(app_state);
+    }
+
     let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
     curr_tab
         .option_mut()
@@ -21,6 +32,17 @@ pub fn set_sort(app_state: &mut AppState, method: SortMethod, reverse: Option<bo
 }
 
 pub fn toggle_reverse(app_state: &mut AppState) -> AppResult {
+    let curr_tab = app_state.state.tab_state_ref().curr_tab_ref();
+    let reversed = !app_state
+        .config
+        .display_options
+        .dir_views
+        .sort_options(curr_tab.get_cwd(), curr_tab.option_ref().sort_options_ref())
+        .reverse;
+    if update_curr_dir_view(app_state, |view| view.reverse = Some(reversed))? {
+        return refresh(app_state);
+    }
+
     let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
     let reversed = !curr_tab.option_mut().sort_options_ref().reverse;
     curr_tab.option_mut().sort_options_mut().reverse = reversed;
@@ -1,16 +1,56 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use uuid::Uuid;
 
 use crate::error::AppResult;
-use crate::types::state::AppState;
+use crate::fs::find_files;
+use crate::types::event::AppEvent;
+use crate::types::state::{AppState, MatchState};
 use crate::ui::AppBackend;
 
 use super::change_directory::change_directory;
 use super::fzf;
 
 pub fn subdir_fzf(app_state: &mut AppState, backend: &mut AppBackend) -> AppResult {
-    let fzf_output = fzf::fzf(app_state, backend, Vec::new())?;
-    let path: PathBuf = PathBuf::from(fzf_output);
-    fzf_change_dir(app_state, path.as_path())
+    let cwd = app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .get_cwd()
+        .to_path_buf();
+
+    // list the paths below the current directory in the background,
+    // leaving out hidden and ignored files, so the picker opens right away
+    let id = Uuid::new_v4();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let event_tx = app_state.clone_event_tx();
+    let display_options = &app_state.config.display_options;
+    let (show_hidden, show_ignored) = (display_options.show_hidden, display_options.show_ignored);
+    let thread_root = cwd.clone();
+    let thread_cancel = cancel.clone();
+    let _ = thread::spawn(move || {
+        let res = find_files(
+            &thread_root,
+            &MatchState::None,
+            show_hidden,
+            show_ignored,
+            &thread_cancel,
+            |paths| {
+                let _ = event_tx.send(AppEvent::FindMatches { id, paths });
+            },
+        );
+        let _ = event_tx.send(AppEvent::FindDone { id, res });
+    });
+
+    let picked = fzf::fzf_find_results(app_state, backend, "Go to", id, &cwd);
+    cancel.store(true, Ordering::Relaxed);
+    if let Some(path) = picked {
+        fzf_change_dir(app_state, &path)?;
+    }
+    Ok(())
 }
 
 pub fn fzf_change_dir(app_state: &mut AppState, path: &Path) -> AppResult {
@@ -9,6 +9,8 @@ use crate::history::{
     create_dirlist_with_history, generate_entries_to_root, DirectoryHistory, JoshutoHistory,
 };
 use crate::tab::{JoshutoTab, NewTabMode, TabHomePage};
+use crate::types::option::display::DisplayMode;
+use crate::types::option::hooks::Hook;
 use crate::types::state::AppState;
 use crate::utils::{cwd, unix};
 
@@ -25,6 +27,13 @@ fn _tab_switch(new_index: usize, app_state: &mut AppState) -> std::io::Result<()
         .get_cwd()
         .to_path_buf();
     cwd::set_current_dir(cwd.as_path())?;
+    app_state.config.hooks.run(
+        Hook::TabChange,
+        [
+            (new_index + 1).to_string(),
+            cwd.to_string_lossy().into_owned(),
+        ],
+    );
 
     let entry_path = match app_state
         .state
@@ -109,6 +118,48 @@ pub fn tab_switch_index(app_state: &mut AppState, new_index: usize) -> AppResult
     Ok(())
 }
 
+/// Index of the tab shown next to the current one in the split display modes.
+/// Tabs are paired up, so the first and second tab share the screen, the third and fourth, and so on.
+pub fn pane_partner_index(index: usize) -> usize {
+    index ^ 1
+}
+
+/// The tab shown in the other pane, if both panes are shown
+pub fn other_pane_tab(app_state: &AppState) -> AppResult<&JoshutoTab> {
+    if !matches!(
+        app_state.config.display_options.mode,
+        DisplayMode::DualPane | DisplayMode::HSplit
+    ) {
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            "No other pane, switch to the dualpane display mode first".to_string(),
+        ));
+    }
+    let tab_state = app_state.state.tab_state_ref();
+    tab_state
+        .tab_order
+        .get(pane_partner_index(tab_state.index))
+        .and_then(|id| tab_state.tab_ref(id))
+        .ok_or_else(|| {
+            AppError::new(
+                AppErrorKind::InvalidParameters,
+                "The other pane is not open".to_string(),
+            )
+        })
+}
+
+/// Switches to the other pane, opening it in the current directory if needed
+pub fn pane_switch(app_state: &mut AppState) -> AppResult {
+    let index = app_state.state.tab_state_ref().index;
+    let other_index = pane_partner_index(index);
+    if other_index < app_state.state.tab_state_ref().len() {
+        _tab_switch(other_index, app_state)?;
+        Ok(())
+    } else {
+        new_tab(app_state, &NewTabMode::CurrentTabDir, true)
+    }
+}
+
 pub fn new_tab_home_path(app_state: &AppState) -> path::PathBuf {
     match app_state.config.tab_options.home_page {
         TabHomePage::Home => match HOME_DIR.as_ref() {
@@ -230,6 +281,25 @@ pub fn close_tab(app_state: &mut AppState) -> AppResult {
     Ok(())
 }
 
+/// Closes the tab at `index`, the current tab stays unless it is the one closed
+pub fn close_tab_index(app_state: &mut AppState, index: usize) -> AppResult {
+    let curr_index = app_state.state.tab_state_ref().index;
+    if index == curr_index {
+        return close_tab(app_state);
+    }
+    // the current tab stays, so there is no tab switch and no hook to run
+    let tab_state = app_state.state.tab_state_mut();
+    let id = match tab_state.tab_order.get(index) {
+        Some(id) => *id,
+        None => return Ok(()),
+    };
+    tab_state.remove_tab(&id);
+    if index < curr_index {
+        tab_state.index = curr_index - 1;
+    }
+    Ok(())
+}
+
 pub fn reload_all_tabs(app_state: &mut AppState, curr_path: &Path) -> io::Result<()> {
     let mut map = HashMap::new();
     {
@@ -9,6 +9,8 @@ use crate::error::AppResult;
 use crate::history::create_dirlist_with_history;
 use crate::types::state::AppState;
 
+use super::find;
+
 fn _update_actime(file: &path::Path) -> std::io::Result<()> {
     let file_time = FileTime::from_system_time(SystemTime::now());
     filetime::set_file_times(file, file_time, file_time)
@@ -39,6 +41,7 @@ pub fn touch_file(app_state: &mut AppState, arg: &str) -> AppResult {
             if file.exists() {
                 _update_actime(file.as_path())?;
             } else {
+                find::ensure_not_find_results(curr_tab, curr_tab.get_cwd())?;
                 _create_file(file.as_path())?;
             }
         }
@@ -0,0 +1,98 @@
+use std::path;
+
+use crate::error::AppResult;
+use crate::fs::{JoshutoDirEntry, LinkType};
+use crate::types::state::AppState;
+
+use super::{cursor_move, reload};
+
+fn is_expandable(entry: &JoshutoDirEntry) -> bool {
+    // symlinks are not followed when reading the tree
+    entry.metadata.is_dir() && matches!(entry.metadata.link_type(), LinkType::Normal)
+}
+
+fn reload_tree(app_state: &mut AppState) -> AppResult {
+    if let Some(list) = app_state
+        .state
+        .tab_state_mut()
+        .curr_tab_mut()
+        .curr_list_mut()
+    {
+        list.depreciate();
+    }
+    reload::soft_reload_curr_tab(app_state)?;
+    Ok(())
+}
+
+/// Expands or collapses the directory under the cursor.
+/// On a file inside of an expanded directory, collapses that directory.
+pub fn tree_toggle(app_state: &mut AppState) -> AppResult {
+    let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
+    let cwd = curr_tab.get_cwd().to_path_buf();
+    let (path, expandable) = match curr_tab.curr_list_ref().and_then(|l| l.curr_entry_ref()) {
+        Some(entry) => (entry.file_path_buf(), is_expandable(entry)),
+        None => return Ok(()),
+    };
+
+    let options = curr_tab.option_mut().dirlist_options_mut(&cwd);
+    let collapsed_parent: Option<path::PathBuf> = if expandable {
+        let expanded = options.is_expanded(&path);
+        options.set_expanded(&path, !expanded);
+        None
+    } else {
+        match path.parent() {
+            Some(parent) if parent != cwd => {
+                options.set_expanded(parent, false);
+                Some(parent.to_path_buf())
+            }
+            _ => return Ok(()),
+        }
+    };
+    reload_tree(app_state)?;
+
+    // keep the cursor on the directory that was collapsed
+    if let Some(parent) = collapsed_parent {
+        let index = app_state
+            .state
+            .tab_state_ref()
+            .curr_tab_ref()
+            .curr_list_ref()
+            .and_then(|list| list.iter().position(|e| e.file_path() == parent));
+        if let Some(index) = index {
+            cursor_move::cursor_move(app_state, index);
+        }
+    }
+    Ok(())
+}
+
+/// Expands every directory that is shown, so each use reveals one more level
+pub fn tree_expand_all(app_state: &mut AppState) -> AppResult {
+    let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
+    let cwd = curr_tab.get_cwd().to_path_buf();
+    let dirs: Vec<path::PathBuf> = curr_tab
+        .curr_list_ref()
+        .map(|list| {
+            list.iter()
+                .filter(|e| is_expandable(e))
+                .map(|e| e.file_path_buf())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let options = curr_tab.option_mut().dirlist_options_mut(&cwd);
+    for dir in dirs {
+        options.set_expanded(&dir, true);
+    }
+    reload_tree(app_state)
+}
+
+/// Collapses all directories, showing the plain list again
+pub fn tree_collapse(app_state: &mut AppState) -> AppResult {
+    let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
+    let cwd = curr_tab.get_cwd().to_path_buf();
+    curr_tab
+        .option_mut()
+        .dirlist_options_mut(&cwd)
+        .collapse_all();
+    reload_tree(app_state)
+}
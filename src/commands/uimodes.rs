@@ -1,4 +1,5 @@
-use crate::error::AppResult;
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::types::keymap::AppKeyMapping;
 use crate::types::state::AppState;
 
 pub fn toggle_visual_mode(app_state: &mut AppState) -> AppResult {
@@ -12,3 +13,19 @@ pub fn toggle_visual_mode(app_state: &mut AppState) -> AppResult {
     };
     Ok(())
 }
+
+pub fn push_mode(app_state: &mut AppState, keymap_t: &AppKeyMapping, name: &str) -> AppResult {
+    if !keymap_t.modes.contains_key(name) {
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            format!("Unknown mode '{}'", name),
+        ));
+    }
+    app_state.state.mode_stack.push(name.to_string());
+    Ok(())
+}
+
+pub fn pop_mode(app_state: &mut AppState) -> AppResult {
+    app_state.state.mode_stack.pop();
+    Ok(())
+}
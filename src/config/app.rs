@@ -7,8 +7,12 @@ use crate::{
     traits::config::TomlConfigFile,
     types::{
         config_type::ConfigType,
+        context_menu_entry::ContextMenuEntry,
         custom_command::CustomCommand,
-        option::{display::DisplayOption, preview::PreviewOption, search::SearchOption},
+        option::{
+            clipboard::ClipboardOption, display::DisplayOption, hooks::HookOption,
+            preview::PreviewOption, search::SearchOption,
+        },
     },
 };
 
@@ -19,7 +23,9 @@ pub struct AppConfig {
     pub xdg_open_fork: bool,
     pub case_insensitive_ext: bool,
     pub watch_files: bool,
+    pub watch_config: bool,
     pub custom_commands: Vec<CustomCommand>,
+    pub context_menu: Vec<ContextMenuEntry>,
     pub focus_on_create: bool,
     pub mouse_support: bool,
     pub cmd_aliases: HashMap<String, String>,
@@ -28,6 +34,8 @@ pub struct AppConfig {
     pub preview_options: PreviewOption,
     pub search_options: SearchOption,
     pub tab_options: TabOption,
+    pub clipboard_options: ClipboardOption,
+    pub hooks: HookOption,
 }
 
 impl AppConfig {
@@ -61,6 +69,7 @@ impl From<AppConfigRaw> for AppConfig {
             xdg_open_fork: raw.xdg_open_fork,
             case_insensitive_ext: raw.case_insensitive_ext,
             watch_files: raw.watch_files,
+            watch_config: raw.watch_config,
             cmd_aliases: raw.cmd_aliases,
             focus_on_create: raw.focus_on_create,
             mouse_support: raw.mouse_support,
@@ -69,7 +78,10 @@ impl From<AppConfigRaw> for AppConfig {
             preview_options: PreviewOption::from(raw.preview_options),
             search_options: raw.search_options,
             tab_options: raw.tab_options,
+            clipboard_options: raw.clipboard_options,
             custom_commands: raw.custom_commands,
+            context_menu: raw.context_menu,
+            hooks: HookOption::from(raw.hooks),
         }
     }
 }
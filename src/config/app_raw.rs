@@ -3,10 +3,13 @@ use std::collections::HashMap;
 use serde::Deserialize;
 
 use super::display_raw::DisplayOptionRaw;
+use super::hooks_raw::HooksRaw;
 use super::preview::preview_option_raw::PreviewOptionRaw;
 use super::tab::TabOption;
 
+use crate::types::context_menu_entry::ContextMenuEntry;
 use crate::types::custom_command::CustomCommand;
+use crate::types::option::clipboard::ClipboardOption;
 use crate::types::option::search::SearchOption;
 use crate::utils::serde::default_true;
 
@@ -22,6 +25,8 @@ pub struct AppConfigRaw {
     pub xdg_open_fork: bool,
     #[serde(default = "default_true")]
     pub watch_files: bool,
+    #[serde(default)]
+    pub watch_config: bool,
     #[serde(default = "default_true")]
     pub focus_on_create: bool,
     #[serde(default = "default_true")]
@@ -38,6 +43,12 @@ pub struct AppConfigRaw {
     pub search_options: SearchOption,
     #[serde(default, rename = "tab")]
     pub tab_options: TabOption,
+    #[serde(default, rename = "clipboard")]
+    pub clipboard_options: ClipboardOption,
     #[serde(default)]
     pub custom_commands: Vec<CustomCommand>,
+    #[serde(default)]
+    pub context_menu: Vec<ContextMenuEntry>,
+    #[serde(default)]
+    pub hooks: HooksRaw,
 }
@@ -0,0 +1,493 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use termion::event::{Event, Key};
+use toml::Spanned;
+
+use crate::config::app_raw::AppConfigRaw;
+use crate::config::bookmarks::BookmarksRaw;
+use crate::config::icon_raw::IconsRaw;
+use crate::config::keymap_raw::{AppKeyMappingRaw, CommandKeymapRaw};
+use crate::config::macros::{self, MacrosRaw};
+use crate::config::mimetype_raw::AppProgramRegistryRaw;
+use crate::config::preview::FileEntryPreview;
+use crate::config::theme::theme_raw::AppThemeRaw;
+use crate::traits::config::search_config_directories;
+use crate::types::command::Command;
+use crate::types::config_type::ConfigType;
+use crate::utils::keyparse::str_to_event;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub file_name: String,
+    pub line: Option<usize>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(
+                f,
+                "{}:{}: {}: {}",
+                self.file_name, line, self.severity, self.message
+            ),
+            None => write!(f, "{}: {}: {}", self.file_name, self.severity, self.message),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ConfigReport {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ConfigReport {
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|d| d.severity == Severity::Error)
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+
+    fn push(&mut self, file_name: &str, line: Option<usize>, severity: Severity, message: String) {
+        self.diagnostics.push(Diagnostic {
+            file_name: file_name.to_string(),
+            line,
+            severity,
+            message,
+        });
+    }
+}
+
+// same as AppModeKeyMappingRaw, but keeps track of where each keymap was defined
+#[derive(Clone, Debug, Deserialize)]
+struct SpannedModeKeyMappingRaw {
+    #[serde(default)]
+    keymap: Vec<Spanned<CommandKeymapRaw>>,
+}
+
+// same as AppKeyMappingRaw, but keeps track of where each keymap was defined
+#[derive(Clone, Debug, Deserialize)]
+struct SpannedKeyMappingRaw {
+    default_view: SpannedModeKeyMappingRaw,
+    task_view: SpannedModeKeyMappingRaw,
+    help_view: SpannedModeKeyMappingRaw,
+    visual_mode: Option<SpannedModeKeyMappingRaw>,
+    filter_mode: Option<SpannedModeKeyMappingRaw>,
+    #[serde(default)]
+    mode: HashMap<String, SpannedModeKeyMappingRaw>,
+}
+
+// a successfully parsed key sequence along with where it was defined
+struct ParsedKeymap<'a> {
+    line: usize,
+    keys: &'a [String],
+    events: Vec<Event>,
+}
+
+/// Parses every config file found in the config hierarchy and reports
+/// problems that would otherwise be silently ignored at startup.
+pub fn check_config() -> ConfigReport {
+    let mut report = ConfigReport::default();
+    for config_type in ConfigType::enumerate() {
+        let file_name = config_type.as_filename();
+        let file_path = match search_config_directories(file_name) {
+            Some(p) => p,
+            None => continue,
+        };
+        match fs::read_to_string(&file_path) {
+            Ok(contents) => check_config_str(&mut report, *config_type, &file_path, &contents),
+            Err(e) => report.push(
+                &file_path.to_string_lossy(),
+                None,
+                Severity::Error,
+                e.to_string(),
+            ),
+        }
+    }
+    report
+}
+
+fn check_config_str(
+    report: &mut ConfigReport,
+    config_type: ConfigType,
+    file_path: &Path,
+    contents: &str,
+) {
+    let file_name = file_path.to_string_lossy();
+    match config_type {
+        ConfigType::App => check_toml::<AppConfigRaw>(report, &file_name, contents),
+        ConfigType::Mimetype => check_toml::<AppProgramRegistryRaw>(report, &file_name, contents),
+        ConfigType::Theme => check_toml::<AppThemeRaw>(report, &file_name, contents),
+        ConfigType::Preview => check_toml::<FileEntryPreview>(report, &file_name, contents),
+        ConfigType::Icons => check_toml::<IconsRaw>(report, &file_name, contents),
+        ConfigType::Bookmarks => check_bookmarks(report, &file_name, contents),
+        ConfigType::Keymap => check_keymap(report, &file_name, contents),
+        ConfigType::Macros => check_macros(report, &file_name, contents),
+    }
+}
+
+fn check_toml<T: DeserializeOwned>(report: &mut ConfigReport, file_name: &str, contents: &str) {
+    let _ = parse_toml::<T>(report, file_name, contents);
+}
+
+fn parse_toml<T: DeserializeOwned>(
+    report: &mut ConfigReport,
+    file_name: &str,
+    contents: &str,
+) -> Option<T> {
+    match toml::from_str::<T>(contents) {
+        Ok(t) => Some(t),
+        Err(e) => {
+            let line = e.span().map(|span| line_number(contents, span.start));
+            report.push(
+                file_name,
+                line,
+                Severity::Error,
+                e.message().trim().to_string(),
+            );
+            None
+        }
+    }
+}
+
+fn check_bookmarks(report: &mut ConfigReport, file_name: &str, contents: &str) {
+    if let Some(raw) = parse_toml::<BookmarksRaw>(report, file_name, contents) {
+        for bookmark in raw.bookmark {
+            if str_to_event(&bookmark.key).is_none() {
+                report.push(
+                    file_name,
+                    None,
+                    Severity::Error,
+                    format!(
+                        "unknown key '{}' for bookmark '{}'",
+                        bookmark.key, bookmark.path
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn check_macros(report: &mut ConfigReport, file_name: &str, contents: &str) {
+    if let Some(raw) = parse_toml::<MacrosRaw>(report, file_name, contents) {
+        for m in raw.macros {
+            if macros::parse_register(&m.register).is_none() {
+                report.push(
+                    file_name,
+                    None,
+                    Severity::Error,
+                    format!("invalid macro register '{}'", m.register),
+                );
+            }
+            for command in m.commands {
+                if let Err(e) = Command::from_str(&command) {
+                    report.push(
+                        file_name,
+                        None,
+                        Severity::Error,
+                        format!("[macro {}] {} (in '{}')", m.register, e, command),
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn check_keymap(report: &mut ConfigReport, file_name: &str, contents: &str) {
+    // make sure the keymap can be loaded at all, otherwise joshuto falls back to the defaults
+    if parse_toml::<AppKeyMappingRaw>(report, file_name, contents).is_none() {
+        return;
+    }
+    let raw = match toml::from_str::<SpannedKeyMappingRaw>(contents) {
+        Ok(raw) => raw,
+        Err(_) => return,
+    };
+
+    let mut views: Vec<(String, &SpannedModeKeyMappingRaw)> = vec![
+        ("default_view".to_string(), &raw.default_view),
+        ("task_view".to_string(), &raw.task_view),
+        ("help_view".to_string(), &raw.help_view),
+    ];
+    if let Some(view) = raw.visual_mode.as_ref() {
+        views.push(("visual_mode".to_string(), view));
+    }
+    if let Some(view) = raw.filter_mode.as_ref() {
+        views.push(("filter_mode".to_string(), view));
+    }
+    let mut mode_names: Vec<&String> = raw.mode.keys().collect();
+    mode_names.sort();
+    for name in mode_names.iter() {
+        views.push((format!("mode.{}", name), &raw.mode[*name]));
+    }
+
+    for (view_name, view) in views {
+        check_keymap_view(report, file_name, contents, &view_name, view, &raw.mode);
+    }
+}
+
+fn check_keymap_view(
+    report: &mut ConfigReport,
+    file_name: &str,
+    contents: &str,
+    view_name: &str,
+    view: &SpannedModeKeyMappingRaw,
+    modes: &HashMap<String, SpannedModeKeyMappingRaw>,
+) {
+    let mut parsed: Vec<ParsedKeymap> = Vec::with_capacity(view.keymap.len());
+
+    for spanned in view.keymap.iter() {
+        let line = line_number(contents, spanned.span().start);
+        let keymap = spanned.get_ref();
+        let mut push_error = |message: String| {
+            report.push(
+                file_name,
+                Some(line),
+                Severity::Error,
+                format!("[{}] {}", view_name, message),
+            )
+        };
+
+        let command_strs: Vec<&String> = match &keymap.command {
+            Some(command) => vec![command],
+            None => keymap.commands.iter().collect(),
+        };
+        if command_strs.is_empty() {
+            push_error("keymap `commands` cannot be empty".to_string());
+        }
+        let mut valid = !command_strs.is_empty();
+        for command_str in command_strs {
+            match Command::from_str(command_str) {
+                Ok(Command::PushMode { name }) if !modes.contains_key(&name) => {
+                    push_error(format!(
+                        "unknown mode '{}', expected a [mode.{}] section",
+                        name, name
+                    ));
+                    valid = false;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    push_error(format!("{} (in '{}')", e, command_str));
+                    valid = false;
+                }
+            }
+        }
+
+        if keymap.keys.is_empty() {
+            push_error("keymap `keys` cannot be empty".to_string());
+            valid = false;
+        }
+        let mut events = Vec::with_capacity(keymap.keys.len());
+        for key in keymap.keys.iter() {
+            match str_to_event(key) {
+                Some(event) => events.push(event),
+                None => {
+                    push_error(format!("unknown key '{}'", key));
+                    valid = false;
+                }
+            }
+        }
+
+        if valid {
+            parsed.push(ParsedKeymap {
+                line,
+                keys: &keymap.keys,
+                events,
+            });
+        }
+    }
+
+    for (i, curr) in parsed.iter().enumerate() {
+        for prev in parsed[..i].iter() {
+            let message = if prev.events == curr.events {
+                format!(
+                    "[{}] duplicate binding for {:?}, already bound on line {}",
+                    view_name, curr.keys, prev.line
+                )
+            } else if curr.events.starts_with(&prev.events) {
+                format!(
+                    "[{}] {:?} is shadowed by {:?} on line {}",
+                    view_name, curr.keys, prev.keys, prev.line
+                )
+            } else if prev.events.starts_with(&curr.events) {
+                format!(
+                    "[{}] {:?} shadows {:?} on line {}",
+                    view_name, curr.keys, prev.keys, prev.line
+                )
+            } else {
+                continue;
+            };
+            // joshuto keeps the first binding and drops this one
+            report.push(file_name, Some(curr.line), Severity::Error, message);
+        }
+
+        // composite keybinds are always cancelled by escape
+        if curr.events[1..].contains(&Event::Key(Key::Esc)) {
+            report.push(
+                file_name,
+                Some(curr.line),
+                Severity::Warning,
+                format!(
+                    "[{}] {:?} is unreachable, escape cancels a key sequence",
+                    view_name, curr.keys
+                ),
+            );
+        }
+        if let Some(reason) = unreachable_in_view(view_name, &curr.events) {
+            report.push(
+                file_name,
+                Some(curr.line),
+                Severity::Warning,
+                format!("[{}] {:?} is unreachable, {}", view_name, curr.keys, reason),
+            );
+        }
+    }
+}
+
+// keys which are intercepted by a view before its keymap is consulted
+fn unreachable_in_view(view_name: &str, events: &[Event]) -> Option<&'static str> {
+    match view_name {
+        "help_view" if events.len() > 1 => Some("help_view does not support key sequences"),
+        "help_view" => match events.first() {
+            Some(Event::Key(Key::Esc))
+            | Some(Event::Key(Key::Char('1')))
+            | Some(Event::Key(Key::Char('2')))
+            | Some(Event::Key(Key::Char('3')))
+            | Some(Event::Key(Key::Char('/'))) => Some("key is reserved by help_view"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn line_number(contents: &str, byte_offset: usize) -> usize {
+    let end = byte_offset.min(contents.len());
+    contents.as_bytes()[..end]
+        .iter()
+        .filter(|b| **b == b'\n')
+        .count()
+        + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_keymap, ConfigReport, Severity};
+    use crate::constants::config::KEYMAP_CONFIG;
+
+    fn check(contents: &str) -> ConfigReport {
+        let mut report = ConfigReport::default();
+        check_keymap(&mut report, "keymap.toml", contents);
+        report
+    }
+
+    #[test]
+    fn default_keymap_has_no_errors() {
+        let report = check(KEYMAP_CONFIG);
+        assert!(!report.has_errors(), "{:?}", report.diagnostics);
+    }
+
+    #[test]
+    fn unknown_command_reports_line() {
+        let report = check(
+            r#"
+[default_view]
+keymap = [
+  { keys = ["j"], commands = ["cursor_move_down"] },
+  { keys = ["k"], commands = ["not_a_command"] },
+]
+[task_view]
+[help_view]
+"#,
+        );
+        assert_eq!(report.count(Severity::Error), 1);
+        assert_eq!(report.diagnostics[0].line, Some(5));
+    }
+
+    #[test]
+    fn duplicate_and_shadowed_bindings() {
+        let report = check(
+            r#"
+[default_view]
+keymap = [
+  { keys = ["g"], commands = ["cursor_move_home"] },
+  { keys = ["g", "g"], commands = ["cursor_move_home"] },
+  { keys = ["j"], commands = ["cursor_move_down"] },
+  { keys = ["j"], commands = ["cursor_move_up"] },
+]
+[task_view]
+[help_view]
+"#,
+        );
+        let lines: Vec<Option<usize>> = report.diagnostics.iter().map(|d| d.line).collect();
+        assert_eq!(lines, vec![Some(5), Some(7)]);
+    }
+
+    #[test]
+    fn mode_sections_are_checked() {
+        let report = check(
+            r#"
+[default_view]
+keymap = [
+  { keys = ["M"], commands = ["push_mode media"] },
+  { keys = ["X"], commands = ["push_mode missing"] },
+]
+[task_view]
+[help_view]
+[mode.media]
+keymap = [
+  { keys = ["q"], commands = ["pop_mode"] },
+  { keys = ["p"], commands = ["not_a_command"] },
+]
+"#,
+        );
+        let lines: Vec<Option<usize>> = report.diagnostics.iter().map(|d| d.line).collect();
+        assert_eq!(lines, vec![Some(5), Some(12)]);
+        assert!(report.diagnostics[1].message.starts_with("[mode.media]"));
+    }
+
+    #[test]
+    fn unreachable_bindings_are_warnings() {
+        let report = check(
+            r#"
+[default_view]
+keymap = [
+  { keys = ["g", "escape"], commands = ["cursor_move_home"] },
+]
+[task_view]
+[help_view]
+keymap = [
+  { keys = ["/"], commands = ["cursor_move_home"] },
+]
+"#,
+        );
+        assert!(!report.has_errors());
+        assert_eq!(report.count(Severity::Warning), 2);
+    }
+}
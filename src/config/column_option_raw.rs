@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use super::theme::style_raw::AppStyleOptionsRaw;
+
+/// Layout of a linemode column, see `[display.columns.<linemode>]`
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ColumnOptionRaw {
+    #[serde(default)]
+    pub width: Option<usize>,
+    #[serde(default)]
+    pub align: Option<String>,
+    #[serde(default)]
+    pub header: Option<String>,
+    #[serde(default)]
+    pub style: Option<AppStyleOptionsRaw>,
+    #[serde(default)]
+    pub time_format: Option<String>,
+}
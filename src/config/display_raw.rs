@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 use crate::{
@@ -5,6 +7,7 @@ use crate::{
     utils::serde::{default_mode, default_scroll_offset, default_true, deserialize_line_mode},
 };
 
+use super::column_option_raw::ColumnOptionRaw;
 use super::sort_option_raw::SortOptionRaw;
 
 #[derive(Clone, Debug, Deserialize)]
@@ -23,6 +26,8 @@ pub struct DisplayOptionRaw {
     pub show_borders: bool,
     #[serde(default)]
     pub show_hidden: bool,
+    #[serde(default = "default_true")]
+    pub show_ignored: bool,
     #[serde(default)]
     pub show_icons: bool,
     #[serde(default, rename = "sort")]
@@ -31,6 +36,14 @@ pub struct DisplayOptionRaw {
     pub line_number_style: LineNumberStyle,
     #[serde(default, deserialize_with = "deserialize_line_mode")]
     pub linemode: LineMode,
+    #[serde(default)]
+    pub column_headers: bool,
+    #[serde(default)]
+    pub time_format: Option<String>,
+    #[serde(default)]
+    pub columns: HashMap<String, ColumnOptionRaw>,
+    #[serde(default)]
+    pub persist_dir_views: bool,
 }
 
 impl std::default::Default for DisplayOptionRaw {
@@ -43,10 +56,15 @@ impl std::default::Default for DisplayOptionRaw {
             scroll_offset: 4,
             show_borders: true,
             show_hidden: false,
+            show_ignored: true,
             show_icons: false,
             sort_options: SortOptionRaw::default(),
             line_number_style: LineNumberStyle::default(),
             linemode: LineMode::default(),
+            column_headers: false,
+            time_format: None,
+            columns: HashMap::new(),
+            persist_dir_views: false,
         }
     }
 }
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct HooksRaw {
+    #[serde(default)]
+    pub on_cd: Option<String>,
+    #[serde(default)]
+    pub on_open: Option<String>,
+    #[serde(default)]
+    pub on_select: Option<String>,
+    #[serde(default)]
+    pub on_tab_change: Option<String>,
+    #[serde(default)]
+    pub on_io_task_done: Option<String>,
+    #[serde(default)]
+    pub on_quit: Option<String>,
+}
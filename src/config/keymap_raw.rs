@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 #[derive(Clone, Debug, Deserialize)]
@@ -12,7 +14,7 @@ pub struct CommandKeymapRaw {
     pub description: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize)]
 pub struct AppModeKeyMappingRaw {
     #[serde(default)]
     pub keymap: Vec<CommandKeymapRaw>,
@@ -23,4 +25,10 @@ pub struct AppKeyMappingRaw {
     pub default_view: AppModeKeyMappingRaw,
     pub task_view: AppModeKeyMappingRaw,
     pub help_view: AppModeKeyMappingRaw,
+    #[serde(default)]
+    pub visual_mode: AppModeKeyMappingRaw,
+    #[serde(default)]
+    pub filter_mode: AppModeKeyMappingRaw,
+    #[serde(default)]
+    pub mode: HashMap<String, AppModeKeyMappingRaw>,
 }
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::traits::config::TomlConfigFile;
+use crate::types::command::Command;
+use crate::types::config_type::ConfigType;
+
+pub type Macros = HashMap<char, Vec<Command>>;
+
+impl TomlConfigFile for Macros {
+    type Raw = MacrosRaw;
+
+    fn get_type() -> ConfigType {
+        ConfigType::Macros
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MacroRaw {
+    pub register: String,
+    #[serde(default)]
+    pub commands: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MacrosRaw {
+    #[serde(default, rename = "macro")]
+    pub macros: Vec<MacroRaw>,
+}
+
+/// Parses the register name of a macro, which has to be a single character
+pub fn parse_register(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+impl From<MacrosRaw> for Macros {
+    fn from(raw: MacrosRaw) -> Self {
+        // macros with invalid commands are dropped entirely,
+        // replaying only parts of them could do more harm than good
+        raw.macros
+            .into_iter()
+            .filter_map(|m| {
+                let register = parse_register(&m.register)?;
+                let commands: Result<Vec<Command>, _> =
+                    m.commands.iter().map(|s| Command::from_str(s)).collect();
+                Some((register, commands.ok()?))
+            })
+            .collect()
+    }
+}
+
+impl From<&Macros> for MacrosRaw {
+    fn from(macros: &Macros) -> Self {
+        let mut macros: Vec<MacroRaw> = macros
+            .iter()
+            .map(|(register, commands)| MacroRaw {
+                register: register.to_string(),
+                commands: commands.iter().map(|c| c.to_string()).collect(),
+            })
+            .collect();
+        macros.sort_by(|a, b| a.register.cmp(&b.register));
+        Self { macros }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn macros_survive_a_round_trip() {
+        let contents = r#"
+[[macro]]
+register = "a"
+commands = [
+  "select_glob --toggle=true --all=false --deselect=false '*.jpg'",
+  "cut_files",
+  "cd '/tmp/some dir'",
+  "paste_files --overwrite=true --skip_exist=false",
+  "shell mv 'a b' c",
+  "cd -",
+  "search_glob '*.a;b'",
+  "search_regex '^a && b$'",
+  "filter 'two words'",
+  "filter_regex '\\.rs$'",
+  "select --toggle=true --all=false --deselect=false 'x; y'",
+]
+"#;
+        let raw: MacrosRaw = toml::from_str(contents).unwrap();
+        let expected: Vec<String> = raw.macros[0].commands.clone();
+
+        let macros = Macros::from(raw);
+        assert_eq!(macros.get(&'a').map(|c| c.len()), Some(11));
+
+        let raw = MacrosRaw::from(&macros);
+        assert_eq!(raw.macros[0].commands, expected);
+    }
+
+    #[test]
+    fn invalid_macros_are_dropped() {
+        let contents = r#"
+[[macro]]
+register = "ab"
+commands = ["cut_files"]
+
+[[macro]]
+register = "b"
+commands = ["cut_files", "not_a_command"]
+
+[[macro]]
+register = "c"
+commands = ["copy_files"]
+"#;
+        let raw: MacrosRaw = toml::from_str(contents).unwrap();
+        let macros = Macros::from(raw);
+        assert_eq!(macros.keys().collect::<Vec<_>>(), vec![&'c']);
+    }
+}
@@ -1,10 +1,14 @@
 pub mod app;
 pub mod app_raw;
 pub mod bookmarks;
+pub mod check;
+pub mod column_option_raw;
 pub mod display_raw;
+pub mod hooks_raw;
 pub mod icon;
 pub mod icon_raw;
 pub mod keymap_raw;
+pub mod macros;
 pub mod mimetype;
 pub mod mimetype_raw;
 pub mod preview;
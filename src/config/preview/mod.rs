@@ -32,6 +32,14 @@ impl FileEntryPreview {
         Self::from_toml_str(&file_contents)
     }
 
+    // falls back to the default only if there is no config file
+    pub fn get_config_res() -> AppResult<Self> {
+        match search_config_directories(ConfigType::Preview.as_filename()) {
+            Some(_) => Self::get_config(),
+            None => Ok(Self::default()),
+        }
+    }
+
     pub fn get_config_or_default() -> Self {
         Self::get_config().unwrap_or_default()
     }
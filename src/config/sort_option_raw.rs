@@ -12,6 +12,8 @@ pub struct SortOptionRaw {
     pub reverse: bool,
     #[serde(default)]
     pub sort_method: Option<String>,
+    #[serde(default)]
+    pub tie_break: Option<Vec<String>>,
 }
 
 impl std::default::Default for SortOptionRaw {
@@ -21,6 +23,7 @@ impl std::default::Default for SortOptionRaw {
             case_sensitive: bool::default(),
             reverse: bool::default(),
             sort_method: None,
+            tie_break: None,
         }
     }
 }
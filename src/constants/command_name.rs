@@ -33,6 +33,7 @@ cmd_constants![
     (CMD_COPY_DIRECTORY_PATH, "copy_dirpath"),
     (CMD_SYMLINK_FILES, "symlink_files"),
     (CMD_PASTE_FILES, "paste_files"),
+    (CMD_PASTE_FROM_CLIPBOARD, "paste_from_clipboard"),
     (CMD_DELETE_FILES, "delete_files"),
     (CMD_CURSOR_MOVE_UP, "cursor_move_up"),
     (CMD_CURSOR_MOVE_DOWN, "cursor_move_down"),
@@ -49,8 +50,11 @@ cmd_constants![
     (CMD_PREVIEW_CURSOR_MOVE_DOWN, "preview_cursor_move_down"),
     (CMD_NEW_DIRECTORY, "mkdir"),
     (CMD_OPEN_FILE, "open"),
+    (CMD_CONTEXT_MENU, "context_menu"),
+    (CMD_PROPERTIES, "properties"),
     (CMD_OPEN_FILE_WITH, "open_with"),
     (CMD_RELOAD_DIRECTORY_LIST, "reload_dirlist"),
+    (CMD_RELOAD_CONFIG, "reload_config"),
     (CMD_RENAME_FILE, "rename"),
     (CMD_RENAME_FILE_APPEND, "rename_append"),
     (CMD_RENAME_FILE_APPEND_BASE, "rename_append_base"),
@@ -60,24 +64,42 @@ cmd_constants![
     (CMD_SEARCH_INCREMENTAL, "search_inc"),
     (CMD_SEARCH_GLOB, "search_glob"),
     (CMD_SEARCH_REGEX, "search_regex"),
+    (CMD_SEARCH_EXPR, "search_expr"),
     (CMD_SEARCH_NEXT, "search_next"),
     (CMD_SEARCH_PREV, "search_prev"),
+    (CMD_GREP, "grep"),
+    (CMD_FIND, "find"),
     (CMD_SELECT_GLOB, "select_glob"),
     (CMD_SELECT_REGEX, "select_regex"),
     (CMD_SELECT_STRING, "select"),
+    (CMD_SELECT_EXPR, "select_expr"),
     (CMD_SET_CASE_SENSITIVITY, "set_case_sensitivity"),
     (CMD_SET_MODE, "set_mode"),
     (CMD_SORT, "sort"),
     (CMD_SORT_REVERSE, "sort reverse"),
+    (CMD_VIEW_RESET, "view_reset"),
     (CMD_SUBPROCESS_INTERACTIVE, "shell"),
     (CMD_SUBPROCESS_SPAWN, "spawn"),
     (CMD_SUBPROCESS_CAPTURE, "capture"),
     (CMD_STDOUT_POST_PROCESS, "stdout"),
     (CMD_SHOW_TASKS, "show_tasks"),
+    (CMD_PANE_SWITCH, "pane_switch"),
+    (CMD_DISK_USAGE, "disk_usage"),
     (CMD_TAB_SWITCH, "tab_switch"),
     (CMD_TAB_SWITCH_INDEX, "tab_switch_index"),
     (CMD_TOGGLE_HIDDEN, "toggle_hidden"),
+    (CMD_TOGGLE_IGNORED, "toggle_ignored"),
+    (CMD_COMPUTE_SIZES, "compute_sizes"),
+    (CMD_TREE_TOGGLE, "tree_toggle"),
+    (CMD_TREE_EXPAND_ALL, "tree_expand_all"),
+    (CMD_TREE_COLLAPSE, "tree_collapse"),
     (CMD_TOGGLE_VISUAL, "toggle_visual"),
+    (CMD_PUSH_MODE, "push_mode"),
+    (CMD_POP_MODE, "pop_mode"),
+    (CMD_MACRO_RECORD, "macro_record"),
+    (CMD_MACRO_STOP, "macro_stop"),
+    (CMD_MACRO_PLAY, "macro_play"),
+    (CMD_SCRIPT, "script"),
     (CMD_SWITCH_LINE_NUMBERS, "line_nums"),
     (CMD_SET_DISPLAY_MODE, "set_display_mode"),
     (CMD_SET_LINEMODE, "linemode"),
@@ -94,6 +116,7 @@ cmd_constants![
     (CMD_FILTER_GLOB, "filter_glob"),
     (CMD_FILTER_REGEX, "filter_regex"),
     (CMD_FILTER_STRING, "filter"),
+    (CMD_FILTER_EXPR, "filter_expr"),
     (CMD_BOOKMARK_ADD, "add_bookmark"),
     (CMD_BOOKMARK_CHANGE_DIRECTORY, "cd_bookmark"),
     (CMD_CUSTOM_SEARCH, "custom_search"),
@@ -20,6 +20,8 @@ pub enum AppErrorKind {
 
     Regex,
 
+    Script,
+
     InvalidParameters,
     StateError,
 
@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+use std::{fs, io};
+
+use lazy_static::lazy_static;
+use walkdir::WalkDir;
+
+// how often the running total of a directory is reported
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+// changes deep below a directory are not watched, so sizes are recomputed after a while
+const MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+lazy_static! {
+    // recursive sizes of directories by device and inode
+    static ref DIR_SIZES: Mutex<HashMap<(u64, u64), CachedDirSize>> = Mutex::new(HashMap::new());
+}
+
+struct CachedDirSize {
+    size: u64,
+    path: PathBuf,
+    modified: SystemTime,
+    computed: Instant,
+}
+
+impl CachedDirSize {
+    fn is_valid(&self, modified: SystemTime) -> bool {
+        self.modified == modified && self.computed.elapsed() < MAX_AGE
+    }
+}
+
+/// The total size of the files below a directory, as computed by `compute_dir_size`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DirSize {
+    pub size: u64,
+    /// number of entries that could not be read and are left out of `size`
+    pub unreadable: usize,
+}
+
+/// The recursive size of a directory, if it has been computed and is not outdated
+pub fn cached_dir_size(metadata: &fs::Metadata) -> Option<u64> {
+    let modified = metadata.modified().ok()?;
+    let key = (metadata.dev(), metadata.ino());
+    let mut sizes = DIR_SIZES.lock().ok()?;
+    let cached = sizes.get(&key)?;
+    if cached.is_valid(modified) {
+        Some(cached.size)
+    } else {
+        sizes.remove(&key);
+        None
+    }
+}
+
+/// Forgets the sizes of the directories containing any of `paths`, as they changed
+pub fn invalidate_dir_sizes(paths: &[PathBuf]) {
+    if let Ok(mut sizes) = DIR_SIZES.lock() {
+        sizes.retain(|_, cached| !paths.iter().any(|path| path.starts_with(&cached.path)));
+    }
+}
+
+/// Adds up the sizes of all files below `path`, like `du --apparent-size`.
+/// Symlinks are not followed, hard links are counted once and other file systems are skipped.
+/// Entries that can not be read are left out and counted in `unreadable`.
+/// `progress` is called with the running total every now and then.
+/// The result is cached, unless some entries could not be read.
+pub fn compute_dir_size<F>(path: &Path, mut progress: F) -> io::Result<DirSize>
+where
+    F: FnMut(u64),
+{
+    let metadata = fs::metadata(path)?;
+    let key = (metadata.dev(), metadata.ino());
+    let modified = metadata.modified()?;
+    // a previous result is outdated now
+    if let Ok(mut sizes) = DIR_SIZES.lock() {
+        sizes.remove(&key);
+    }
+
+    let mut total = DirSize {
+        size: 0,
+        unreadable: 0,
+    };
+    let mut hard_links = HashSet::new();
+    let mut last_progress = Instant::now();
+    for entry in WalkDir::new(path).min_depth(1).same_file_system(true) {
+        let metadata = match entry.and_then(|e| e.metadata()) {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                total.unreadable += 1;
+                continue;
+            }
+        };
+        if metadata.is_dir() {
+            continue;
+        }
+        if metadata.nlink() > 1 && !hard_links.insert((metadata.dev(), metadata.ino())) {
+            continue;
+        }
+        total.size += metadata.len();
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            progress(total.size);
+            last_progress = Instant::now();
+        }
+    }
+
+    if total.unreadable == 0 {
+        if let Ok(mut sizes) = DIR_SIZES.lock() {
+            let cached = CachedDirSize {
+                size: total.size,
+                path: path.to_path_buf(),
+                modified,
+                computed: Instant::now(),
+            };
+            sizes.insert(key, cached);
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn changes_below_a_directory_invalidate_its_size() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("a/b")).unwrap();
+        fs::write(root.join("a/b/file"), "1234").unwrap();
+
+        let total = compute_dir_size(root, |_| {}).unwrap();
+        assert_eq!((total.size, total.unreadable), (4, 0));
+        assert_eq!(cached_dir_size(&fs::metadata(root).unwrap()), Some(4));
+
+        // the watcher reports the change below the directory
+        fs::write(root.join("a/b/other"), "12").unwrap();
+        invalidate_dir_sizes(&[root.join("a/b/other")]);
+        assert_eq!(cached_dir_size(&fs::metadata(root).unwrap()), None);
+        assert_eq!(compute_dir_size(root, |_| {}).unwrap().size, 6);
+        assert_eq!(cached_dir_size(&fs::metadata(root).unwrap()), Some(6));
+
+        // changes elsewhere keep the size
+        invalidate_dir_sizes(&[temp.path().with_file_name("elsewhere")]);
+        assert_eq!(cached_dir_size(&fs::metadata(root).unwrap()), Some(6));
+    }
+}
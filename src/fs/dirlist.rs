@@ -1,5 +1,5 @@
 use std::slice::{Iter, IterMut};
-use std::{io, path};
+use std::{cmp, io, path};
 
 use crate::fs::{entry::JoshutoDirEntry, metadata::JoshutoMetadata};
 use crate::history::read_directory;
@@ -50,7 +50,10 @@ impl JoshutoDirList {
         let mut contents =
             read_directory(path.as_path(), filter_func, display_options, tab_options)?;
 
-        contents.sort_by(|f1, f2| tab_options.sort_options.compare(f1, f2));
+        let sort_options = display_options
+            .dir_views
+            .sort_options(&path, tab_options.sort_options_ref());
+        contents.sort_by(|f1, f2| sort_options.compare(f1, f2));
 
         let index = if contents.is_empty() { None } else { Some(0) };
         let metadata = JoshutoMetadata::from(&path)?;
@@ -137,7 +140,8 @@ impl JoshutoDirList {
 
     pub fn update_viewport(&mut self, ui_state: &UiState, options: &DisplayOption) {
         if let Some(ix) = self.index {
-            let height = ui_state.layout[0].height as usize;
+            // the height of the current view, which may be shorter than the others
+            let height = ui_state.layout[1].height as usize;
 
             // get scroll buffer size, corrected in case of too small terminal
             let scroll_offset = if height < 4 {
@@ -201,6 +205,35 @@ impl JoshutoDirList {
             .unwrap_or(false)
     }
 
+    /// Adds entries to the list and sorts it again, keeping the cursor on the same entry
+    pub fn insert_entries<F>(&mut self, entries: Vec<JoshutoDirEntry>, compare: F)
+    where
+        F: FnMut(&JoshutoDirEntry, &JoshutoDirEntry) -> cmp::Ordering,
+    {
+        let curr_path = self.curr_entry_ref().map(|e| e.file_path_buf());
+        let anchor_path = self
+            .visual_mode_anchor_index
+            .and_then(|i| self.contents.get(i))
+            .map(|e| e.file_path_buf());
+        let position = |contents: &[JoshutoDirEntry], path: Option<path::PathBuf>| {
+            path.and_then(|p| contents.iter().position(|e| e.file_path() == p))
+        };
+
+        // the list is sorted already, so sorting it again after adding entries is cheap
+        self.contents.extend(entries);
+        self.contents.sort_by(compare);
+
+        self.index = position(&self.contents, curr_path).or(if self.contents.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+        if self.visual_mode_anchor_index.is_some() {
+            self.visual_mode_anchor_index = position(&self.contents, anchor_path);
+            self.update_visual_mode_selection();
+        }
+    }
+
     pub fn depreciate(&mut self) {
         self.need_update = true;
     }
@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use std::{fs, io};
+
+// how often the running totals of a scan are reported
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A file or directory in a disk usage tree.
+/// The children of a directory are sorted by size, largest first.
+#[derive(Clone, Debug)]
+pub struct DiskUsageNode {
+    pub name: String,
+    pub size: u64,
+    pub is_dir: bool,
+    pub children: Vec<DiskUsageNode>,
+}
+
+impl DiskUsageNode {
+    fn file(name: String, size: u64) -> Self {
+        Self {
+            name,
+            size,
+            is_dir: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Follows a path of child indices down from this node
+    pub fn descendant(&self, indices: &[usize]) -> Option<&Self> {
+        indices
+            .iter()
+            .try_fold(self, |node, i| node.children.get(*i))
+    }
+
+    /// Removes the descendant at the end of a path of child indices
+    /// and subtracts its size from every directory above it
+    pub fn remove_descendant(&mut self, indices: &[usize]) -> Option<Self> {
+        let (last, parents) = indices.split_last()?;
+        self.descendant(indices)?;
+
+        let mut node = &mut *self;
+        for i in parents {
+            node = &mut node.children[*i];
+        }
+        let removed = node.children.remove(*last);
+
+        let mut node = self;
+        node.size -= removed.size;
+        for i in parents {
+            node = &mut node.children[*i];
+            node.size -= removed.size;
+        }
+        Some(removed)
+    }
+}
+
+/// Running totals of a scan
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DiskUsageProgress {
+    pub files: u64,
+    pub bytes: u64,
+}
+
+struct Scanner<'a, F> {
+    hard_links: HashSet<(u64, u64)>,
+    dev: u64,
+    progress: DiskUsageProgress,
+    last_progress: Instant,
+    on_progress: F,
+    cancel: &'a AtomicBool,
+}
+
+/// Scans the directory tree below `path` for the sizes of all files and directories,
+/// like `du --apparent-size`.
+/// Symlinks are not followed, hard links are counted once and other file systems are skipped.
+/// Unreadable directories are counted as empty.
+/// `on_progress` is called with the running totals every now and then.
+/// Setting `cancel` stops the scan with an `Interrupted` error.
+pub fn scan_disk_usage<F>(
+    path: &Path,
+    cancel: &AtomicBool,
+    on_progress: F,
+) -> io::Result<DiskUsageNode>
+where
+    F: FnMut(DiskUsageProgress),
+{
+    let metadata = fs::symlink_metadata(path)?;
+    let name = path
+        .file_name()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string());
+
+    let mut scanner = Scanner {
+        hard_links: HashSet::new(),
+        dev: metadata.dev(),
+        progress: DiskUsageProgress::default(),
+        last_progress: Instant::now(),
+        on_progress,
+        cancel,
+    };
+    if metadata.is_dir() {
+        scanner.scan_dir(path, name)
+    } else {
+        Ok(DiskUsageNode::file(name, metadata.len()))
+    }
+}
+
+impl<F> Scanner<'_, F>
+where
+    F: FnMut(DiskUsageProgress),
+{
+    fn scan_dir(&mut self, path: &Path, name: String) -> io::Result<DiskUsageNode> {
+        if self.cancel.load(Ordering::Relaxed) {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Disk usage scan cancelled",
+            ));
+        }
+
+        let mut children = Vec::new();
+        if let Ok(read_dir) = fs::read_dir(path) {
+            for entry in read_dir.filter_map(|e| e.ok()) {
+                let metadata = match entry.metadata() {
+                    Ok(m) => m,
+                    Err(_) => continue,
+                };
+                let name = entry.file_name().to_string_lossy().to_string();
+                if metadata.is_dir() {
+                    if metadata.dev() == self.dev {
+                        children.push(self.scan_dir(&entry.path(), name)?);
+                    }
+                    continue;
+                }
+                if metadata.nlink() > 1 && !self.hard_links.insert((metadata.dev(), metadata.ino()))
+                {
+                    continue;
+                }
+                self.add_file(metadata.len());
+                children.push(DiskUsageNode::file(name, metadata.len()));
+            }
+        }
+        children.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+
+        Ok(DiskUsageNode {
+            name,
+            size: children.iter().map(|c| c.size).sum(),
+            is_dir: true,
+            children,
+        })
+    }
+
+    fn add_file(&mut self, size: u64) {
+        self.progress.files += 1;
+        self.progress.bytes += size;
+        if self.last_progress.elapsed() >= PROGRESS_INTERVAL {
+            (self.on_progress)(self.progress);
+            self.last_progress = Instant::now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn sizes_are_summed_and_sorted() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("big/nested")).unwrap();
+        fs::create_dir_all(root.join("empty")).unwrap();
+        fs::write(root.join("big/nested/a"), vec![0; 3000]).unwrap();
+        fs::write(root.join("big/b"), vec![0; 1000]).unwrap();
+        fs::write(root.join("small"), vec![0; 10]).unwrap();
+        fs::hard_link(root.join("big/b"), root.join("big/c")).unwrap();
+
+        let cancel = AtomicBool::new(false);
+        let mut tree = scan_disk_usage(root, &cancel, |_| {}).unwrap();
+        let names: Vec<&str> = tree.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["big", "small", "empty"]);
+        assert_eq!(tree.size, 4010);
+        assert_eq!(tree.children[0].size, 4000);
+        assert_eq!(tree.children[0].children.len(), 2);
+
+        let removed = tree.remove_descendant(&[0, 0]).unwrap();
+        assert_eq!(removed.name, "nested");
+        assert_eq!(tree.children[0].size, 1000);
+        assert_eq!(tree.size, 1010);
+        assert!(tree.remove_descendant(&[5]).is_none());
+
+        cancel.store(true, Ordering::Relaxed);
+        assert!(scan_disk_usage(root, &cancel, |_| {}).is_err());
+    }
+}
@@ -10,6 +10,8 @@ pub struct JoshutoDirEntry {
     pub ext: Option<String>,
     pub path: path::PathBuf,
     pub metadata: JoshutoMetadata,
+    /// Indentation guide shown before the name in tree mode
+    pub tree_guide: String,
     /// Directly selected by the user, _not_ by a current visual mode selection
     permanent_selected: bool,
     /// Temporarily selected by the visual mode range
@@ -23,21 +25,27 @@ impl JoshutoDirEntry {
         base: &path::Path,
         options: &DisplayOption,
     ) -> io::Result<Self> {
-        let path = direntry.path().to_path_buf();
+        Self::from_path(direntry.path(), base, options)
+    }
 
-        let name = direntry
-            .path()
+    /// Entry of a file below `base`, named by its path relative to `base`
+    pub fn from_path(
+        path: &path::Path,
+        base: &path::Path,
+        options: &DisplayOption,
+    ) -> io::Result<Self> {
+        let name = path
             .strip_prefix(base)
-            .unwrap()
+            .unwrap_or(path)
             .to_string_lossy()
             .to_string();
 
-        let ext = direntry
-            .path()
+        let ext = path
             .extension()
             .and_then(|s| s.to_str())
             .map(|s| s.to_string());
 
+        let path = path.to_path_buf();
         let mut metadata = JoshutoMetadata::from(&path)?;
 
         if options.automatically_count_files && metadata.file_type() == FileType::Directory {
@@ -51,6 +59,7 @@ impl JoshutoDirEntry {
             ext,
             path,
             metadata,
+            tree_guide: String::new(),
             permanent_selected: false,
             visual_mode_selected: false,
             _marked: false,
@@ -61,6 +70,17 @@ impl JoshutoDirEntry {
         self.name.as_str()
     }
 
+    /// The name to show, which is only the last component of the path in tree mode
+    pub fn display_name(&self) -> &str {
+        if self.tree_guide.is_empty() {
+            return self.file_name();
+        }
+        match self.name.rfind('/') {
+            Some(i) => &self.name[i + 1..],
+            None => self.file_name(),
+        }
+    }
+
     pub fn ext(&self) -> Option<&str> {
         self.ext.as_deref()
     }
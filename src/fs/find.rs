@@ -0,0 +1,131 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+use crate::types::state::MatchState;
+
+use super::IgnoreFilter;
+
+// how often the paths found so far are reported
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Walks the tree below `root`, leaving out hidden and ignored files and directories
+/// unless they are shown
+pub fn walk_visible(
+    root: &Path,
+    show_hidden: bool,
+    show_ignored: bool,
+) -> impl Iterator<Item = walkdir::DirEntry> {
+    let mut ignore_filter = IgnoreFilter::new(root);
+    WalkDir::new(root)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(move |e| {
+            let is_hidden = e.file_name().to_string_lossy().starts_with('.');
+            (show_hidden || !is_hidden)
+                && (show_ignored || !ignore_filter.is_ignored(e.path(), e.file_type().is_dir()))
+        })
+        .filter_map(|e| e.ok())
+}
+
+/// Searches the tree below `root` for files and directories whose name matches `matcher`.
+/// `on_matches` is called with the paths found since the last call every now and then,
+/// and once more at the end.
+/// Setting `cancel` stops the search with an `Interrupted` error.
+pub fn find_files<F>(
+    root: &Path,
+    matcher: &MatchState,
+    show_hidden: bool,
+    show_ignored: bool,
+    cancel: &AtomicBool,
+    mut on_matches: F,
+) -> io::Result<()>
+where
+    F: FnMut(Vec<PathBuf>),
+{
+    let mut pending = Vec::new();
+    let mut last_progress = Instant::now();
+    for entry in walk_visible(root, show_hidden, show_ignored) {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "Find cancelled"));
+        }
+        if matcher.is_match(&entry.file_name().to_string_lossy()) {
+            pending.push(entry.into_path());
+        }
+        if !pending.is_empty() && last_progress.elapsed() >= PROGRESS_INTERVAL {
+            on_matches(std::mem::take(&mut pending));
+            last_progress = Instant::now();
+        }
+    }
+    on_matches(pending);
+    Ok(())
+}
+
+/// Paths found by `find`, listed instead of the contents of the directory the search started in
+#[derive(Clone, Debug)]
+pub struct FindResults {
+    pub id: Uuid,
+    pub pattern: String,
+    pub paths: Vec<PathBuf>,
+    /// whether the search has finished
+    pub done: bool,
+    cancel: Arc<AtomicBool>,
+}
+
+impl FindResults {
+    pub fn new(id: Uuid, pattern: String, cancel: Arc<AtomicBool>) -> Self {
+        Self {
+            id,
+            pattern,
+            paths: Vec::new(),
+            done: false,
+            cancel,
+        }
+    }
+
+    /// Stops the search if it is still running
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use temp_dir::TempDir;
+
+    use crate::types::option::search::CaseSensitivity;
+
+    use super::*;
+
+    #[test]
+    fn matching_names_are_found() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src/main")).unwrap();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("src/main.rs"), "").unwrap();
+        fs::write(root.join("src/lib.rs"), "").unwrap();
+        fs::write(root.join(".git/main"), "").unwrap();
+
+        let matcher = MatchState::new_string("main", CaseSensitivity::Insensitive);
+        let cancel = AtomicBool::new(false);
+        let mut found = Vec::new();
+        find_files(root, &matcher, false, false, &cancel, |p| found.extend(p)).unwrap();
+        let found: Vec<&Path> = found
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap())
+            .collect();
+        assert_eq!(found, [Path::new("src/main"), Path::new("src/main.rs")]);
+
+        cancel.store(true, Ordering::Relaxed);
+        assert!(find_files(root, &matcher, true, true, &cancel, |_| {}).is_err());
+    }
+}
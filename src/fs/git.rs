@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Status of a file in a git repository.
+/// Ordered by priority, a directory shows the highest status of its children.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GitStatus {
+    Ignored,
+    Untracked,
+    Staged,
+    Modified,
+    Conflicted,
+}
+
+impl GitStatus {
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            Self::Ignored => "!",
+            Self::Untracked => "?",
+            Self::Staged => "S",
+            Self::Modified => "M",
+            Self::Conflicted => "U",
+        }
+    }
+
+    fn from_xy(x: u8, y: u8) -> Self {
+        match (x, y) {
+            (b'?', b'?') => Self::Untracked,
+            (b'!', b'!') => Self::Ignored,
+            (b'D', b'D') | (b'A', b'A') | (b'U', _) | (_, b'U') => Self::Conflicted,
+            (_, b' ') => Self::Staged,
+            _ => Self::Modified,
+        }
+    }
+}
+
+/// Location of a directory inside a git repository
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GitDir {
+    pub root: PathBuf,
+    // path of the directory relative to the root of the repository
+    pub prefix: PathBuf,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct GitRepoStatus {
+    pub branch: Option<String>,
+    // paths relative to the root of the repository, including directories with changes
+    statuses: HashMap<PathBuf, GitStatus>,
+    // untracked and ignored directories, whose contents are not listed by git
+    whole_dirs: HashMap<PathBuf, GitStatus>,
+}
+
+impl GitRepoStatus {
+    /// Parses the output of `git status --porcelain=v1 -z --branch`
+    pub fn parse(output: &[u8]) -> Self {
+        let mut repo_status = Self::default();
+        let mut fields = output.split(|b| *b == 0).filter(|f| !f.is_empty());
+        while let Some(field) = fields.next() {
+            if let Some(header) = field.strip_prefix(b"## ") {
+                repo_status.branch = parse_branch(&String::from_utf8_lossy(header));
+                continue;
+            }
+            if field.len() < 4 {
+                continue;
+            }
+            let (x, y) = (field[0], field[1]);
+            // renames and copies are followed by the original path
+            if x == b'R' || x == b'C' {
+                fields.next();
+            }
+            let path = String::from_utf8_lossy(&field[3..]);
+            let status = GitStatus::from_xy(x, y);
+            match path.strip_suffix('/') {
+                Some(dir) => {
+                    repo_status.whole_dirs.insert(PathBuf::from(dir), status);
+                    repo_status.insert(Path::new(dir), status);
+                }
+                None => repo_status.insert(Path::new(path.as_ref()), status),
+            }
+        }
+        repo_status
+    }
+
+    fn insert(&mut self, path: &Path, status: GitStatus) {
+        self.statuses.insert(path.to_path_buf(), status);
+        // ignored files don't make their directories ignored
+        if status == GitStatus::Ignored {
+            return;
+        }
+        for ancestor in path.ancestors().skip(1) {
+            if ancestor.as_os_str().is_empty() {
+                break;
+            }
+            let entry = self
+                .statuses
+                .entry(ancestor.to_path_buf())
+                .or_insert(status);
+            if *entry < status {
+                *entry = status;
+            }
+        }
+    }
+
+    /// Status of a path relative to the root of the repository
+    pub fn status(&self, path: &Path) -> Option<GitStatus> {
+        self.statuses.get(path).copied().or_else(|| {
+            path.ancestors()
+                .skip(1)
+                .find_map(|ancestor| self.whole_dirs.get(ancestor).copied())
+        })
+    }
+}
+
+fn parse_branch(header: &str) -> Option<String> {
+    if header.starts_with("HEAD (no branch)") {
+        return Some("HEAD".to_string());
+    }
+    let header = header
+        .strip_prefix("No commits yet on ")
+        .or_else(|| header.strip_prefix("Initial commit on "))
+        .unwrap_or(header);
+    let branch = match header.find("...") {
+        Some(i) => &header[..i],
+        None => header.split(' ').next().unwrap_or_default(),
+    };
+    if branch.is_empty() {
+        None
+    } else {
+        Some(branch.to_string())
+    }
+}
+
+fn git_output(dir: &Path, args: &[&str]) -> Option<Vec<u8>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .arg("--no-optional-locks")
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if output.status.success() {
+        Some(output.stdout)
+    } else {
+        None
+    }
+}
+
+/// Runs `git` to find out if `dir` is inside a repository and the status of its files.
+/// Returns `None` if it is not.
+pub fn read_git_status(dir: &Path) -> Option<(GitDir, GitRepoStatus)> {
+    let output = git_output(dir, &["rev-parse", "--show-toplevel", "--show-prefix"])?;
+    let output = String::from_utf8_lossy(&output);
+    let mut lines = output.lines();
+    let root = PathBuf::from(lines.next()?);
+    let prefix = PathBuf::from(lines.next().unwrap_or_default());
+
+    let output = git_output(
+        &root,
+        &["status", "--porcelain=v1", "-z", "--branch", "--ignored"],
+    )?;
+    Some((GitDir { root, prefix }, GitRepoStatus::parse(&output)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statuses_are_parsed_and_aggregated() {
+        let output = b"## main...origin/main [ahead 1]\0 M src/main.rs\0M  src/fs/git.rs\0\
+                       ?? notes/\0!! target/\0R  new.rs\0old.rs\0UU src/conflict.rs\0";
+        let repo = GitRepoStatus::parse(output);
+
+        assert_eq!(repo.branch.as_deref(), Some("main"));
+        assert_eq!(
+            repo.status(Path::new("src/main.rs")),
+            Some(GitStatus::Modified)
+        );
+        assert_eq!(
+            repo.status(Path::new("src/fs/git.rs")),
+            Some(GitStatus::Staged)
+        );
+        assert_eq!(repo.status(Path::new("src/fs")), Some(GitStatus::Staged));
+        assert_eq!(repo.status(Path::new("src")), Some(GitStatus::Conflicted));
+        assert_eq!(repo.status(Path::new("new.rs")), Some(GitStatus::Staged));
+        assert_eq!(repo.status(Path::new("old.rs")), None);
+        assert_eq!(
+            repo.status(Path::new("notes/todo.md")),
+            Some(GitStatus::Untracked)
+        );
+        assert_eq!(
+            repo.status(Path::new("target/debug")),
+            Some(GitStatus::Ignored)
+        );
+        assert_eq!(repo.status(Path::new("Cargo.toml")), None);
+    }
+
+    #[test]
+    fn branch_names_are_parsed() {
+        assert_eq!(parse_branch("main"), Some("main".to_string()));
+        assert_eq!(
+            parse_branch("No commits yet on trunk"),
+            Some("trunk".to_string())
+        );
+        assert_eq!(parse_branch("HEAD (no branch)"), Some("HEAD".to_string()));
+        assert_eq!(
+            parse_branch("feature/x...origin/feature/x [behind 2]"),
+            Some("feature/x".to_string())
+        );
+    }
+}
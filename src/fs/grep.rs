@@ -0,0 +1,205 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use super::walk_visible;
+use globset::GlobMatcher;
+use regex::Regex;
+
+// how often the matches found so far are reported
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+// files with a NUL byte in their first few kilobytes are not searched
+const BINARY_CHECK_LEN: usize = 8 * 1024;
+// longer lines are cut off in the results
+const MAX_SNIPPET_LEN: usize = 256;
+
+/// A line of a file that matches a content search
+#[derive(Clone, Debug)]
+pub struct GrepMatch {
+    /// relative to the directory that was searched
+    pub path: PathBuf,
+    /// starting at 1
+    pub line: usize,
+    /// the line with leading whitespace trimmed
+    pub snippet: String,
+    /// byte range of the first match in `snippet`
+    pub range: Range<usize>,
+}
+
+/// What to search for and which files to search in
+#[derive(Clone, Debug)]
+pub struct GrepQuery {
+    pub regex: Regex,
+    /// only search files whose name or relative path matches
+    pub glob: Option<GlobMatcher>,
+    pub show_hidden: bool,
+    pub show_ignored: bool,
+}
+
+/// Running totals of a search
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GrepProgress {
+    pub files: u64,
+    pub matches: u64,
+}
+
+/// Searches the contents of all files below `root` for lines matching `query`, like `grep -rn`.
+/// Hidden and ignored files are skipped unless `query` says otherwise,
+/// binary and unreadable files are skipped as well.
+/// `on_matches` is called with the matches found since the last call every now and then,
+/// and once more at the end.
+/// Setting `cancel` stops the search with an `Interrupted` error.
+pub fn grep_dir<F>(
+    root: &Path,
+    query: &GrepQuery,
+    cancel: &AtomicBool,
+    mut on_matches: F,
+) -> io::Result<GrepProgress>
+where
+    F: FnMut(Vec<GrepMatch>, GrepProgress),
+{
+    let mut progress = GrepProgress::default();
+    let mut pending = Vec::new();
+    let mut last_progress = Instant::now();
+
+    for entry in walk_visible(root, query.show_hidden, query.show_ignored) {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Search cancelled",
+            ));
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = match entry.path().strip_prefix(root) {
+            Ok(path) => path,
+            Err(_) => continue,
+        };
+        if let Some(glob) = query.glob.as_ref() {
+            if !glob.is_match(entry.file_name()) && !glob.is_match(relative) {
+                continue;
+            }
+        }
+
+        progress.files += 1;
+        let before = pending.len();
+        let _ = grep_file(entry.path(), relative, &query.regex, &mut pending);
+        progress.matches += (pending.len() - before) as u64;
+
+        if !pending.is_empty() && last_progress.elapsed() >= PROGRESS_INTERVAL {
+            on_matches(std::mem::take(&mut pending), progress);
+            last_progress = Instant::now();
+        }
+    }
+    on_matches(pending, progress);
+    Ok(progress)
+}
+
+fn grep_file(
+    path: &Path,
+    relative: &Path,
+    regex: &Regex,
+    matches: &mut Vec<GrepMatch>,
+) -> io::Result<()> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let head = reader.fill_buf()?;
+    if head[..head.len().min(BINARY_CHECK_LEN)].contains(&0) {
+        return Ok(());
+    }
+
+    let mut line = Vec::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        line_number += 1;
+        let text = String::from_utf8_lossy(&line);
+        let text = text.trim_end_matches(['\n', '\r']);
+        if let Some(m) = regex.find(text) {
+            let trimmed = text.trim_start();
+            let offset = text.len() - trimmed.len();
+            let snippet = truncate(trimmed, MAX_SNIPPET_LEN);
+            let start = m.start().saturating_sub(offset).min(snippet.len());
+            let end = m.end().saturating_sub(offset).min(snippet.len());
+            matches.push(GrepMatch {
+                path: relative.to_path_buf(),
+                line: line_number,
+                snippet: snippet.to_string(),
+                range: start..end,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn truncate(s: &str, max_len: usize) -> &str {
+    if s.len() <= max_len {
+        return s;
+    }
+    let mut end = max_len;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use globset::Glob;
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn matching_lines_are_found() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join(".hidden")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {\n    todo!()\n}\n").unwrap();
+        fs::write(root.join("notes.txt"), "nothing\nTODO: more\n").unwrap();
+        fs::write(root.join(".hidden/a.rs"), "todo\n").unwrap();
+        fs::write(root.join("data.bin"), b"todo\0").unwrap();
+
+        let mut query = GrepQuery {
+            regex: Regex::new("(?i)todo").unwrap(),
+            glob: None,
+            show_hidden: false,
+            show_ignored: false,
+        };
+        let cancel = AtomicBool::new(false);
+        let mut found = Vec::new();
+        let progress = grep_dir(root, &query, &cancel, |m, _| found.extend(m)).unwrap();
+        assert_eq!(progress.matches, 2);
+        let results: Vec<(&Path, usize, &str)> = found
+            .iter()
+            .map(|m| (m.path.as_path(), m.line, &m.snippet[m.range.clone()]))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                (Path::new("notes.txt"), 2, "TODO"),
+                (Path::new("src/main.rs"), 2, "todo"),
+            ]
+        );
+        assert_eq!(found[1].snippet, "todo!()");
+
+        query.glob = Some(Glob::new("*.rs").unwrap().compile_matcher());
+        query.show_hidden = true;
+        found.clear();
+        grep_dir(root, &query, &cancel, |m, _| found.extend(m)).unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].path, Path::new(".hidden/a.rs"));
+
+        cancel.store(true, Ordering::Relaxed);
+        assert!(grep_dir(root, &query, &cancel, |_, _| {}).is_err());
+    }
+}
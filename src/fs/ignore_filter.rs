@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+
+/// Decides whether paths below a directory are ignored by `.gitignore` and `.ignore` files,
+/// `.git/info/exclude` and the global git excludes file.
+///
+/// `.gitignore` files are only honored inside of git repositories.
+/// Like git, patterns of deeper directories take precedence.
+pub struct IgnoreFilter {
+    // root of the git repository the directory is in
+    repo_root: Option<PathBuf>,
+    // ignore files of each directory, loaded as they are needed
+    dirs: HashMap<PathBuf, Gitignore>,
+    repo_exclude: Gitignore,
+    global: Gitignore,
+}
+
+impl IgnoreFilter {
+    pub fn new(dir: &Path) -> Self {
+        let repo_root = dir
+            .ancestors()
+            .find(|ancestor| ancestor.join(".git").exists())
+            .map(|root| root.to_path_buf());
+        let repo_exclude = match repo_root.as_ref() {
+            Some(root) => {
+                let mut builder = GitignoreBuilder::new(root);
+                builder.add(root.join(".git").join("info").join("exclude"));
+                builder.build().unwrap_or_else(|_| Gitignore::empty())
+            }
+            None => Gitignore::empty(),
+        };
+        let global = if repo_root.is_some() {
+            Gitignore::global().0
+        } else {
+            Gitignore::empty()
+        };
+        Self {
+            repo_root,
+            dirs: HashMap::new(),
+            repo_exclude,
+            global,
+        }
+    }
+
+    pub fn is_ignored(&mut self, path: &Path, is_dir: bool) -> bool {
+        for dir in path.ancestors().skip(1) {
+            match self.dir_matcher(dir).matched(path, is_dir) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {}
+            }
+        }
+        if self.repo_root.is_none() {
+            return false;
+        }
+        match self.repo_exclude.matched(path, is_dir) {
+            Match::Ignore(_) => true,
+            Match::Whitelist(_) => false,
+            Match::None => self.global.matched(path, is_dir).is_ignore(),
+        }
+    }
+
+    fn dir_matcher(&mut self, dir: &Path) -> &Gitignore {
+        let in_repo = self
+            .repo_root
+            .as_ref()
+            .map_or(false, |root| dir.starts_with(root));
+        self.dirs.entry(dir.to_path_buf()).or_insert_with(|| {
+            let mut builder = GitignoreBuilder::new(dir);
+            if in_repo {
+                builder.add(dir.join(".gitignore"));
+            }
+            // added last, so it takes precedence over `.gitignore`
+            builder.add(dir.join(".ignore"));
+            builder.build().unwrap_or_else(|_| Gitignore::empty())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn nested_ignore_files_are_honored() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join("src/gen")).unwrap();
+        fs::write(root.join(".gitignore"), "target/\n*.log\n").unwrap();
+        fs::write(root.join("src/.gitignore"), "gen/\n!keep.log\n").unwrap();
+        fs::write(root.join("src/.ignore"), "scratch.rs\n").unwrap();
+
+        let mut filter = IgnoreFilter::new(root);
+        assert!(filter.is_ignored(&root.join("target"), true));
+        assert!(!filter.is_ignored(&root.join("target"), false));
+        assert!(filter.is_ignored(&root.join("debug.log"), false));
+        assert!(filter.is_ignored(&root.join("src/gen"), true));
+        assert!(filter.is_ignored(&root.join("src/scratch.rs"), false));
+        assert!(!filter.is_ignored(&root.join("src/keep.log"), false));
+        assert!(!filter.is_ignored(&root.join("src/main.rs"), false));
+    }
+}
@@ -2,6 +2,8 @@ use std::{fs, io, path, time};
 
 use nix::sys::stat::{Mode, SFlag};
 
+use super::cached_dir_size;
+
 #[cfg(target_os = "macos")]
 use nix::sys::stat::mode_t;
 
@@ -47,8 +49,12 @@ pub enum LinkType {
 pub struct JoshutoMetadata {
     pub len: u64,
     pub directory_size: Option<usize>,
+    // total size of the contents of a directory, see `compute_sizes`
+    pub recursive_size: Option<u64>,
     pub modified: time::SystemTime,
     pub accessed: time::SystemTime,
+    // not supported by every file system
+    pub created: Option<time::SystemTime>,
     pub mode: Mode,
     pub file_type: FileType,
     pub link_type: LinkType,
@@ -56,6 +62,12 @@ pub struct JoshutoMetadata {
     pub uid: u32,
     #[cfg(unix)]
     pub gid: u32,
+    #[cfg(unix)]
+    pub changed: time::SystemTime,
+    #[cfg(unix)]
+    pub inode: u64,
+    #[cfg(unix)]
+    pub nlink: u64,
 }
 
 impl JoshutoMetadata {
@@ -73,6 +85,11 @@ impl JoshutoMetadata {
                 symlink_metadata.accessed()?,
             ),
         };
+        let created = metadata
+            .as_ref()
+            .unwrap_or(&symlink_metadata)
+            .created()
+            .ok();
 
         let directory_size = None;
         let (file_type, mode) = match metadata.as_ref() {
@@ -95,6 +112,11 @@ impl JoshutoMetadata {
             _ => (FileType::File, Mode::empty()),
         };
 
+        let recursive_size = match metadata.as_ref() {
+            Ok(metadata) if file_type == FileType::Directory => cached_dir_size(metadata),
+            _ => None,
+        };
+
         let link_type = if symlink_metadata.file_type().is_symlink() {
             let mut link = "".to_string();
 
@@ -117,12 +139,21 @@ impl JoshutoMetadata {
         let uid = symlink_metadata.uid();
         #[cfg(unix)]
         let gid = symlink_metadata.gid();
+        #[cfg(unix)]
+        let (changed, inode, nlink) = {
+            let m = metadata.as_ref().unwrap_or(&symlink_metadata);
+            let changed = time::UNIX_EPOCH
+                + time::Duration::new(m.ctime().max(0) as u64, m.ctime_nsec() as u32);
+            (changed, m.ino(), m.nlink())
+        };
 
         Ok(Self {
             len,
             directory_size,
+            recursive_size,
             modified,
             accessed,
+            created,
             mode,
             file_type,
             link_type,
@@ -130,6 +161,12 @@ impl JoshutoMetadata {
             uid,
             #[cfg(unix)]
             gid,
+            #[cfg(unix)]
+            changed,
+            #[cfg(unix)]
+            inode,
+            #[cfg(unix)]
+            nlink,
         })
     }
 
@@ -145,6 +182,14 @@ impl JoshutoMetadata {
         self.directory_size = Some(size);
     }
 
+    pub fn recursive_size(&self) -> Option<u64> {
+        self.recursive_size
+    }
+
+    pub fn update_recursive_size(&mut self, size: Option<u64>) {
+        self.recursive_size = size;
+    }
+
     pub fn modified(&self) -> time::SystemTime {
         self.modified
     }
@@ -153,6 +198,15 @@ impl JoshutoMetadata {
         self.accessed
     }
 
+    pub fn created(&self) -> Option<time::SystemTime> {
+        self.created
+    }
+
+    #[cfg(unix)]
+    pub fn changed(&self) -> time::SystemTime {
+        self.changed
+    }
+
     pub fn file_type(&self) -> FileType {
         self.file_type
     }
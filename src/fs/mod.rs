@@ -1,9 +1,23 @@
+mod dir_size;
 mod dirlist;
+mod disk_usage;
 mod entry;
+mod find;
+mod git;
+mod grep;
+mod ignore_filter;
 mod metadata;
 mod options;
+mod properties;
 
+pub use dir_size::*;
 pub use dirlist::*;
+pub use disk_usage::*;
 pub use entry::*;
+pub use find::*;
+pub use git::*;
+pub use grep::*;
+pub use ignore_filter::*;
 pub use metadata::*;
 pub use options::*;
+pub use properties::*;
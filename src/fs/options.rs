@@ -1,10 +1,21 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
 use crate::types::state::MatchState;
 
+use super::FindResults;
+
 /// Display options valid pre JoshutoDirList in a JoshutoTab
 #[derive(Clone, Debug)]
 pub struct DirListDisplayOptions {
     pub filter_state: MatchState,
     pub depth: u8,
+    // directories shown expanded in tree mode
+    pub expanded: HashSet<PathBuf>,
+    // listed instead of the contents of the directory
+    pub find_results: Option<FindResults>,
+    // overrides `show_hidden` of the display options for the directory
+    pub show_hidden: Option<bool>,
 }
 
 impl DirListDisplayOptions {
@@ -23,6 +34,43 @@ impl DirListDisplayOptions {
     pub fn depth(&self) -> u8 {
         self.depth
     }
+
+    /// The list is shown as a tree while any directory is expanded
+    pub fn is_tree(&self) -> bool {
+        !self.expanded.is_empty()
+    }
+
+    pub fn is_expanded(&self, path: &Path) -> bool {
+        self.expanded.contains(path)
+    }
+
+    pub fn set_expanded(&mut self, path: &Path, expanded: bool) {
+        if expanded {
+            self.expanded.insert(path.to_path_buf());
+        } else {
+            self.expanded.remove(path);
+        }
+    }
+
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    pub fn find_results_ref(&self) -> Option<&FindResults> {
+        self.find_results.as_ref()
+    }
+
+    pub fn find_results_mut(&mut self) -> Option<&mut FindResults> {
+        self.find_results.as_mut()
+    }
+
+    /// Replaces the find results, stopping the search of the former ones
+    pub fn set_find_results(&mut self, find_results: Option<FindResults>) {
+        if let Some(former) = self.find_results.as_ref() {
+            former.cancel();
+        }
+        self.find_results = find_results;
+    }
 }
 
 impl std::default::Default for DirListDisplayOptions {
@@ -30,6 +78,9 @@ impl std::default::Default for DirListDisplayOptions {
         Self {
             filter_state: MatchState::None,
             depth: 0,
+            expanded: HashSet::new(),
+            find_results: None,
+            show_hidden: None,
         }
     }
 }
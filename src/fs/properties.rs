@@ -0,0 +1,293 @@
+use std::collections::HashSet;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::{fs, io, time};
+
+use nix::sys::stat::Mode;
+
+use crate::utils::mimetype::get_mimetype;
+
+use super::cached_dir_size;
+
+// the longest symlink chain followed, like the kernel's limit
+const MAX_LINK_DEPTH: usize = 40;
+
+// xattrs holding POSIX ACLs, shown decoded instead of as raw values
+const ACL_ACCESS_XATTR: &str = "system.posix_acl_access";
+const ACL_DEFAULT_XATTR: &str = "system.posix_acl_default";
+
+/// The full stat of a file along with the details only shown in the properties view
+#[derive(Clone, Debug)]
+pub struct FileProperties {
+    pub path: PathBuf,
+    pub metadata: fs::Metadata,
+    // the targets of a symlink up to the first path that is not a symlink
+    pub link_chain: Vec<PathBuf>,
+    // only read on demand, as it runs `file`
+    pub mimetype: Option<String>,
+    pub xattrs: Vec<(String, String)>,
+    pub acl: Vec<String>,
+    pub default_acl: Vec<String>,
+}
+
+impl FileProperties {
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let metadata = fs::symlink_metadata(path)?;
+
+        let mut xattrs = Vec::new();
+        let mut acl = Vec::new();
+        let mut default_acl = Vec::new();
+        for (name, value) in read_xattrs(path) {
+            match name.as_str() {
+                ACL_ACCESS_XATTR => acl = parse_posix_acl(&value),
+                ACL_DEFAULT_XATTR => default_acl = parse_posix_acl(&value),
+                _ => xattrs.push((name, String::from_utf8_lossy(&value).to_string())),
+            }
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            metadata,
+            link_chain: symlink_chain(path),
+            mimetype: None,
+            xattrs,
+            acl,
+            default_acl,
+        })
+    }
+
+    pub fn read_mimetype(&mut self) {
+        self.mimetype = get_mimetype(&self.path)
+            .ok()
+            .map(|m| format!("{}/{}", m.get_type(), m.get_subtype()));
+    }
+
+    pub fn uid(&self) -> u32 {
+        self.metadata.uid()
+    }
+
+    pub fn gid(&self) -> u32 {
+        self.metadata.gid()
+    }
+
+    pub fn modified(&self) -> time::SystemTime {
+        self.metadata.modified().unwrap_or(time::UNIX_EPOCH)
+    }
+
+    pub fn mode(&self) -> Mode {
+        Mode::from_bits_truncate(self.metadata.mode() as _)
+    }
+
+    pub fn changed(&self) -> time::SystemTime {
+        time::UNIX_EPOCH
+            + time::Duration::new(
+                self.metadata.ctime().max(0) as u64,
+                self.metadata.ctime_nsec() as u32,
+            )
+    }
+
+    /// The size of a file or the recursive size of a directory, if it has been computed
+    pub fn size(&self) -> Option<u64> {
+        if self.metadata.is_dir() {
+            cached_dir_size(&self.metadata)
+        } else {
+            Some(self.metadata.len())
+        }
+    }
+
+    pub fn file_type_str(&self) -> &'static str {
+        use std::os::unix::fs::FileTypeExt;
+
+        let file_type = self.metadata.file_type();
+        if file_type.is_symlink() {
+            "symbolic link"
+        } else if file_type.is_dir() {
+            "directory"
+        } else if file_type.is_block_device() {
+            "block device"
+        } else if file_type.is_char_device() {
+            "character device"
+        } else if file_type.is_fifo() {
+            "fifo"
+        } else if file_type.is_socket() {
+            "socket"
+        } else {
+            "regular file"
+        }
+    }
+}
+
+/// Follows a symlink until a path that is not a symlink, stopping at loops
+pub fn symlink_chain(path: &Path) -> Vec<PathBuf> {
+    let mut chain = Vec::new();
+    let mut curr = path.to_path_buf();
+    let mut visited = HashSet::from([curr.clone()]);
+    while chain.len() < MAX_LINK_DEPTH {
+        let target = match fs::read_link(&curr) {
+            Ok(target) => target,
+            Err(_) => break,
+        };
+        let next = match curr.parent() {
+            Some(parent) => parent.join(&target),
+            None => target.clone(),
+        };
+        chain.push(target);
+        if !visited.insert(next.clone()) {
+            break;
+        }
+        curr = next;
+    }
+    chain
+}
+
+/// Reads the extended attributes of a file, symlinks are not followed
+#[cfg(target_os = "linux")]
+pub fn read_xattrs(path: &Path) -> Vec<(String, Vec<u8>)> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    use nix::libc::{lgetxattr, llistxattr};
+
+    let c_path = match CString::new(path.as_os_str().as_bytes()) {
+        Ok(s) => s,
+        Err(_) => return Vec::new(),
+    };
+
+    let names = {
+        let len = unsafe { llistxattr(c_path.as_ptr(), std::ptr::null_mut(), 0) };
+        if len <= 0 {
+            return Vec::new();
+        }
+        let mut buf = vec![0u8; len as usize];
+        let len = unsafe { llistxattr(c_path.as_ptr(), buf.as_mut_ptr() as *mut _, buf.len()) };
+        if len <= 0 {
+            return Vec::new();
+        }
+        buf.truncate(len as usize);
+        buf
+    };
+
+    let mut xattrs = Vec::new();
+    for name in names.split(|b| *b == 0).filter(|n| !n.is_empty()) {
+        let c_name = match CString::new(name) {
+            Ok(s) => s,
+            Err(_) => continue,
+        };
+        let len = unsafe { lgetxattr(c_path.as_ptr(), c_name.as_ptr(), std::ptr::null_mut(), 0) };
+        if len < 0 {
+            continue;
+        }
+        let mut value = vec![0u8; len as usize];
+        let len = unsafe {
+            lgetxattr(
+                c_path.as_ptr(),
+                c_name.as_ptr(),
+                value.as_mut_ptr() as *mut _,
+                value.len(),
+            )
+        };
+        if len < 0 {
+            continue;
+        }
+        value.truncate(len as usize);
+        xattrs.push((String::from_utf8_lossy(name).to_string(), value));
+    }
+    xattrs
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn read_xattrs(_path: &Path) -> Vec<(String, Vec<u8>)> {
+    Vec::new()
+}
+
+/// Decodes the value of a `system.posix_acl_*` xattr into entries like `getfacl` shows them
+pub fn parse_posix_acl(value: &[u8]) -> Vec<String> {
+    const ACL_USER_OBJ: u16 = 0x01;
+    const ACL_USER: u16 = 0x02;
+    const ACL_GROUP_OBJ: u16 = 0x04;
+    const ACL_GROUP: u16 = 0x08;
+    const ACL_MASK: u16 = 0x10;
+    const ACL_OTHER: u16 = 0x20;
+
+    // a 4 byte version header followed by 8 byte entries of tag, permissions and id
+    if value.len() < 4 {
+        return Vec::new();
+    }
+    value[4..]
+        .chunks_exact(8)
+        .filter_map(|entry| {
+            let tag = u16::from_le_bytes([entry[0], entry[1]]);
+            let perm = u16::from_le_bytes([entry[2], entry[3]]);
+            let id = u32::from_le_bytes([entry[4], entry[5], entry[6], entry[7]]);
+            let qualifier = match tag {
+                ACL_USER_OBJ => "user:".to_string(),
+                ACL_USER => format!("user:{}", id),
+                ACL_GROUP_OBJ => "group:".to_string(),
+                ACL_GROUP => format!("group:{}", id),
+                ACL_MASK => "mask:".to_string(),
+                ACL_OTHER => "other:".to_string(),
+                _ => return None,
+            };
+            let perm: String = [(4, 'r'), (2, 'w'), (1, 'x')]
+                .iter()
+                .map(|(bit, ch)| if perm & bit != 0 { *ch } else { '-' })
+                .collect();
+            Some(format!("{}:{}", qualifier, perm))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    fn acl_entry(tag: u16, perm: u16, id: u32) -> Vec<u8> {
+        let mut entry = tag.to_le_bytes().to_vec();
+        entry.extend(perm.to_le_bytes());
+        entry.extend(id.to_le_bytes());
+        entry
+    }
+
+    #[test]
+    fn test_parse_posix_acl() {
+        let mut value = 2u32.to_le_bytes().to_vec();
+        value.extend(acl_entry(0x01, 6, u32::MAX));
+        value.extend(acl_entry(0x02, 5, 1000));
+        value.extend(acl_entry(0x04, 4, u32::MAX));
+        value.extend(acl_entry(0x10, 7, u32::MAX));
+        value.extend(acl_entry(0x20, 0, u32::MAX));
+        assert_eq!(
+            parse_posix_acl(&value),
+            vec![
+                "user::rw-",
+                "user:1000:r-x",
+                "group::r--",
+                "mask::rwx",
+                "other::---"
+            ]
+        );
+        assert!(parse_posix_acl(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_symlink_chain() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        fs::write(dir.join("file"), "").unwrap();
+        std::os::unix::fs::symlink("file", dir.join("b")).unwrap();
+        std::os::unix::fs::symlink("b", dir.join("a")).unwrap();
+        std::os::unix::fs::symlink("loop", dir.join("loop")).unwrap();
+
+        assert_eq!(
+            symlink_chain(&dir.join("a")),
+            vec![PathBuf::from("b"), PathBuf::from("file")]
+        );
+        assert!(symlink_chain(&dir.join("file")).is_empty());
+        assert_eq!(
+            symlink_chain(&dir.join("loop")),
+            vec![PathBuf::from("loop")]
+        );
+    }
+}
@@ -4,10 +4,12 @@ use std::path::{Path, PathBuf};
 
 use walkdir::WalkDir;
 
-use crate::fs::{DirListDisplayOptions, JoshutoDirEntry, JoshutoDirList, JoshutoMetadata};
+use crate::fs::{
+    DirListDisplayOptions, IgnoreFilter, JoshutoDirEntry, JoshutoDirList, JoshutoMetadata,
+};
 use crate::tab::TabDisplayOption;
 use crate::types::option::display::DisplayOption;
-use crate::types::state::UiState;
+use crate::types::state::{MatchState, UiState};
 
 pub trait DirectoryHistory {
     fn insert_entries(&mut self, entries: Vec<JoshutoDirList>);
@@ -69,9 +71,18 @@ pub fn create_dirlist_with_history(
         }
     }
 
-    let sort_options = tab_options.sort_options_ref();
+    let sort_options = options
+        .dir_views
+        .sort_options(path, tab_options.sort_options_ref());
     contents.sort_by(|f1, f2| sort_options.compare(f1, f2));
 
+    let is_tree = tab_options
+        .dirlist_options_ref(&path.to_path_buf())
+        .map_or(false, |opts| opts.is_tree());
+    if is_tree {
+        contents = arrange_tree(contents, path);
+    }
+
     let contents_len = contents.len();
     let index = if contents_len == 0 {
         None
@@ -138,17 +149,60 @@ pub fn read_directory<F>(
 where
     F: Fn(&walkdir::DirEntry, &DisplayOption, &DirListDisplayOptions) -> bool,
 {
-    let dirlist_opts = tab_options
+    // find results are listed as they are, files that are gone since are left out
+    if let Some(find_results) = tab_options
+        .dirlist_options_ref(&path.to_path_buf())
+        .and_then(|opts| opts.find_results_ref())
+    {
+        return Ok(find_results
+            .paths
+            .iter()
+            .filter_map(|p| JoshutoDirEntry::from_path(p, path, display_options).ok())
+            .collect());
+    }
+
+    let mut dirlist_opts = tab_options
         .dirlist_options_ref(&path.to_path_buf())
         .map(|v| v.to_owned())
         .unwrap_or_default();
+    // the view remembered for the directory applies until a filter is set in the tab
+    if let Some(view) = display_options.dir_views.get(path) {
+        dirlist_opts.show_hidden = view.show_hidden;
+        if let (MatchState::None, Some(filter)) = (&dirlist_opts.filter_state, &view.filter) {
+            dirlist_opts.set_filter_state(filter.state.clone());
+        }
+    }
+
+    let mut ignore_filter = if display_options.show_ignored {
+        None
+    } else {
+        Some(IgnoreFilter::new(path))
+    };
+
+    // in tree mode, only the contents of expanded directories are shown
+    let max_depth = if dirlist_opts.is_tree() {
+        usize::MAX
+    } else {
+        dirlist_opts.depth() as usize + 1
+    };
+    let is_visible = |e: &walkdir::DirEntry| {
+        !dirlist_opts.is_tree()
+            || e.depth() <= 1
+            || e.path()
+                .parent()
+                .map_or(false, |parent| dirlist_opts.is_expanded(parent))
+    };
 
     let results: Vec<JoshutoDirEntry> = WalkDir::new(path)
-        .max_depth(dirlist_opts.depth() as usize + 1)
+        .max_depth(max_depth)
         .into_iter()
         .filter_entry(|e| {
             if e.path().to_str().cmp(&path.to_str()).is_ne() {
-                filter_func(e, display_options, &dirlist_opts)
+                is_visible(e)
+                    && filter_func(e, display_options, &dirlist_opts)
+                    && !ignore_filter
+                        .as_mut()
+                        .map_or(false, |f| f.is_ignored(e.path(), e.file_type().is_dir()))
             } else {
                 true
             }
@@ -161,11 +215,59 @@ where
             }
         })
         .filter_map(|res| JoshutoDirEntry::from(&res.ok()?, path, display_options).ok())
+        // filter expressions need the metadata, so they are only matched once it is read
+        .filter(|entry| match dirlist_opts.filter_state_ref() {
+            MatchState::Expr(expr) => expr.is_match(entry),
+            _ => true,
+        })
         .collect();
 
     Ok(results)
 }
 
+/// Orders sorted entries depth first, so every expanded directory is followed by its contents,
+/// and sets their indentation guides
+fn arrange_tree(contents: Vec<JoshutoDirEntry>, root: &Path) -> Vec<JoshutoDirEntry> {
+    let mut children: HashMap<PathBuf, Vec<JoshutoDirEntry>> = HashMap::new();
+    for entry in contents {
+        let parent = entry.file_path().parent().unwrap_or(root).to_path_buf();
+        children.entry(parent).or_default().push(entry);
+    }
+
+    let mut tree = Vec::new();
+    push_tree_children(&mut tree, &mut children, root, None);
+    tree
+}
+
+fn push_tree_children(
+    tree: &mut Vec<JoshutoDirEntry>,
+    children: &mut HashMap<PathBuf, Vec<JoshutoDirEntry>>,
+    dir: &Path,
+    guide: Option<&str>,
+) {
+    let entries = match children.remove(dir) {
+        Some(entries) => entries,
+        None => return,
+    };
+    let len = entries.len();
+    for (i, mut entry) in entries.into_iter().enumerate() {
+        let is_last = i + 1 == len;
+        let path = entry.file_path_buf();
+        // entries at the top level have no guides
+        let child_guide = guide.map(|guide| {
+            entry.tree_guide = format!("{}{}", guide, if is_last { "└─ " } else { "├─ " });
+            format!("{}{}", guide, if is_last { "   " } else { "│  " })
+        });
+        tree.push(entry);
+        push_tree_children(
+            tree,
+            children,
+            &path,
+            Some(child_guide.as_deref().unwrap_or("")),
+        );
+    }
+}
+
 pub fn generate_entries_to_root(
     path: &Path,
     history: &JoshutoHistory,
@@ -203,3 +305,42 @@ pub fn generate_entries_to_root(
     }
     Ok(dirlists)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn expanded_directories_are_followed_by_their_contents() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("a/nested")).unwrap();
+        fs::create_dir_all(root.join("b")).unwrap();
+        fs::write(root.join("a/nested/deep"), "").unwrap();
+        fs::write(root.join("a/x"), "").unwrap();
+        fs::write(root.join("b/hidden"), "").unwrap();
+        fs::write(root.join("c"), "").unwrap();
+
+        let mut tab_options = TabDisplayOption::default();
+        let dirlist_options = tab_options.dirlist_options_mut(&root.to_path_buf());
+        dirlist_options.set_expanded(&root.join("a"), true);
+        dirlist_options.set_expanded(&root.join("a/nested"), true);
+
+        let dirlist = create_dirlist_with_history(
+            &JoshutoHistory::new(),
+            root,
+            &DisplayOption::default(),
+            &tab_options,
+        )
+        .unwrap();
+        let rows: Vec<String> = dirlist
+            .iter()
+            .map(|e| format!("{}{}", e.tree_guide, e.display_name()))
+            .collect();
+        assert_eq!(rows, ["a", "├─ nested", "│  └─ deep", "└─ x", "b", "c"]);
+    }
+}
@@ -0,0 +1,14 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path;
+
+/// Sends a single request to a running instance and returns its response
+pub fn send_message(socket: &path::Path, message: &str) -> io::Result<String> {
+    let mut stream = UnixStream::connect(socket)?;
+    writeln!(stream, "{}", message)?;
+    stream.shutdown(std::net::Shutdown::Write)?;
+
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response)?;
+    Ok(response.trim_end().to_string())
+}
@@ -0,0 +1,69 @@
+mod client;
+mod server;
+
+pub use client::*;
+pub use server::*;
+
+use serde_json::json;
+
+use crate::types::state::AppState;
+
+/// Environment variable holding the socket path of the running instance,
+/// exported to all child processes
+pub const SOCKET_ENV_VAR: &str = "JOSHUTO_SOCKET";
+
+#[derive(Clone, Copy, Debug)]
+pub enum IpcQuery {
+    Cwd,
+    Selection,
+    Tabs,
+}
+
+impl IpcQuery {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "cwd" => Some(Self::Cwd),
+            "selection" => Some(Self::Selection),
+            "tabs" => Some(Self::Tabs),
+            _ => None,
+        }
+    }
+}
+
+pub fn ok_response() -> String {
+    json!({ "ok": true }).to_string()
+}
+
+pub fn error_response(msg: &str) -> String {
+    json!({ "error": msg }).to_string()
+}
+
+pub fn answer_query(app_state: &AppState, query: IpcQuery) -> String {
+    let tab_state = app_state.state.tab_state_ref();
+    let curr_tab = tab_state.curr_tab_ref();
+    match query {
+        IpcQuery::Cwd => json!({ "cwd": curr_tab.get_cwd() }).to_string(),
+        IpcQuery::Selection => {
+            let selection = curr_tab
+                .curr_list_ref()
+                .map(|list| list.get_selected_paths())
+                .unwrap_or_default();
+            json!({ "selection": selection }).to_string()
+        }
+        IpcQuery::Tabs => {
+            let tabs: Vec<_> = tab_state
+                .tab_refs_in_order()
+                .iter()
+                .enumerate()
+                .map(|(i, tab)| {
+                    json!({
+                        "index": i + 1,
+                        "cwd": tab.get_cwd(),
+                        "current": i == tab_state.index,
+                    })
+                })
+                .collect();
+            json!({ "tabs": tabs }).to_string()
+        }
+    }
+}
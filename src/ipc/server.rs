@@ -0,0 +1,154 @@
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path;
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::thread;
+
+use nix::unistd::Uid;
+
+use crate::types::command::Command;
+use crate::types::event::AppEvent;
+
+use super::{error_response, IpcQuery};
+
+/// Listens on a Unix domain socket and forwards received commands and queries to the main loop.
+/// The socket file is removed when the server is dropped.
+pub struct IpcServer {
+    path: path::PathBuf,
+}
+
+impl IpcServer {
+    pub fn start(event_tx: mpsc::Sender<AppEvent>) -> io::Result<Self> {
+        let base = std::env::var_os("XDG_RUNTIME_DIR")
+            .map(path::PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir);
+        let path = private_dir(&base)?.join(format!("joshuto-{}.sock", std::process::id()));
+        if let Ok(metadata) = fs::symlink_metadata(&path) {
+            if metadata.uid() != Uid::current().as_raw() {
+                return Err(not_owned_error(&path));
+            }
+            fs::remove_file(&path)?;
+        }
+        let listener = UnixListener::bind(&path)?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+
+        let _ = thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let event_tx = event_tx.clone();
+                let _ = thread::spawn(move || handle_client(stream, event_tx));
+            }
+        });
+        Ok(Self { path })
+    }
+
+    pub fn path(&self) -> &path::Path {
+        &self.path
+    }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Returns a directory below `base` only the current user has access to, creating it if needed.
+/// The socket is bound inside of it, so other users can never connect to it,
+/// not even before its own permissions are set.
+fn private_dir(base: &path::Path) -> io::Result<path::PathBuf> {
+    let uid = Uid::current().as_raw();
+    let dir = base.join(format!("joshuto-{}", uid));
+    match fs::symlink_metadata(&dir) {
+        Ok(metadata) => {
+            if !metadata.is_dir() || metadata.uid() != uid {
+                return Err(not_owned_error(&dir));
+            }
+            if metadata.mode() & 0o077 != 0 {
+                fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            fs::DirBuilder::new().mode(0o700).create(&dir)?;
+        }
+        Err(e) => return Err(e),
+    }
+    Ok(dir)
+}
+
+fn not_owned_error(path: &path::Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!("{} is not owned by the current user", path.display()),
+    )
+}
+
+fn handle_client(stream: UnixStream, event_tx: mpsc::Sender<AppEvent>) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let response = handle_request(line, &event_tx);
+        writeln!(writer, "{}", response)?;
+    }
+    Ok(())
+}
+
+// each request is answered with a single line of JSON
+fn handle_request(line: &str, event_tx: &mpsc::Sender<AppEvent>) -> String {
+    let (reply_tx, reply_rx) = mpsc::channel();
+    let event = match line.strip_prefix("get ") {
+        Some(query) => match IpcQuery::from_str(query.trim()) {
+            Some(query) => AppEvent::IpcQuery {
+                query,
+                reply: reply_tx,
+            },
+            None => return error_response(&format!("Unknown query '{}'", query.trim())),
+        },
+        None => match Command::from_str(line) {
+            Ok(command) => AppEvent::IpcCommand {
+                command,
+                reply: reply_tx,
+            },
+            Err(e) => return error_response(&e.to_string()),
+        },
+    };
+    if event_tx.send(event).is_err() {
+        return error_response("joshuto is shutting down");
+    }
+    // the reply is dropped without an answer if joshuto is waiting for user input
+    reply_rx
+        .recv()
+        .unwrap_or_else(|_| error_response("joshuto is busy, command was not executed"))
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn sockets_are_kept_in_a_private_dir() {
+        let base = TempDir::new().unwrap();
+
+        let dir = private_dir(base.path()).unwrap();
+        let mode = fs::metadata(&dir).unwrap().mode();
+        assert_eq!(mode & 0o777, 0o700);
+
+        // permissions of an existing directory are tightened
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o755)).unwrap();
+        assert_eq!(private_dir(base.path()).unwrap(), dir);
+        assert_eq!(fs::metadata(&dir).unwrap().mode() & 0o777, 0o700);
+
+        // a symlink could point anywhere, e.g. to a directory of another user
+        fs::remove_dir(&dir).unwrap();
+        std::os::unix::fs::symlink(base.path(), &dir).unwrap();
+        assert!(private_dir(base.path()).is_err());
+    }
+}
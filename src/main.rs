@@ -4,8 +4,11 @@ mod constants;
 mod error;
 mod fs;
 mod history;
+mod ipc;
 mod preview;
 mod run;
+#[cfg(feature = "scripting")]
+mod scripting;
 mod shadow;
 mod tab;
 mod traits;
@@ -22,13 +25,16 @@ use std::sync::Mutex;
 use clap::{CommandFactory, Parser, Subcommand};
 use config::app::AppConfig;
 use config::bookmarks::Bookmarks;
+use config::check::Severity;
 use config::icon::AppIcons;
+use config::macros::Macros;
 use config::mimetype::AppProgramRegistry;
 use config::preview::FileEntryPreview;
 use config::theme::AppTheme;
 use lazy_static::lazy_static;
 
 use traits::config::TomlConfigFile;
+use types::config_cell::ConfigCell;
 use types::config_type::ConfigType;
 use types::keymap::AppKeyMapping;
 use utils::cwd;
@@ -67,11 +73,14 @@ lazy_static! {
 
         config_dirs
     };
-    static ref THEME_T: AppTheme = AppTheme::get_config();
-    static ref MIMETYPE_T: AppProgramRegistry = AppProgramRegistry::get_config();
-    static ref PREVIEW_T: FileEntryPreview = FileEntryPreview::get_config_or_default();
+    static ref THEME_T: ConfigCell<AppTheme> = ConfigCell::new(AppTheme::get_config());
+    static ref MIMETYPE_T: ConfigCell<AppProgramRegistry> =
+        ConfigCell::new(AppProgramRegistry::get_config());
+    static ref PREVIEW_T: ConfigCell<FileEntryPreview> =
+        ConfigCell::new(FileEntryPreview::get_config_or_default());
     static ref BOOKMARKS_T: Mutex<Bookmarks> = Mutex::new(Bookmarks::get_config());
-    static ref ICONS_T: AppIcons = AppIcons::get_config();
+    static ref MACROS_T: Mutex<Macros> = Mutex::new(Macros::get_config());
+    static ref ICONS_T: ConfigCell<AppIcons> = ConfigCell::new(AppIcons::get_config());
 
     static ref HOME_DIR: Option<PathBuf> = dirs_next::home_dir();
 
@@ -116,6 +125,19 @@ pub enum Commands {
         config_type: ConfigType,
     },
 
+    /// Check config files for errors, e.g. unknown commands or conflicting keybindings.
+    CheckConfig,
+
+    /// Send a command or query (e.g. 'get cwd') to a running joshuto instance.
+    Msg {
+        /// Socket of the instance, defaults to $JOSHUTO_SOCKET.
+        #[arg(long = "socket")]
+        socket: Option<PathBuf>,
+
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        message: Vec<String>,
+    },
+
     /// Print 'joshuto' build version.
     Version,
 }
@@ -136,6 +158,8 @@ fn run_main(args: Args) -> Result<i32, AppError> {
                     Ok(0)
                 }
             },
+            Commands::CheckConfig => check_config(),
+            Commands::Msg { socket, message } => send_msg(socket, &message.join(" ")),
             Commands::Version => print_version(),
         };
         return result;
@@ -157,6 +181,7 @@ fn run_main(args: Args) -> Result<i32, AppError> {
     lazy_static::initialize(&MIMETYPE_T);
     lazy_static::initialize(&PREVIEW_T);
     lazy_static::initialize(&BOOKMARKS_T);
+    lazy_static::initialize(&MACROS_T);
     lazy_static::initialize(&ICONS_T);
 
     lazy_static::initialize(&HOME_DIR);
@@ -165,6 +190,21 @@ fn run_main(args: Args) -> Result<i32, AppError> {
 
     let mouse_support = config.mouse_support;
     let mut app_state = AppState::new(config, args.clone());
+
+    // the server removes its socket when dropped at the end of this function
+    let _ipc_server = match ipc::IpcServer::start(app_state.clone_event_tx()) {
+        Ok(server) => {
+            std::env::set_var(ipc::SOCKET_ENV_VAR, server.path());
+            Some(server)
+        }
+        Err(e) => {
+            app_state
+                .state
+                .message_queue_mut()
+                .push_error(format!("Failed to create IPC socket: {}", e));
+            None
+        }
+    };
     {
         let mut backend: ui::AppBackend = ui::AppBackend::new(mouse_support)?;
         run::process_run_loop::run_loop(&mut backend, &mut app_state, keymap)?;
@@ -219,6 +259,40 @@ fn run_quit(args: &Args, app_state: &AppState) -> Result<(), AppError> {
     Ok(())
 }
 
+fn check_config() -> Result<i32, AppError> {
+    let report = config::check::check_config();
+    for diagnostic in report.diagnostics.iter() {
+        println!("{}", diagnostic);
+    }
+    let errors = report.count(Severity::Error);
+    let warnings = report.count(Severity::Warning);
+    println!("{} error(s), {} warning(s)", errors, warnings);
+    if report.has_errors() {
+        Ok(1)
+    } else {
+        Ok(0)
+    }
+}
+
+fn send_msg(socket: Option<PathBuf>, message: &str) -> Result<i32, AppError> {
+    let socket = match socket.or_else(|| std::env::var_os(ipc::SOCKET_ENV_VAR).map(PathBuf::from)) {
+        Some(socket) => socket,
+        None => {
+            return AppError::fail(format!(
+                "no socket given and ${} is not set",
+                ipc::SOCKET_ENV_VAR
+            ))
+        }
+    };
+    let response = ipc::send_message(&socket, message)?;
+    println!("{}", response);
+    if response.starts_with("{\"error\"") {
+        Ok(1)
+    } else {
+        Ok(0)
+    }
+}
+
 fn print_version() -> Result<i32, AppError> {
     writeln!(
         &mut std::io::stdout(),
@@ -259,6 +333,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_check_config() {
+        match Args::parse_from(["program_name", "check-config"]).commands {
+            Some(Commands::CheckConfig) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_command_msg() {
+        match Args::parse_from(["program_name", "msg", "get", "cwd"]).commands {
+            Some(Commands::Msg {
+                socket: None,
+                message,
+            }) => assert_eq!(message, vec!["get", "cwd"]),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn test_command_completions() {
         for shell in ["bash", "zsh", "fish", "elvish", "powershell"] {
@@ -2,6 +2,7 @@ use std::io;
 use std::path;
 
 use notify;
+use notify::event::{AccessKind, AccessMode, EventKind, ModifyKind, RenameMode};
 use ratatui::layout::Rect;
 use ratatui::layout::{Constraint, Direction, Layout};
 use signal_hook::consts::signal;
@@ -9,9 +10,11 @@ use termion::event::{Event, Key, MouseButton, MouseEvent};
 use uuid::Uuid;
 
 use crate::commands::tab_ops;
-use crate::commands::{cursor_move, parent_cursor_move, reload};
+use crate::commands::{cursor_move, find, parent_cursor_move, reload, reload_config, select};
 use crate::error::AppResult;
-use crate::fs::JoshutoDirList;
+use crate::fs::{invalidate_dir_sizes, JoshutoDirList};
+use crate::history::DirectoryHistory;
+use crate::ipc;
 use crate::preview::preview_dir::PreviewDirState;
 use crate::preview::preview_file::PreviewFileState;
 use crate::traits::app_execute::AppExecute;
@@ -23,9 +26,12 @@ use crate::types::io::IoTaskStat;
 use crate::types::keybind::CommandKeybind;
 use crate::types::keybind::KeyMapping;
 use crate::types::keymap::AppKeyMapping;
+use crate::types::option::hooks::Hook;
+use crate::types::option::sort::SortMethod;
 use crate::types::state::AppState;
 use crate::ui;
-use crate::ui::views::TuiCommandMenu;
+use crate::ui::views::{TuiCommandMenu, TuiView};
+use crate::ui::widgets::{TuiFooter, TuiTopBar};
 use crate::utils::format;
 
 pub fn poll_event_until_simple_keybind<'a>(
@@ -74,19 +80,63 @@ pub fn process_noninteractive(event: AppEvent, app_state: &mut AppState) {
         AppEvent::IoTaskResult(res) => process_finished_io_task(app_state, res),
         AppEvent::PreviewDir { id, path, res } => process_dir_preview(app_state, id, path, *res),
         AppEvent::PreviewFile { path, res } => process_file_preview(app_state, path, res),
+        AppEvent::GitStatus { dir, res } => {
+            app_state
+                .state
+                .git_state_mut()
+                .insert(dir, res.map(|res| *res));
+        }
+        AppEvent::DirSize {
+            path,
+            size,
+            unreadable,
+            done,
+        } => process_dir_size(app_state, path, size, unreadable, done),
+        AppEvent::FindMatches { id, paths } => find::process_find_matches(app_state, id, paths),
+        AppEvent::FindDone { id, res } => find::process_find_done(app_state, id, res),
         AppEvent::Signal(signal::SIGWINCH) => {}
         AppEvent::Filesystem(e) => process_filesystem_event(e, app_state),
         AppEvent::ChildProcessComplete(child_id) => {
             app_state.state.thread_pool.join_child(child_id);
         }
+        AppEvent::IpcQuery { query, reply } => {
+            let _ = reply.send(ipc::answer_query(app_state, query));
+        }
         _ => {}
     }
 }
 
-fn process_filesystem_event(_event: notify::Event, app_state: &mut AppState) {
+fn process_filesystem_event(event: notify::Event, app_state: &mut AppState) {
+    if is_config_change(&event, app_state) {
+        if let Err(e) = reload_config::reload_config(app_state) {
+            app_state
+                .state
+                .message_queue_mut()
+                .push_error(e.to_string());
+        }
+    }
+    app_state.state.git_state_mut().invalidate(&event.paths);
+    invalidate_dir_sizes(&event.paths);
     let _ = reload::soft_reload_curr_tab(app_state);
 }
 
+// only react to finished writes, editors tend to emit many events per save
+fn is_config_change(event: &notify::Event, app_state: &AppState) -> bool {
+    let watched_config_dirs = &app_state.state.watched_config_dirs;
+    let is_write = matches!(
+        event.kind,
+        EventKind::Access(AccessKind::Close(AccessMode::Write))
+            | EventKind::Create(_)
+            | EventKind::Modify(ModifyKind::Name(RenameMode::To))
+    );
+    is_write
+        && event.paths.iter().any(|path| {
+            path.parent().map_or(false, |parent| {
+                watched_config_dirs.iter().any(|dir| dir == parent)
+            }) && reload_config::is_config_file(path)
+        })
+}
+
 pub fn process_new_io_task(app_state: &mut AppState) {
     if app_state.state.worker_state_ref().is_busy() {
         return;
@@ -113,6 +163,10 @@ pub fn process_finished_io_task(app_state: &mut AppState, res: AppResult) {
     match res {
         Err(err) => {
             let msg = format!("{err}");
+            app_state
+                .config
+                .hooks
+                .run(Hook::IoTaskDone, ["error", msg.as_str()]);
             app_state.state.message_queue_mut().push_error(msg);
         }
         Ok(_) => {
@@ -132,6 +186,15 @@ pub fn process_finished_io_task(app_state: &mut AppState, res: AppResult) {
                     tab_ops::remove_entry_from_all_tabs(app_state, io_path);
                 }
 
+                app_state.config.hooks.run(
+                    Hook::IoTaskDone,
+                    [
+                        "ok".as_ref(),
+                        io_stat.progress.kind.actioned_str().as_ref(),
+                        io_stat.dest_path().as_os_str(),
+                    ],
+                );
+
                 let progress = io_stat.progress;
                 let op = progress.kind.actioned_str();
                 let processed_size = format::file_size_to_string(progress.bytes_processed);
@@ -148,6 +211,57 @@ pub fn process_finished_io_task(app_state: &mut AppState, res: AppResult) {
     process_new_io_task(app_state);
 }
 
+pub fn process_dir_size(
+    app_state: &mut AppState,
+    path: path::PathBuf,
+    size: Option<u64>,
+    unreadable: usize,
+    done: bool,
+) {
+    // a failed computation clears the running total shown so far
+    if let Some(parent) = path.parent().filter(|_| size.is_some() || done) {
+        for (_, tab) in app_state.state.tab_state_mut().iter_mut() {
+            if let Some(dirlist) = tab.history_mut().get_mut(parent) {
+                for entry in dirlist.iter_mut().filter(|e| e.file_path() == path) {
+                    entry.metadata.update_recursive_size(size);
+                }
+            }
+        }
+    }
+    if !done {
+        return;
+    }
+    if unreadable > 0 {
+        let msg = format!(
+            "{} entries below {} could not be read, its size is incomplete",
+            unreadable,
+            path.to_string_lossy()
+        );
+        app_state.state.message_queue_mut().push_error(msg);
+    }
+
+    let dir_size_state = app_state.state.dir_size_state_mut();
+    dir_size_state.finish(&path);
+    if dir_size_state.is_computing() {
+        return;
+    }
+    // all sizes are known now, so lists sorted by size can be sorted again
+    let curr_tab = app_state.state.tab_state_mut().curr_tab_mut();
+    let sorted_by_size = app_state
+        .config
+        .display_options
+        .dir_views
+        .sort_options(curr_tab.get_cwd(), curr_tab.option_ref().sort_options_ref())
+        .sort_methods
+        .list
+        .front()
+        == Some(&SortMethod::Size);
+    if sorted_by_size {
+        curr_tab.history_mut().depreciate_all_entries();
+        let _ = reload::soft_reload_curr_tab(app_state);
+    }
+}
+
 pub fn process_dir_preview(
     app_state: &mut AppState,
     id: Uuid,
@@ -243,6 +357,30 @@ enum Panel {
     Preview,
 }
 
+fn panel_at(layout_rect: &[Rect], x: u16) -> Panel {
+    if x < layout_rect[1].x {
+        Panel::Parent
+    } else if x < layout_rect[2].x {
+        Panel::Current
+    } else {
+        Panel::Preview
+    }
+}
+
+fn execute_mouse_command(
+    app_state: &mut AppState,
+    backend: &mut ui::AppBackend,
+    keymap_t: &AppKeyMapping,
+    command: Command,
+) {
+    if let Err(e) = command.execute(app_state, backend, keymap_t) {
+        app_state
+            .state
+            .message_queue_mut()
+            .push_error(e.to_string());
+    }
+}
+
 fn children_cursor_move(app_state: &mut AppState, new_index: usize) {
     let mut new_index = new_index;
     let ui_state = app_state.state.ui_state_ref().clone();
@@ -263,12 +401,158 @@ fn children_cursor_move(app_state: &mut AppState, new_index: usize) {
     }
 }
 
+/// A left click moves the cursor to the entry and opens it on a double click,
+/// with ctrl held it toggles the selection of the entry instead
+fn click_current_entry(
+    app_state: &mut AppState,
+    backend: &mut ui::AppBackend,
+    keymap_t: &AppKeyMapping,
+    button: MouseButton,
+    ctrl: bool,
+    index: usize,
+) {
+    cursor_move::cursor_move(app_state, index);
+    // clicks below the last entry only move the cursor
+    let clicked_path = match app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+        .filter(|list| index < list.len())
+        .and_then(|list| list.curr_entry_ref())
+    {
+        Some(entry) => entry.file_path_buf(),
+        None => return,
+    };
+    match button {
+        MouseButton::Left if ctrl => {
+            if let Some(entry) = app_state
+                .state
+                .tab_state_mut()
+                .curr_tab_mut()
+                .curr_list_mut()
+                .and_then(|list| list.curr_entry_mut())
+            {
+                entry.set_permanent_selected(!entry.is_selected());
+            }
+            select::run_select_hook(app_state);
+        }
+        MouseButton::Left => {
+            let mouse_state = app_state.state.mouse_state_mut();
+            mouse_state.pressed = true;
+            if mouse_state.click(&clicked_path) {
+                execute_mouse_command(app_state, backend, keymap_t, Command::OpenFile);
+            }
+        }
+        MouseButton::Right => {
+            execute_mouse_command(app_state, backend, keymap_t, Command::ContextMenu);
+        }
+        _ => {}
+    }
+}
+
+/// Dragging with the left button held selects the entries from the one the button
+/// went down on via visual mode, rows above and below the view scroll it
+fn drag_current_entries(app_state: &mut AppState, area: Rect, y: u16) {
+    if !app_state.state.mouse_state_mut().pressed {
+        return;
+    }
+    let column_headers = app_state.config.display_options.column_headers;
+    let (new_index, in_visual_mode) = match app_state
+        .state
+        .tab_state_ref()
+        .curr_tab_ref()
+        .curr_list_ref()
+    {
+        Some(list) => {
+            let skip_dist = list.first_index_for_viewport();
+            let first_row = area.y + 1 + u16::from(column_headers);
+            let new_index = if y < first_row {
+                skip_dist.saturating_sub(1)
+            } else {
+                skip_dist + (y - first_row) as usize
+            };
+            if list.get_index() == Some(new_index) {
+                return;
+            }
+            (new_index, list.get_visual_mode_anchor_index().is_some())
+        }
+        None => return,
+    };
+    if !in_visual_mode {
+        if let Some(list) = app_state
+            .state
+            .tab_state_mut()
+            .curr_tab_mut()
+            .curr_list_mut()
+        {
+            list.toggle_visual_mode();
+        }
+        app_state.state.mouse_state_mut().dragging = true;
+    }
+    cursor_move::cursor_move(app_state, new_index);
+}
+
+/// Keeps the entries selected by dragging once the button is released
+fn release_current_entries(app_state: &mut AppState) {
+    let mouse_state = app_state.state.mouse_state_mut();
+    mouse_state.pressed = false;
+    if !std::mem::take(&mut mouse_state.dragging) {
+        return;
+    }
+    if let Some(list) = app_state
+        .state
+        .tab_state_mut()
+        .curr_tab_mut()
+        .curr_list_mut()
+    {
+        if list.get_visual_mode_anchor_index().is_some() {
+            list.toggle_visual_mode();
+        }
+    }
+    select::run_select_hook(app_state);
+}
+
+/// The command of the footer indicator at column `x`, if the footer is shown
+fn footer_command_at(app_state: &AppState, area: Rect, x: u16) -> Option<Command> {
+    if app_state.state.worker_state_ref().get_msg().is_some()
+        || app_state
+            .state
+            .message_queue_ref()
+            .current_message()
+            .is_some()
+    {
+        return None;
+    }
+    let curr_tab = app_state.state.tab_state_ref().curr_tab_ref();
+    let list = curr_tab.curr_list_ref()?;
+    TuiFooter::new(list, curr_tab.option_ref())
+        .mode(app_state.state.mode_stack.last().map(|s| s.as_str()))
+        .dir_view(
+            app_state
+                .config
+                .display_options
+                .dir_views
+                .get(list.file_path()),
+        )
+        .branch(
+            app_state
+                .state
+                .git_state_ref()
+                .dir_status(list.file_path())
+                .and_then(|git| git.branch()),
+        )
+        .dir_sizes(app_state.state.dir_size_state_ref().status())
+        .command_at(area, x)
+}
+
 pub fn process_mouse(
     app_state: &mut AppState,
     backend: &mut ui::AppBackend,
     keymap_t: &AppKeyMapping,
     event: MouseEvent,
 ) {
+    let ctrl = app_state.events.mouse_ctrl_held();
     let f_size = backend.terminal.as_ref().unwrap().size().unwrap();
 
     let rect = Rect {
@@ -291,54 +575,34 @@ pub fn process_mouse(
         .constraints(constraints.as_ref())
         .split(rect);
 
+    // mouse positions start at 1, areas at 0
     match event {
         MouseEvent::Press(MouseButton::WheelUp, x, _) => {
-            if x < layout_rect[1].x {
-                let command = Command::ParentCursorMoveUp { offset: 1 };
-                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                    app_state
-                        .state
-                        .message_queue_mut()
-                        .push_error(e.to_string());
-                }
-            } else if x < layout_rect[2].x {
-                let command = Command::CursorMoveUp { offset: 1 };
-                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                    app_state
-                        .state
-                        .message_queue_mut()
-                        .push_error(e.to_string());
-                }
-            } else {
-                let command = Command::PreviewCursorMoveUp { offset: 1 };
-                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                    app_state
-                        .state
-                        .message_queue_mut()
-                        .push_error(e.to_string());
-                }
-            }
+            let command = match panel_at(&layout_rect, x) {
+                Panel::Parent => Command::ParentCursorMoveUp { offset: 1 },
+                Panel::Current => Command::CursorMoveUp { offset: 1 },
+                Panel::Preview => Command::PreviewCursorMoveUp { offset: 1 },
+            };
+            execute_mouse_command(app_state, backend, keymap_t, command);
         }
         MouseEvent::Press(MouseButton::WheelDown, x, _) => {
-            if x < layout_rect[1].x {
-                let command = Command::ParentCursorMoveDown { offset: 1 };
-                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                    app_state
-                        .state
-                        .message_queue_mut()
-                        .push_error(e.to_string());
-                }
-            } else if x < layout_rect[2].x {
-                let command = Command::CursorMoveDown { offset: 1 };
-                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                    app_state
-                        .state
-                        .message_queue_mut()
-                        .push_error(e.to_string());
-                }
-            } else {
-                let command = Command::PreviewCursorMoveDown { offset: 1 };
-                if let Err(e) = command.execute(app_state, backend, keymap_t) {
+            let command = match panel_at(&layout_rect, x) {
+                Panel::Parent => Command::ParentCursorMoveDown { offset: 1 },
+                Panel::Current => Command::CursorMoveDown { offset: 1 },
+                Panel::Preview => Command::PreviewCursorMoveDown { offset: 1 },
+            };
+            execute_mouse_command(app_state, backend, keymap_t, command);
+        }
+        // the top bar shows the tabs
+        MouseEvent::Press(button, x, 1) => {
+            let area = TuiView::new(app_state).header_area(&rect);
+            if let Some(index) = TuiTopBar::new(app_state).tab_index_at(area, x - 1) {
+                let res = match button {
+                    MouseButton::Left => tab_ops::tab_switch_index(app_state, index + 1),
+                    MouseButton::Middle => tab_ops::close_tab_index(app_state, index),
+                    _ => Ok(()),
+                };
+                if let Err(e) = res {
                     app_state
                         .state
                         .message_queue_mut()
@@ -346,42 +610,28 @@ pub fn process_mouse(
                 }
             }
         }
+        MouseEvent::Press(MouseButton::Left, x, y) if y == f_size.height => {
+            let area = TuiView::new(app_state).footer_area(&rect);
+            if let Some(command) = footer_command_at(app_state, area, x - 1) {
+                execute_mouse_command(app_state, backend, keymap_t, command);
+            }
+        }
         MouseEvent::Press(button @ MouseButton::Left, x, y)
         | MouseEvent::Press(button @ MouseButton::Right, x, y) => {
             if y > layout_rect[1].y && y <= layout_rect[1].y + layout_rect[1].height {
-                let (dirlist, panel) = if x < layout_rect[1].x {
-                    (
-                        app_state
-                            .state
-                            .tab_state_mut()
-                            .curr_tab_mut()
-                            .parent_list_ref(),
-                        Some(Panel::Parent),
-                    )
-                } else if x < layout_rect[2].x {
-                    (
-                        app_state
-                            .state
-                            .tab_state_mut()
-                            .curr_tab_mut()
-                            .curr_list_ref(),
-                        Some(Panel::Current),
-                    )
-                } else {
-                    (
-                        app_state
-                            .state
-                            .tab_state_mut()
-                            .curr_tab_mut()
-                            .child_list_ref(),
-                        Some(Panel::Preview),
-                    )
+                let panel = panel_at(&layout_rect, x);
+                let curr_tab = app_state.state.tab_state_ref().curr_tab_ref();
+                let dirlist = match panel {
+                    Panel::Parent => curr_tab.parent_list_ref(),
+                    Panel::Current => curr_tab.curr_list_ref(),
+                    Panel::Preview => curr_tab.child_list_ref(),
                 };
                 if let Some(dirlist) = dirlist {
                     let skip_dist = dirlist.first_index_for_viewport();
                     let new_index = skip_dist + (y - layout_rect[1].y - 1) as usize;
+                    let column_headers = app_state.config.display_options.column_headers;
                     match panel {
-                        Some(Panel::Parent) => {
+                        Panel::Parent => {
                             if let Err(e) =
                                 parent_cursor_move::parent_cursor_move(app_state, new_index)
                             {
@@ -394,43 +644,38 @@ pub fn process_mouse(
                                 let command = Command::ChangeDirectory {
                                     path: path::PathBuf::from(".."),
                                 };
-                                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                                    app_state
-                                        .state
-                                        .message_queue_mut()
-                                        .push_error(e.to_string());
-                                }
+                                execute_mouse_command(app_state, backend, keymap_t, command);
                             };
                         }
-                        Some(Panel::Current) => {
-                            cursor_move::cursor_move(app_state, new_index);
-                            if button == MouseButton::Right {
-                                let command = Command::OpenFile;
-                                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                                    app_state
-                                        .state
-                                        .message_queue_mut()
-                                        .push_error(e.to_string());
-                                }
-                            }
+                        // the first line of the current view shows the column headers
+                        Panel::Current if column_headers && new_index == skip_dist => {}
+                        Panel::Current => {
+                            let new_index = if column_headers {
+                                new_index - 1
+                            } else {
+                                new_index
+                            };
+                            click_current_entry(
+                                app_state, backend, keymap_t, button, ctrl, new_index,
+                            );
                         }
-                        Some(Panel::Preview) => {
+                        Panel::Preview => {
                             children_cursor_move(app_state, new_index);
                             if button == MouseButton::Left {
-                                let command = Command::OpenFile;
-                                if let Err(e) = command.execute(app_state, backend, keymap_t) {
-                                    app_state
-                                        .state
-                                        .message_queue_mut()
-                                        .push_error(e.to_string());
-                                }
+                                execute_mouse_command(
+                                    app_state,
+                                    backend,
+                                    keymap_t,
+                                    Command::OpenFile,
+                                );
                             }
                         }
-                        _ => {}
                     }
                 }
             }
         }
+        MouseEvent::Hold(_, y) => drag_current_entries(app_state, layout_rect[1], y),
+        MouseEvent::Release(_, _) => release_current_entries(app_state),
         _ => {}
     }
     app_state.flush_event();
@@ -4,6 +4,7 @@ use crate::commands::quit::QuitAction;
 use crate::history::generate_entries_to_root;
 use crate::history::DirectoryHistory;
 use crate::history::JoshutoHistory;
+use crate::ipc;
 use crate::preview::preview_default;
 use crate::tab::JoshutoTab;
 use crate::traits::app_execute::AppExecute;
@@ -26,7 +27,7 @@ use termion::event::Event;
 pub fn run_loop(
     backend: &mut ui::AppBackend,
     app_state: &mut AppState,
-    keymap_t: AppKeyMapping,
+    mut keymap_t: AppKeyMapping,
 ) -> std::io::Result<()> {
     let curr_path = std::env::current_dir()?;
 
@@ -67,6 +68,9 @@ pub fn run_loop(
     }
 
     while app_state.quit == QuitAction::DoNot {
+        // read the git status of newly entered directories in the background
+        app_state.state.update_git_status();
+
         // do the ui
         if let Ok(size) = backend.terminal_ref().size() {
             let area = Rect {
@@ -109,6 +113,11 @@ pub fn run_loop(
 
         // process user input
         process_input(app_state, backend, &keymap_t, event);
+
+        // swap in the keymap if the configs have been reloaded
+        if let Some(keymap) = app_state.pending_keymap.take() {
+            keymap_t = keymap;
+        }
     } // end of main loop
     Ok(())
 }
@@ -141,7 +150,7 @@ fn process_input(
                 Event::Unsupported(s) => {
                     process_event::process_unsupported(app_state, backend, keymap_t, s);
                 }
-                key => match keymap_t.default_view.get(&key) {
+                key => match keymap_t.get(app_state, &key) {
                     None => {
                         app_state
                             .state
@@ -180,6 +189,18 @@ fn process_input(
             preview_default::load_previews(app_state, backend);
             app_state.flush_event();
         }
+        AppEvent::IpcCommand { command, reply } => {
+            let response = match command.execute(app_state, backend, keymap_t) {
+                Ok(()) => ipc::ok_response(),
+                Err(e) => {
+                    let msg = e.to_string();
+                    app_state.state.message_queue_mut().push_error(msg.clone());
+                    ipc::error_response(&msg)
+                }
+            };
+            let _ = reply.send(response);
+            preview_default::load_previews(app_state, backend);
+        }
         event => process_event::process_noninteractive(event, app_state),
     }
 }
@@ -192,10 +213,17 @@ fn calculate_ui_state(app_state: &mut AppState, area: Rect) {
     };
     let display_options = &app_state.config.display_options;
     let constraints = views::get_constraints(app_state);
-    let layout = if display_options.show_borders {
+    let mut layout = if display_options.show_borders {
         views::calculate_layout_with_borders(area, constraints)
     } else {
         views::calculate_layout(area, constraints)
     };
+    // the column headers take up the first line of the current view,
+    // the parent and preview columns keep their full height
+    if display_options.column_headers {
+        if let Some(rect) = layout.get_mut(1) {
+            rect.height = rect.height.saturating_sub(1);
+        }
+    }
     app_state.state.ui_state_mut().layout = layout;
 }
@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Scope};
+
+// the number of operations after which a script is stopped, so that an endless loop
+// does not keep running in the background forever
+const MAX_OPERATIONS: u64 = 100_000_000;
+
+/// Requests sent by a running script to the main thread,
+/// which owns the app state and the terminal
+#[derive(Debug)]
+pub enum ScriptRequest {
+    Cwd(mpsc::Sender<String>),
+    TabIndex(mpsc::Sender<usize>),
+    Entries(mpsc::Sender<Vec<String>>),
+    Selection(mpsc::Sender<Vec<String>>),
+    Current(mpsc::Sender<Option<String>>),
+    Message(ScriptMessage, String),
+    Prompt(String, mpsc::Sender<Option<String>>),
+    Confirm(String, mpsc::Sender<bool>),
+    Execute(String, mpsc::Sender<Result<(), String>>),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ScriptMessage {
+    Info,
+    Success,
+    Error,
+}
+
+/// Runs a script in its own thread.
+/// The thread ends, and `request_tx` is dropped, once the script finishes
+/// or once `cancel` is set.
+pub fn spawn(
+    path: PathBuf,
+    args: Vec<String>,
+    request_tx: mpsc::Sender<ScriptRequest>,
+    cancel: Arc<AtomicBool>,
+) -> thread::JoinHandle<Result<(), String>> {
+    thread::spawn(move || {
+        let mut engine = create_engine(request_tx);
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.on_progress(move |_| {
+            if cancel.load(Ordering::Relaxed) {
+                Some(Dynamic::from("cancelled"))
+            } else {
+                None
+            }
+        });
+        let ast = engine
+            .compile_file(path)
+            .map_err(|e| format!("Failed to load script: {}", e))?;
+
+        let args: Array = args.into_iter().map(Dynamic::from).collect();
+        let mut scope = Scope::new();
+        scope.push_constant("ARGS", args);
+        engine
+            .run_ast_with_scope(&mut scope, &ast)
+            .map_err(|e| e.to_string())
+    })
+}
+
+fn create_engine(request_tx: mpsc::Sender<ScriptRequest>) -> Engine {
+    let mut engine = Engine::new();
+
+    let tx = request_tx.clone();
+    engine.on_print(move |s| {
+        let _ = tx.send(ScriptRequest::Message(ScriptMessage::Info, s.to_string()));
+    });
+
+    let tx = request_tx.clone();
+    engine.register_fn("cwd", move || -> String {
+        query(&tx, ScriptRequest::Cwd).unwrap_or_default()
+    });
+    let tx = request_tx.clone();
+    engine.register_fn("tab_index", move || -> i64 {
+        query(&tx, ScriptRequest::TabIndex).map_or(0, |i| i as i64 + 1)
+    });
+    let tx = request_tx.clone();
+    engine.register_fn("entries", move || -> Array {
+        to_array(query(&tx, ScriptRequest::Entries).unwrap_or_default())
+    });
+    let tx = request_tx.clone();
+    engine.register_fn("selected", move || -> Array {
+        to_array(query(&tx, ScriptRequest::Selection).unwrap_or_default())
+    });
+    let tx = request_tx.clone();
+    engine.register_fn("current", move || -> Dynamic {
+        query(&tx, ScriptRequest::Current)
+            .flatten()
+            .map_or(Dynamic::UNIT, Dynamic::from)
+    });
+
+    for (name, kind) in [
+        ("info", ScriptMessage::Info),
+        ("success", ScriptMessage::Success),
+        ("error", ScriptMessage::Error),
+    ] {
+        let tx = request_tx.clone();
+        engine.register_fn(name, move |msg: &str| {
+            let _ = tx.send(ScriptRequest::Message(kind, msg.to_string()));
+        });
+    }
+
+    let tx = request_tx.clone();
+    engine.register_fn("prompt", move |text: &str| -> Dynamic {
+        query(&tx, |reply| ScriptRequest::Prompt(text.to_string(), reply))
+            .flatten()
+            .map_or(Dynamic::UNIT, Dynamic::from)
+    });
+    let tx = request_tx.clone();
+    engine.register_fn("confirm", move |text: &str| -> bool {
+        query(&tx, |reply| ScriptRequest::Confirm(text.to_string(), reply)).unwrap_or(false)
+    });
+    let tx = request_tx;
+    engine.register_fn(
+        "exec",
+        move |command: &str| -> Result<(), Box<EvalAltResult>> {
+            match query(&tx, |reply| {
+                ScriptRequest::Execute(command.to_string(), reply)
+            }) {
+                Some(Ok(())) => Ok(()),
+                Some(Err(e)) => Err(e.into()),
+                None => Err("joshuto stopped responding".into()),
+            }
+        },
+    );
+
+    engine
+}
+
+fn query<T, F>(request_tx: &mpsc::Sender<ScriptRequest>, request: F) -> Option<T>
+where
+    F: FnOnce(mpsc::Sender<T>) -> ScriptRequest,
+{
+    let (reply_tx, reply_rx) = mpsc::channel();
+    request_tx.send(request(reply_tx)).ok()?;
+    reply_rx.recv().ok()
+}
+
+fn to_array(v: Vec<String>) -> Array {
+    v.into_iter().map(Dynamic::from).collect()
+}
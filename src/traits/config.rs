@@ -2,7 +2,7 @@ use serde::de::DeserializeOwned;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::error::AppResult;
+use crate::error::{AppError, AppErrorKind, AppResult};
 use crate::types::config_type::ConfigType;
 use crate::CONFIG_HIERARCHY;
 
@@ -14,6 +14,10 @@ pub trait TomlConfigFile: Sized + Default {
     fn get_config() -> Self {
         parse_config_or_default::<Self::Raw, Self>(Self::get_type().as_filename())
     }
+
+    fn get_config_res() -> AppResult<Self> {
+        parse_config::<Self::Raw, Self>(Self::get_type().as_filename())
+    }
 }
 
 // searches a list of folders for a given file in order of preference
@@ -41,18 +45,32 @@ where
 }
 
 pub fn parse_config_or_default<T, S>(file_name: &str) -> S
+where
+    T: DeserializeOwned + Into<S>,
+    S: std::default::Default,
+{
+    match parse_config::<T, S>(file_name) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("{}", e);
+            S::default()
+        }
+    }
+}
+
+// like parse_config_or_default, but returns an error instead of falling back to the default
+pub fn parse_config<T, S>(file_name: &str) -> AppResult<S>
 where
     T: DeserializeOwned + Into<S>,
     S: std::default::Default,
 {
     match search_config_directories(file_name) {
-        Some(file_path) => match parse_file_to_config::<T, S>(&file_path) {
-            Ok(s) => s,
-            Err(e) => {
-                eprintln!("Failed to parse {}: {}", file_name, e);
-                S::default()
-            }
-        },
-        None => S::default(),
+        Some(file_path) => parse_file_to_config::<T, S>(&file_path).map_err(|e| {
+            AppError::new(
+                AppErrorKind::Config,
+                format!("Failed to parse {}: {}", file_name, e),
+            )
+        }),
+        None => Ok(S::default()),
     }
 }
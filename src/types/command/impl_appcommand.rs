@@ -12,6 +12,21 @@ impl AppCommand for Command {
             Self::Quit(_) => CMD_QUIT,
 
             Self::ToggleVisualMode => CMD_TOGGLE_VISUAL,
+            Self::PushMode { .. } => CMD_PUSH_MODE,
+            Self::PopMode => CMD_POP_MODE,
+            Self::MacroRecord { .. } => CMD_MACRO_RECORD,
+            Self::MacroStop => CMD_MACRO_STOP,
+            Self::MacroPlay { .. } => CMD_MACRO_PLAY,
+            Self::Sequence { steps } => steps.first().map_or("", |(_, c)| c.command()),
+            Self::Expand { template } => {
+                let name = template.split(' ').next().unwrap_or_default();
+                COMMANDS
+                    .iter()
+                    .find(|c| **c == name)
+                    .copied()
+                    .unwrap_or_default()
+            }
+            Self::Script { .. } => CMD_SCRIPT,
             Self::Escape => CMD_ESCAPE,
 
             Self::BulkRename => CMD_BULK_RENAME,
@@ -24,14 +39,15 @@ impl AppCommand for Command {
             Self::CloseTab => CMD_CLOSE_TAB,
             Self::CommandLine { .. } => CMD_COMMAND_LINE,
 
-            Self::CutFiles => CMD_CUT_FILES,
-            Self::CopyFiles => CMD_COPY_FILES,
+            Self::CutFiles { .. } => CMD_CUT_FILES,
+            Self::CopyFiles { .. } => CMD_COPY_FILES,
             Self::CopyFileName => CMD_COPY_FILENAME,
             Self::CopyFileNameWithoutExtension => CMD_COPY_FILENAME_WITHOUT_EXTENSION,
             Self::CopyFilePath { .. } => CMD_COPY_FILEPATH,
             Self::CopyDirPath => CMD_COPY_DIRECTORY_PATH,
             Self::SymlinkFiles { .. } => CMD_SYMLINK_FILES,
             Self::PasteFiles { .. } => CMD_PASTE_FILES,
+            Self::PasteFromClipboard => CMD_PASTE_FROM_CLIPBOARD,
 
             Self::DeleteFiles { .. } => CMD_DELETE_FILES,
 
@@ -41,9 +57,9 @@ impl AppCommand for Command {
             Self::CursorMoveEnd => CMD_CURSOR_MOVE_END,
             Self::CursorMovePageUp(_) => CMD_CURSOR_MOVE_PAGEUP,
             Self::CursorMovePageDown(_) => CMD_CURSOR_MOVE_PAGEDOWN,
-            Self::CursorMovePageHome => CMD_CURSOR_MOVE_PAGEUP,
-            Self::CursorMovePageMiddle => CMD_CURSOR_MOVE_PAGEDOWN,
-            Self::CursorMovePageEnd => CMD_CURSOR_MOVE_PAGEDOWN,
+            Self::CursorMovePageHome => CMD_CURSOR_MOVE_PAGEHOME,
+            Self::CursorMovePageMiddle => CMD_CURSOR_MOVE_PAGEMIDDLE,
+            Self::CursorMovePageEnd => CMD_CURSOR_MOVE_PAGEEND,
 
             Self::ParentCursorMoveUp { .. } => CMD_PARENT_CURSOR_MOVE_UP,
             Self::ParentCursorMoveDown { .. } => CMD_PARENT_CURSOR_MOVE_DOWN,
@@ -53,9 +69,12 @@ impl AppCommand for Command {
 
             Self::NewDirectory { .. } => CMD_NEW_DIRECTORY,
             Self::OpenFile => CMD_OPEN_FILE,
+            Self::ContextMenu => CMD_CONTEXT_MENU,
+            Self::Properties => CMD_PROPERTIES,
             Self::OpenFileWith { .. } => CMD_OPEN_FILE_WITH,
 
             Self::ReloadDirList => CMD_RELOAD_DIRECTORY_LIST,
+            Self::ReloadConfig => CMD_RELOAD_CONFIG,
             Self::RenameFile { .. } => CMD_RENAME_FILE,
             Self::RenameFileAppend => CMD_RENAME_FILE_APPEND,
             Self::RenameFileAppendBase => CMD_RENAME_FILE_APPEND_BASE,
@@ -66,10 +85,14 @@ impl AppCommand for Command {
             Self::SearchIncremental { .. } => CMD_SEARCH_INCREMENTAL,
             Self::SearchGlob { .. } => CMD_SEARCH_GLOB,
             Self::SearchRegex { .. } => CMD_SEARCH_REGEX,
+            Self::SearchExpr { .. } => CMD_SEARCH_EXPR,
             Self::SearchNext => CMD_SEARCH_NEXT,
             Self::SearchPrev => CMD_SEARCH_PREV,
+            Self::Grep { .. } => CMD_GREP,
+            Self::Find { .. } => CMD_FIND,
 
             Self::SelectGlob { .. } => CMD_SELECT_GLOB,
+            Self::SelectExpr { .. } => CMD_SELECT_EXPR,
             Self::SelectRegex { .. } => CMD_SELECT_REGEX,
             Self::SelectString { .. } => CMD_SELECT_STRING,
 
@@ -77,14 +100,21 @@ impl AppCommand for Command {
             Self::SetMode => CMD_SET_MODE,
 
             Self::ShowTasks => CMD_SHOW_TASKS,
+            Self::PaneSwitch => CMD_PANE_SWITCH,
+            Self::DiskUsage => CMD_DISK_USAGE,
 
             Self::Flat { .. } => CMD_FLAT,
+            Self::TreeToggle => CMD_TREE_TOGGLE,
+            Self::TreeExpandAll => CMD_TREE_EXPAND_ALL,
+            Self::TreeCollapse => CMD_TREE_COLLAPSE,
             Self::NumberedCommand { .. } => CMD_NUMBERED_COMMAND,
 
             Self::Sort { .. } => CMD_SORT,
             Self::SortReverse => CMD_SORT_REVERSE,
+            Self::ViewReset => CMD_VIEW_RESET,
 
             Self::FilterGlob { .. } => CMD_FILTER_GLOB,
+            Self::FilterExpr { .. } => CMD_FILTER_EXPR,
             Self::FilterRegex { .. } => CMD_FILTER_REGEX,
             Self::FilterString { .. } => CMD_FILTER_STRING,
 
@@ -110,6 +140,8 @@ impl AppCommand for Command {
             Self::TabSwitch { .. } => CMD_TAB_SWITCH,
             Self::TabSwitchIndex { .. } => CMD_TAB_SWITCH_INDEX,
             Self::ToggleHiddenFiles => CMD_TOGGLE_HIDDEN,
+            Self::ToggleIgnoredFiles => CMD_TOGGLE_IGNORED,
+            Self::ComputeSizes => CMD_COMPUTE_SIZES,
             Self::TouchFile { .. } => CMD_TOUCH_FILE,
 
             Self::SearchFzf => CMD_SEARCH_FZF,
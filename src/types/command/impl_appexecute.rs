@@ -16,10 +16,42 @@ impl AppExecute for Command {
         app_state: &mut AppState,
         backend: &mut AppBackend,
         keymap_t: &AppKeyMapping,
+    ) -> AppResult {
+        let res = self.execute_command(app_state, backend, keymap_t);
+        // failed commands are left out, so playing the macro does not repeat the error
+        if res.is_ok() {
+            app_state.state.macro_state_mut().record(self);
+        }
+        res
+    }
+}
+
+impl Command {
+    fn execute_command(
+        &self,
+        app_state: &mut AppState,
+        backend: &mut AppBackend,
+        keymap_t: &AppKeyMapping,
     ) -> AppResult {
         match self {
             Self::Escape => escape::escape(app_state),
             Self::ToggleVisualMode => uimodes::toggle_visual_mode(app_state),
+            Self::PushMode { name } => uimodes::push_mode(app_state, keymap_t, name),
+            Self::PopMode => uimodes::pop_mode(app_state),
+            Self::MacroRecord { register } => macros::macro_record(app_state, *register),
+            Self::MacroStop => macros::macro_stop(app_state),
+            Self::Sequence { steps } => {
+                chain::execute_sequence(app_state, backend, keymap_t, steps)
+            }
+            Self::Expand { template } => {
+                chain::execute_expanded(app_state, backend, keymap_t, template)
+            }
+            Self::MacroPlay { register, count } => {
+                macros::macro_play(app_state, backend, keymap_t, *register, *count)
+            }
+            Self::Script { name, args } => {
+                script::run_script(app_state, backend, keymap_t, name, args)
+            }
 
             Self::BulkRename => bulk_rename::bulk_rename(app_state, backend),
 
@@ -39,8 +71,8 @@ impl AppExecute for Command {
                 prefix.as_str(),
                 suffix.as_str(),
             ),
-            Self::CutFiles => file_ops::cut(app_state),
-            Self::CopyFiles => file_ops::copy(app_state),
+            Self::CutFiles { other_pane } => file_ops::cut(app_state, *other_pane),
+            Self::CopyFiles { other_pane } => file_ops::copy(app_state, *other_pane),
             Self::CopyFileName => file_ops::copy_filename(app_state),
             Self::CopyFileNameWithoutExtension => {
                 file_ops::copy_filename_without_extension(app_state)
@@ -58,7 +90,11 @@ impl AppExecute for Command {
                 };
                 file_ops::create_io_task(app_state, FileOperation::Symlink, options)
             }
-            Self::PasteFiles { options } => file_ops::create_io_paste_task(app_state, *options),
+            Self::PasteFiles {
+                options,
+                other_pane,
+            } => file_ops::create_io_paste_task(app_state, *options, *other_pane),
+            Self::PasteFromClipboard => file_ops::paste_from_clipboard(app_state),
 
             Self::DeleteFiles {
                 background,
@@ -99,6 +135,8 @@ impl AppExecute for Command {
 
             Self::NewDirectory { path } => new_directory::new_directory(app_state, path.as_path()),
             Self::OpenFile => open_file::open(app_state, backend),
+            Self::ContextMenu => context_menu::context_menu(app_state, backend, keymap_t),
+            Self::Properties => properties::properties_loop(app_state, backend, keymap_t),
             Self::OpenFileWith { index: None } => {
                 open_file::open_with_interactive(app_state, backend)
             }
@@ -109,6 +147,7 @@ impl AppExecute for Command {
             Self::Quit(action) => quit::quit_with_action(app_state, *action),
 
             Self::ReloadDirList => reload::reload_dirlist(app_state),
+            Self::ReloadConfig => reload_config::reload_config(app_state),
             Self::RenameFile { new_name } => {
                 rename_file::rename_file(app_state, new_name.as_path())
             }
@@ -127,6 +166,7 @@ impl AppExecute for Command {
             Self::SearchRegex { pattern } => {
                 search_regex::search_regex(app_state, pattern.as_str())
             }
+            Self::SearchExpr { expr } => search_expr::search_expr(app_state, expr.as_str()),
             Self::SearchString { pattern } => {
                 search_string::search_string(app_state, pattern.as_str(), false);
                 Ok(())
@@ -135,6 +175,23 @@ impl AppExecute for Command {
             // cursor will be one the selected word. And as `interactive_execute` for
             // `SearchIncremental` always starts from index 0, this operation will be a no-op
             Self::SearchIncremental { .. } => Ok(()),
+            Self::Find {
+                pattern,
+                glob,
+                regex,
+            } => find::find(app_state, pattern, *glob, *regex),
+            Self::Grep {
+                pattern,
+                glob,
+                regex,
+            } => grep::grep_loop(
+                app_state,
+                backend,
+                keymap_t,
+                pattern,
+                glob.as_deref(),
+                *regex,
+            ),
             Self::SearchNext => search::search_next(app_state),
             Self::SearchPrev => search::search_prev(app_state),
 
@@ -147,12 +204,17 @@ impl AppExecute for Command {
             Self::SelectString { pattern, options } => {
                 select_string::select_string(app_state, pattern, options)
             }
+            Self::SelectExpr { expr, options } => {
+                select_expr::select_expr(app_state, expr, options)
+            }
             Self::SetCaseSensitivity {
                 case_sensitivity,
                 set_type,
             } => case_sensitivity::set_case_sensitivity(app_state, *case_sensitivity, *set_type),
             Self::SetMode => set_mode::set_mode(app_state, backend),
             Self::ShowTasks => show_tasks::show_tasks(app_state, backend, keymap_t),
+            Self::PaneSwitch => tab_ops::pane_switch(app_state),
+            Self::DiskUsage => disk_usage::disk_usage_loop(app_state, backend, keymap_t),
             Self::Sort {
                 sort_method,
                 reverse,
@@ -160,6 +222,7 @@ impl AppExecute for Command {
             Self::SetDisplayMode(mode) => set_display_mode::set_display_mode(app_state, *mode),
             Self::SetLineMode(mode) => linemode::set_linemode(app_state, *mode),
             Self::SortReverse => sort::toggle_reverse(app_state),
+            Self::ViewReset => dir_view::view_reset(app_state),
             Self::SignalSuspend => signal::signal_suspend(backend),
             Self::SubProcess { words, mode } => {
                 sub_process::sub_process(app_state, backend, words.as_slice(), mode.clone())
@@ -168,6 +231,9 @@ impl AppExecute for Command {
             Self::SwitchLineNums(d) => line_nums::switch_line_numbering(app_state, *d),
 
             Self::Flat { depth } => flat::flatten(app_state, *depth),
+            Self::TreeToggle => tree::tree_toggle(app_state),
+            Self::TreeExpandAll => tree::tree_expand_all(app_state),
+            Self::TreeCollapse => tree::tree_collapse(app_state),
             Self::NumberedCommand { initial } => {
                 numbered_command::numbered_command(app_state, backend, keymap_t, *initial)
             }
@@ -179,8 +245,11 @@ impl AppExecute for Command {
             Self::FilterString { pattern } => {
                 filter_string::filter_string(app_state, pattern.as_str())
             }
+            Self::FilterExpr { expr } => filter_expr::filter_expr(app_state, expr.as_str()),
 
             Self::ToggleHiddenFiles => show_hidden::toggle_hidden(app_state),
+            Self::ToggleIgnoredFiles => show_ignored::toggle_ignored(app_state),
+            Self::ComputeSizes => compute_sizes::compute_sizes(app_state),
 
             Self::TabSwitch { offset } => {
                 tab_ops::tab_switch(app_state, *offset).map_err(|e| e.into())
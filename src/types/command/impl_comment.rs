@@ -15,6 +15,14 @@ impl CommandComment for Command {
             Self::BulkRename => "Bulk rename",
 
             Self::ToggleVisualMode => "Toggle visual mode",
+            Self::PushMode { .. } => "Enter a keymap mode",
+            Self::PopMode => "Leave the current keymap mode",
+            Self::MacroRecord { .. } => "Start recording a macro",
+            Self::MacroStop => "Stop recording the current macro",
+            Self::MacroPlay { .. } => "Play a recorded macro",
+            Self::Sequence { .. } => "Run a sequence of commands",
+            Self::Expand { .. } => "Run a command with substituted variables",
+            Self::Script { .. } => "Run a script",
 
             Self::ChangeDirectory { .. } => "Change directory",
             Self::ParentDirectory => "CD to parent directory",
@@ -32,8 +40,10 @@ impl CommandComment for Command {
                 _ => "Open a command line",
             },
 
-            Self::CutFiles => "Cut selected files",
-            Self::CopyFiles => "Copy selected files",
+            Self::CutFiles { other_pane: true } => "Move selected files to the other pane",
+            Self::CutFiles { .. } => "Cut selected files",
+            Self::CopyFiles { other_pane: true } => "Copy selected files to the other pane",
+            Self::CopyFiles { .. } => "Copy selected files",
             Self::CopyFileName => "Copy filename",
             Self::CopyFileNameWithoutExtension => "Copy filename without extension",
             Self::CopyFilePath { all_selected: true } => "Copy all selected paths to file",
@@ -41,6 +51,9 @@ impl CommandComment for Command {
             Self::CopyDirPath => "Copy directory name",
             Self::SymlinkFiles { .. } => "Symlink selected files",
 
+            Self::PasteFiles {
+                other_pane: true, ..
+            } => "Paste into the other pane",
             Self::PasteFiles {
                 options:
                     FileOperationOptions {
@@ -48,11 +61,13 @@ impl CommandComment for Command {
                         skip_exist,
                         ..
                     },
+                ..
             } => match (overwrite, skip_exist) {
                 (true, false) => "Paste, overwrite",
                 (false, true) => "Paste, skip existing files",
                 _ => "Paste",
             },
+            Self::PasteFromClipboard => "Copy files from the clipboard",
             Self::DeleteFiles { .. } => "Delete selected files",
 
             Self::CursorMoveUp { .. } => "Move cursor up",
@@ -74,10 +89,13 @@ impl CommandComment for Command {
 
             Self::NewDirectory { .. } => "Make a new directory",
             Self::OpenFile => "Open a file",
+            Self::ContextMenu => "Show the actions for the file under the cursor",
+            Self::Properties => "Show and edit the properties of the selected files",
             Self::OpenFileWith { .. } => "Open using selected program",
 
             Self::Quit(_) => "Quit the program",
             Self::ReloadDirList => "Reload current dir listing",
+            Self::ReloadConfig => "Reload all config files",
             Self::RenameFile { .. } => "Rename file",
             Self::TouchFile { .. } => "Touch file",
             Self::RenameFileAppend => "Rename a file",
@@ -89,12 +107,16 @@ impl CommandComment for Command {
             Self::SearchIncremental { .. } => "Search as you type",
             Self::SearchGlob { .. } => "Search with globbing",
             Self::SearchRegex { .. } => "Search with regex",
+            Self::SearchExpr { .. } => "Search by file metadata",
             Self::SearchNext => "Next search entry",
             Self::SearchPrev => "Previous search entry",
+            Self::Grep { .. } => "Search file contents below the current directory",
+            Self::Find { .. } => "List files below the current directory by name",
 
             Self::SelectGlob { .. } => "Select files with globbing",
             Self::SelectRegex { .. } => "Select files with regex",
             Self::SelectString { .. } => "Select files",
+            Self::SelectExpr { .. } => "Select files by metadata",
 
             Self::SetCaseSensitivity { .. } => "Set case sensitivity",
             Self::SetMode => "Set file permissions",
@@ -103,14 +125,21 @@ impl CommandComment for Command {
             Self::SubProcess { mode: SubprocessCallMode::Capture, .. } => "Run a shell command (blocking), do not hand over shall but capture stdout for post-processing",
             Self::StdOutPostProcess { .. } => "Post process stdout of last `shell` command",
             Self::ShowTasks => "Show running background tasks",
+            Self::PaneSwitch => "Switch to the other pane",
+            Self::DiskUsage => "Analyze disk usage of the current directory",
 
             Self::SignalSuspend => "Suspend the current session",
 
             Self::ToggleHiddenFiles => "Toggle hidden files displaying",
+            Self::ToggleIgnoredFiles => "Toggle gitignored files displaying",
+            Self::ComputeSizes => "Compute the sizes of directories",
 
             Self::SwitchLineNums(_) => "Switch line numbering",
 
             Self::Flat { .. } => "Flattern directory list",
+            Self::TreeToggle => "Expand or collapse directory in tree",
+            Self::TreeExpandAll => "Expand all directories in tree",
+            Self::TreeCollapse => "Collapse all directories in tree",
             Self::NumberedCommand { .. } => "Jump via input number",
 
             Self::Sort { sort_method, .. } => match sort_method {
@@ -119,12 +148,21 @@ impl CommandComment for Command {
                 SortMethod::Natural => "Sort naturally",
                 SortMethod::Size => "Sort by size",
                 SortMethod::Ext => "Sort by extension",
+                SortMethod::Atime => "Sort by access time",
+                SortMethod::Ctime => "Sort by status change time",
+                SortMethod::Btime => "Sort by creation time",
+                SortMethod::Type => "Sort by file type",
+                SortMethod::Version => "Sort by version",
+                SortMethod::Random => "Sort randomly",
+                SortMethod::Mimetype => "Sort by mimetype",
             },
             Self::SortReverse => "Reverse sort order",
+            Self::ViewReset => "Forget the view settings of the current directory",
 
             Self::FilterGlob { .. } => "Filter directory list with globbing",
             Self::FilterRegex { .. } => "Filter directory list with regex",
             Self::FilterString { .. } => "Filter directory list",
+            Self::FilterExpr { .. } => "Filter directory list by file metadata",
 
             Self::TabSwitch { .. } => "Switch to the next tab",
             Self::TabSwitchIndex { .. } => "Switch to a given tab",
@@ -14,11 +14,21 @@ impl CommandCompletion for Command {
                 "--noconfirm",
                 "--permanently",
             ]),
+            CMD_CUT_FILES | CMD_COPY_FILES => CompletionKind::Custom(vec!["--other-pane"]),
+            CMD_FIND => CompletionKind::Custom(vec!["--glob", "--regex"]),
+            CMD_GREP => CompletionKind::Custom(vec!["--glob=", "--regex"]),
             CMD_NEW_TAB => CompletionKind::Dir(Some(vec!["--current", "--cursor", "--last"])),
             CMD_OPEN_FILE_WITH
             | CMD_SUBPROCESS_CAPTURE
             | CMD_SUBPROCESS_INTERACTIVE
             | CMD_SUBPROCESS_SPAWN => CompletionKind::Bin,
+            CMD_PASTE_FILES => CompletionKind::Custom(vec![
+                "--other-pane",
+                "--overwrite=false",
+                "--overwrite=true",
+                "--skip_exist=false",
+                "--skip_exist=true",
+            ]),
             CMD_QUIT => CompletionKind::Custom(vec![
                 "--force",
                 "--output-current-directory",
@@ -26,16 +36,15 @@ impl CommandCompletion for Command {
                 "--output-selected-files",
             ]),
             CMD_SEARCH_INCREMENTAL | CMD_SEARCH_STRING => CompletionKind::File,
-            CMD_SELECT_FZF | CMD_SELECT_GLOB | CMD_SELECT_REGEX | CMD_SELECT_STRING => {
-                CompletionKind::Custom(vec![
-                    "--all=false",
-                    "--all=true",
-                    "--deselect=false",
-                    "--deselect=true",
-                    "--toggle=false",
-                    "--toggle=true",
-                ])
-            }
+            CMD_SELECT_EXPR | CMD_SELECT_FZF | CMD_SELECT_GLOB | CMD_SELECT_REGEX
+            | CMD_SELECT_STRING => CompletionKind::Custom(vec![
+                "--all=false",
+                "--all=true",
+                "--deselect=false",
+                "--deselect=true",
+                "--toggle=false",
+                "--toggle=true",
+            ]),
             CMD_SET_CASE_SENSITIVITY => CompletionKind::Custom(vec![
                 "--type=fzf",
                 "--type=glob",
@@ -45,19 +54,29 @@ impl CommandCompletion for Command {
                 "insensitive",
                 "sensitive",
             ]),
-            CMD_SET_DISPLAY_MODE => CompletionKind::Custom(vec!["default", "minimal", "hsplit"]),
+            CMD_SET_DISPLAY_MODE => {
+                CompletionKind::Custom(vec!["default", "minimal", "hsplit", "dualpane"])
+            }
             CMD_SET_LINEMODE => CompletionKind::Custom(vec![
-                "all", "group", "mtime", "none", "perm", "size", "user",
+                "all", "atime", "btime", "ctime", "git", "group", "inode", "mimetype", "mtime",
+                "nlink", "none", "octal", "perm", "size", "target", "type", "user",
             ]),
             CMD_SORT => CompletionKind::Custom(vec![
                 "--reverse=false",
                 "--reverse=true",
+                "atime",
+                "btime",
+                "ctime",
                 "ext",
                 "lexical",
+                "mimetype",
                 "mtime",
                 "natural",
+                "random",
                 "reverse",
                 "size",
+                "type",
+                "version",
             ]),
             CMD_SWITCH_LINE_NUMBERS => CompletionKind::Custom(vec!["absolute", "none", "relative"]),
             CMD_SYMLINK_FILES => {
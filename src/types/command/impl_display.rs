@@ -1,17 +1,49 @@
+use crate::commands::case_sensitivity::SetType;
+use crate::commands::quit::QuitAction;
+use crate::commands::stdout::PostProcessor;
+use crate::tab::NewTabMode;
 use crate::traits::app_execute::AppCommand;
+use crate::types::option::display::DisplayMode;
+use crate::types::option::line_mode::LineNumberStyle;
+use crate::types::option::search::CaseSensitivity;
 
 use super::Command;
 
+// The output of `Display` can be parsed back into the same command with `Command::from_str`,
+// which is relied upon when saving macros.
 impl std::fmt::Display for Command {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Self::ChangeDirectory { path } => write!(f, "{} {:?}", self.command(), path),
+            Self::ChangeDirectory { path } => {
+                write!(
+                    f,
+                    "{} {}",
+                    self.command(),
+                    shell_words::quote(&path.to_string_lossy())
+                )
+            }
+            Self::CommandLine { prefix, suffix } if suffix.is_empty() => {
+                write!(f, "{}{}", self.command(), prefix)
+            }
             Self::CommandLine { prefix, suffix } => {
                 write!(f, "{} {} || {}", self.command(), prefix, suffix)
             }
             Self::CursorMoveUp { offset } => write!(f, "{} {}", self.command(), offset),
             Self::CursorMoveDown { offset } => write!(f, "{} {}", self.command(), offset),
+            Self::CursorMovePageUp(p) => write!(f, "{} {}", self.command(), p),
+            Self::CursorMovePageDown(p) => write!(f, "{} {}", self.command(), p),
 
+            Self::SetDisplayMode(mode) => write!(
+                f,
+                "{} {}",
+                self.command(),
+                match mode {
+                    DisplayMode::Default => "default",
+                    DisplayMode::Minimal => "minimal",
+                    DisplayMode::HSplit => "hsplit",
+                    DisplayMode::DualPane => "dualpane",
+                }
+            ),
             Self::SetLineMode(mode) => write!(f, "{} {}", self.command(), mode.as_string()),
 
             Self::ParentCursorMoveUp { offset } => write!(f, "{} {}", self.command(), offset),
@@ -20,12 +52,49 @@ impl std::fmt::Display for Command {
             Self::PreviewCursorMoveUp { offset } => write!(f, "{} {}", self.command(), offset),
             Self::PreviewCursorMoveDown { offset } => write!(f, "{} {}", self.command(), offset),
 
-            Self::NewDirectory { path } => write!(f, "{} {:?}", self.command(), path),
+            Self::NewDirectory { path } => {
+                write!(
+                    f,
+                    "{} {}",
+                    self.command(),
+                    shell_words::quote(&path.to_string_lossy())
+                )
+            }
+            Self::OpenFileWith { index: Some(index) } => {
+                write!(f, "{} {}", self.command(), index)
+            }
+            Self::Quit(action) => write!(
+                f,
+                "{}{}",
+                self.command(),
+                match action {
+                    QuitAction::Force => " --force",
+                    QuitAction::OutputCurrentDirectory => " --output-current-directory",
+                    QuitAction::OutputSelectedFiles => " --output-selected-files",
+                    QuitAction::DoNot | QuitAction::Noop => "",
+                }
+            ),
 
+            Self::CopyFilePath { all_selected: true } => {
+                write!(f, "{} --all-selected=true", self.command())
+            }
             Self::SymlinkFiles { relative } => {
                 write!(f, "{} --relative={}", self.command(), relative)
             }
-            Self::PasteFiles { options } => write!(f, "{}  {}", self.command(), options),
+            Self::CutFiles { other_pane: true } | Self::CopyFiles { other_pane: true } => {
+                write!(f, "{} --other-pane", self.command())
+            }
+            Self::PasteFiles {
+                options,
+                other_pane,
+            } => write!(
+                f,
+                "{} --overwrite={} --skip_exist={}{}",
+                self.command(),
+                options.overwrite,
+                options.skip_exist,
+                if *other_pane { " --other-pane" } else { "" },
+            ),
             Self::DeleteFiles {
                 background,
                 permanently,
@@ -35,8 +104,8 @@ impl std::fmt::Display for Command {
                     f,
                     "{}{}{}{}",
                     self.command(),
-                    if !background {
-                        " --foreground=true"
+                    if *background {
+                        " --background=true"
                     } else {
                         ""
                     },
@@ -45,12 +114,117 @@ impl std::fmt::Display for Command {
                 )
             }
 
-            Self::RenameFile { new_name } => write!(f, "{} {:?}", self.command(), new_name),
+            Self::RenameFile { new_name } => {
+                write!(
+                    f,
+                    "{} {}",
+                    self.command(),
+                    shell_words::quote(&new_name.to_string_lossy())
+                )
+            }
+            Self::TouchFile { file_name } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(file_name))
+            }
+
+            Self::SearchGlob { pattern } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(pattern))
+            }
+            Self::SearchRegex { pattern } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(pattern))
+            }
+            Self::SearchExpr { expr } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(expr))
+            }
+            Self::SearchString { pattern } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(pattern))
+            }
+            Self::SearchIncremental { pattern } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(pattern))
+            }
+            Self::Grep {
+                pattern,
+                glob,
+                regex,
+            } => {
+                write!(f, "{}", self.command())?;
+                if let Some(glob) = glob {
+                    write!(f, " {}", shell_words::quote(&format!("--glob={}", glob)))?;
+                }
+                if *regex {
+                    write!(f, " --regex")?;
+                }
+                write!(f, " {}", shell_words::quote(pattern))
+            }
+            Self::Find {
+                pattern,
+                glob,
+                regex,
+            } => write!(
+                f,
+                "{}{}{} {}",
+                self.command(),
+                if *glob { " --glob" } else { "" },
+                if *regex { " --regex" } else { "" },
+                shell_words::quote(pattern)
+            ),
+
+            Self::SelectGlob { pattern, options }
+            | Self::SelectRegex { pattern, options }
+            | Self::SelectString { pattern, options }
+            | Self::SelectExpr {
+                expr: pattern,
+                options,
+            } => write!(
+                f,
+                "{} {} {}",
+                self.command(),
+                options,
+                shell_words::quote(pattern)
+            ),
+            Self::SelectFzf { options } => write!(f, "{} {}", self.command(), options),
 
-            Self::SearchGlob { pattern } => write!(f, "{} {}", self.command(), pattern),
-            Self::SearchRegex { pattern } => write!(f, "{} {}", self.command(), pattern),
-            Self::SearchString { pattern } => write!(f, "{} {}", self.command(), pattern),
-            Self::SubProcess { words, .. } => write!(f, "{} {:?}", self.command(), words),
+            Self::SetCaseSensitivity {
+                case_sensitivity,
+                set_type,
+            } => write!(
+                f,
+                "{} --type={} {}",
+                self.command(),
+                match set_type {
+                    SetType::String => "string",
+                    SetType::Glob => "glob",
+                    SetType::Regex => "regex",
+                    SetType::Fzf => "fzf",
+                },
+                match case_sensitivity {
+                    CaseSensitivity::Sensitive => "sensitive",
+                    CaseSensitivity::Insensitive => "insensitive",
+                    CaseSensitivity::Smart => "smart",
+                }
+            ),
+            Self::SubProcess { words, .. } => {
+                write!(f, "{} {}", self.command(), shell_words::join(words))
+            }
+            Self::StdOutPostProcess { processor } => write!(
+                f,
+                "{} {}",
+                self.command(),
+                match processor {
+                    PostProcessor::ChangeDirectory => "cd",
+                }
+            ),
+            Self::SwitchLineNums(style) => write!(
+                f,
+                "{} {}",
+                self.command(),
+                match style {
+                    LineNumberStyle::None => "none",
+                    LineNumberStyle::Relative => "relative",
+                    LineNumberStyle::Absolute => "absolute",
+                }
+            ),
+            Self::Flat { depth } => write!(f, "{} {}", self.command(), depth),
+            Self::NumberedCommand { initial } => write!(f, "{} {}", self.command(), initial),
             Self::Sort {
                 sort_method,
                 reverse,
@@ -65,8 +239,61 @@ impl std::fmt::Display for Command {
                     None => "",
                 },
             ),
+            Self::FilterGlob { pattern } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(pattern))
+            }
+            Self::FilterRegex { pattern } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(pattern))
+            }
+            Self::FilterString { pattern } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(pattern))
+            }
+            Self::FilterExpr { expr } => {
+                write!(f, "{} {}", self.command(), shell_words::quote(expr))
+            }
+
+            Self::PushMode { name } => write!(f, "{} {}", self.command(), name),
+            Self::Sequence { steps } => {
+                for (i, (operator, command)) in steps.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " {} ", operator.as_str())?;
+                    }
+                    write!(f, "{}", command)?;
+                }
+                Ok(())
+            }
+            Self::Expand { template } => write!(f, "{}", template),
+            Self::Script { name, args } if args.is_empty() => {
+                write!(f, "{} {}", self.command(), name)
+            }
+            Self::Script { name, args } => {
+                write!(f, "{} {} {}", self.command(), name, shell_words::join(args))
+            }
+            Self::MacroRecord { register } => write!(f, "{} {}", self.command(), register),
+            Self::MacroPlay { register, count } => {
+                write!(f, "{} {} {}", self.command(), register, count)
+            }
+            Self::NewTab { mode, last } => {
+                write!(f, "{}", self.command())?;
+                if *last {
+                    write!(f, " --last")?;
+                }
+                match mode {
+                    NewTabMode::Default => Ok(()),
+                    NewTabMode::CurrentTabDir => write!(f, " --current"),
+                    NewTabMode::CursorDir => write!(f, " --cursor"),
+                    NewTabMode::Directory(dir) => write!(f, " {}", dir),
+                }
+            }
             Self::TabSwitch { offset } => write!(f, "{} {}", self.command(), offset),
             Self::TabSwitchIndex { index } => write!(f, "{} {}", self.command(), index),
+
+            Self::Zoxide(args) | Self::ZoxideInteractive(args) => {
+                write!(f, "{} {}", self.command(), args)
+            }
+            Self::CustomSearch(words) | Self::CustomSearchInteractive(words) => {
+                write!(f, "{} {}", self.command(), words.join(" "))
+            }
             _ => write!(f, "{}", self.command()),
         }
     }
@@ -1,10 +1,13 @@
 use std::path;
 
 use crate::commands::case_sensitivity::SetType;
+use crate::commands::chain::{self, ChainOperator};
 use crate::commands::quit::QuitAction;
+use crate::commands::script;
 use crate::commands::select::SelectOption;
 use crate::commands::stdout::PostProcessor;
 use crate::commands::sub_process::SubprocessCallMode;
+use crate::config::macros;
 use crate::error::{AppError, AppErrorKind};
 use crate::tab::NewTabMode;
 use crate::types::io::FileOperationOptions;
@@ -19,6 +22,50 @@ use crate::HOME_DIR;
 use super::Command;
 use crate::constants::command_name::*;
 
+// Splits a command string at `;` and `&&`, unless they are quoted or escaped
+fn split_chain(s: &str) -> Result<Vec<(ChainOperator, &str)>, AppError> {
+    let mut steps = Vec::new();
+    let mut operator = ChainOperator::Always;
+    let mut start = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (c, quote) {
+            // like in a shell, backslashes are taken literally in single quotes
+            ('\\', q) if q != Some('\'') => escaped = true,
+            ('\'' | '"', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            (';', None) => {
+                steps.push((operator, &s[start..i]));
+                operator = ChainOperator::Always;
+                start = i + 1;
+            }
+            ('&', None) if matches!(chars.peek(), Some((_, '&'))) => {
+                chars.next();
+                steps.push((operator, &s[start..i]));
+                operator = ChainOperator::OnSuccess;
+                start = i + 2;
+            }
+            _ => {}
+        }
+    }
+    steps.push((operator, &s[start..]));
+
+    if steps.len() > 1 && steps.iter().any(|(_, step)| step.trim().is_empty()) {
+        return Err(AppError::new(
+            AppErrorKind::InvalidParameters,
+            format!("Empty command in '{}'", s),
+        ));
+    }
+    Ok(steps)
+}
+
 macro_rules! simple_command_conversion_case {
     ($command: ident, $command_match: ident, $enum_name: expr) => {
         if $command == $command_match {
@@ -38,6 +85,27 @@ impl std::str::FromStr for Command {
             });
         }
 
+        let steps = split_chain(s)?;
+        if steps.len() > 1 {
+            let steps = steps
+                .into_iter()
+                .map(|(operator, step)| Ok((operator, Self::from_str(step.trim())?)))
+                .collect::<Result<_, AppError>>()?;
+            return Ok(Self::Sequence { steps });
+        }
+
+        if chain::contains_variables(s) {
+            // make sure the command is valid, variables are only substituted when it is executed
+            let example = s
+                .replace("%cwd", "/")
+                .replace("%selected", "file")
+                .replace("%tab_index", "1");
+            Self::from_str(&example)?;
+            return Ok(Self::Expand {
+                template: s.to_string(),
+            });
+        }
+
         let (command, arg) = match s.find(' ') {
             Some(i) => (&s[..i], s[i..].trim_start()),
             None => (s, ""),
@@ -46,6 +114,8 @@ impl std::str::FromStr for Command {
         simple_command_conversion_case!(command, CMD_ESCAPE, Self::Escape);
 
         simple_command_conversion_case!(command, CMD_TOGGLE_VISUAL, Self::ToggleVisualMode);
+        simple_command_conversion_case!(command, CMD_POP_MODE, Self::PopMode);
+        simple_command_conversion_case!(command, CMD_MACRO_STOP, Self::MacroStop);
 
         simple_command_conversion_case!(command, CMD_CLOSE_TAB, Self::CloseTab);
 
@@ -72,8 +142,6 @@ impl std::str::FromStr for Command {
         );
         simple_command_conversion_case!(command, CMD_CURSOR_MOVE_PAGEEND, Self::CursorMovePageEnd);
 
-        simple_command_conversion_case!(command, CMD_CUT_FILES, Self::CutFiles);
-        simple_command_conversion_case!(command, CMD_COPY_FILES, Self::CopyFiles);
         simple_command_conversion_case!(command, CMD_COPY_FILENAME, Self::CopyFileName);
         simple_command_conversion_case!(
             command,
@@ -82,10 +150,18 @@ impl std::str::FromStr for Command {
         );
         // simple_command_conversion_case!(command, CMD_COPY_FILEPATH, Self::CopyFilePath);
         simple_command_conversion_case!(command, CMD_COPY_DIRECTORY_PATH, Self::CopyDirPath);
+        simple_command_conversion_case!(
+            command,
+            CMD_PASTE_FROM_CLIPBOARD,
+            Self::PasteFromClipboard
+        );
 
         simple_command_conversion_case!(command, CMD_OPEN_FILE, Self::OpenFile);
+        simple_command_conversion_case!(command, CMD_CONTEXT_MENU, Self::ContextMenu);
+        simple_command_conversion_case!(command, CMD_PROPERTIES, Self::Properties);
 
         simple_command_conversion_case!(command, CMD_RELOAD_DIRECTORY_LIST, Self::ReloadDirList);
+        simple_command_conversion_case!(command, CMD_RELOAD_CONFIG, Self::ReloadConfig);
         simple_command_conversion_case!(command, CMD_RENAME_FILE_APPEND, Self::RenameFileAppend);
         simple_command_conversion_case!(
             command,
@@ -97,8 +173,16 @@ impl std::str::FromStr for Command {
         simple_command_conversion_case!(command, CMD_SEARCH_NEXT, Self::SearchNext);
         simple_command_conversion_case!(command, CMD_SEARCH_PREV, Self::SearchPrev);
         simple_command_conversion_case!(command, CMD_SHOW_TASKS, Self::ShowTasks);
+        simple_command_conversion_case!(command, CMD_PANE_SWITCH, Self::PaneSwitch);
+        simple_command_conversion_case!(command, CMD_DISK_USAGE, Self::DiskUsage);
         simple_command_conversion_case!(command, CMD_SET_MODE, Self::SetMode);
         simple_command_conversion_case!(command, CMD_TOGGLE_HIDDEN, Self::ToggleHiddenFiles);
+        simple_command_conversion_case!(command, CMD_VIEW_RESET, Self::ViewReset);
+        simple_command_conversion_case!(command, CMD_TOGGLE_IGNORED, Self::ToggleIgnoredFiles);
+        simple_command_conversion_case!(command, CMD_COMPUTE_SIZES, Self::ComputeSizes);
+        simple_command_conversion_case!(command, CMD_TREE_TOGGLE, Self::TreeToggle);
+        simple_command_conversion_case!(command, CMD_TREE_EXPAND_ALL, Self::TreeExpandAll);
+        simple_command_conversion_case!(command, CMD_TREE_COLLAPSE, Self::TreeCollapse);
         simple_command_conversion_case!(command, CMD_BULK_RENAME, Self::BulkRename);
 
         simple_command_conversion_case!(command, CMD_SEARCH_FZF, Self::SearchFzf);
@@ -156,7 +240,7 @@ impl std::str::FromStr for Command {
                 ".." => Ok(Self::ParentDirectory),
                 "-" => Ok(Self::PreviousDirectory),
                 arg => {
-                    let new_path = unix::expand_shell_string(arg);
+                    let new_path = unix::expand_shell_string(&quoted_arg(arg));
                     Ok(Self::ChangeDirectory { path: new_path })
                 }
             }
@@ -221,7 +305,7 @@ impl std::str::FromStr for Command {
                     format!("{}: no directory name given", command),
                 ))
             } else {
-                let path = path::PathBuf::from(arg);
+                let path = path::PathBuf::from(quoted_arg(arg));
                 Ok(Self::NewDirectory { path })
             }
         } else if command == CMD_OPEN_FILE_WITH {
@@ -262,10 +346,30 @@ impl std::str::FromStr for Command {
                 }
             }
             Ok(Self::CopyFilePath { all_selected })
+        } else if command == CMD_CUT_FILES || command == CMD_COPY_FILES {
+            let mut other_pane = false;
+            for arg in arg.split_whitespace() {
+                match arg {
+                    "--other-pane" => other_pane = true,
+                    _ => {
+                        return Err(AppError::new(
+                            AppErrorKind::UnrecognizedArgument,
+                            format!("{}: unknown option '{}'", command, arg),
+                        ));
+                    }
+                }
+            }
+            if command == CMD_CUT_FILES {
+                Ok(Self::CutFiles { other_pane })
+            } else {
+                Ok(Self::CopyFiles { other_pane })
+            }
         } else if command == CMD_PASTE_FILES {
             let mut options = FileOperationOptions::default();
+            let mut other_pane = false;
             for arg in arg.split_whitespace() {
                 match arg {
+                    "--other-pane" => other_pane = true,
                     "--overwrite=true" => options.overwrite = true,
                     "--skip_exist=true" => options.skip_exist = true,
                     "--overwrite=false" => options.overwrite = false,
@@ -278,7 +382,10 @@ impl std::str::FromStr for Command {
                     }
                 }
             }
-            Ok(Self::PasteFiles { options })
+            Ok(Self::PasteFiles {
+                options,
+                other_pane,
+            })
         } else if command == CMD_DELETE_FILES {
             let [mut permanently, mut background, mut noconfirm] = [false; 3];
             for arg in arg.split_whitespace() {
@@ -307,7 +414,7 @@ impl std::str::FromStr for Command {
                     format!("{}: Expected 1, got 0", command),
                 )),
                 arg => {
-                    let path: path::PathBuf = path::PathBuf::from(arg);
+                    let path: path::PathBuf = path::PathBuf::from(quoted_arg(arg));
                     Ok(Self::RenameFile { new_name: path })
                 }
             }
@@ -318,12 +425,12 @@ impl std::str::FromStr for Command {
                     format!("{}: Expected 1, got 0", command),
                 )),
                 arg => Ok(Self::SearchString {
-                    pattern: arg.to_string(),
+                    pattern: quoted_arg(arg),
                 }),
             }
         } else if command == CMD_SEARCH_INCREMENTAL {
             Ok(Self::SearchIncremental {
-                pattern: arg.to_string(),
+                pattern: quoted_arg(arg),
             })
         } else if command == CMD_SEARCH_GLOB {
             match arg {
@@ -332,7 +439,7 @@ impl std::str::FromStr for Command {
                     format!("{}: Expected 1, got 0", command),
                 )),
                 arg => Ok(Self::SearchGlob {
-                    pattern: arg.to_string(),
+                    pattern: quoted_arg(arg),
                 }),
             }
         } else if command == CMD_SEARCH_REGEX {
@@ -342,9 +449,83 @@ impl std::str::FromStr for Command {
                     format!("{}: Expected 1, got 0", command),
                 )),
                 arg => Ok(Self::SearchRegex {
-                    pattern: arg.to_string(),
+                    pattern: quoted_arg(arg),
                 }),
             }
+        } else if command == CMD_SEARCH_EXPR {
+            match quoted_arg(arg) {
+                expr if expr.is_empty() => Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: Expected 1, got 0", command),
+                )),
+                expr => Ok(Self::SearchExpr { expr }),
+            }
+        } else if command == CMD_GREP {
+            let mut glob = None;
+            let mut regex = false;
+            let mut pattern = None;
+            match shell_words::split(arg) {
+                Ok(args) => {
+                    for arg in args {
+                        match arg.as_str() {
+                            "--regex" => regex = true,
+                            s if s.starts_with("--glob=") => {
+                                glob = Some(s["--glob=".len()..].to_string())
+                            }
+                            _ => pattern = Some(arg),
+                        }
+                    }
+                    match pattern {
+                        Some(pattern) if !pattern.is_empty() => Ok(Self::Grep {
+                            pattern,
+                            glob,
+                            regex,
+                        }),
+                        _ => Err(AppError::new(
+                            AppErrorKind::InvalidParameters,
+                            format!("{}: Expected 1, got 0", command),
+                        )),
+                    }
+                }
+                Err(e) => Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: {}", arg, e),
+                )),
+            }
+        } else if command == CMD_FIND {
+            let mut glob = false;
+            let mut regex = false;
+            let mut pattern = None;
+            match shell_words::split(arg) {
+                Ok(args) => {
+                    for arg in args {
+                        match arg.as_str() {
+                            "--glob" => glob = true,
+                            "--regex" => regex = true,
+                            _ => pattern = Some(arg),
+                        }
+                    }
+                    match pattern {
+                        _ if glob && regex => Err(AppError::new(
+                            AppErrorKind::InvalidParameters,
+                            format!("{}: --glob and --regex cannot be combined", command),
+                        )),
+                        Some(pattern) if !pattern.is_empty() => Ok(Self::Find {
+                            pattern,
+                            glob,
+                            regex,
+                        }),
+                        _ => Err(AppError::new(
+                            AppErrorKind::InvalidParameters,
+                            format!("{}: Expected 1, got 0", command),
+                        )),
+                    }
+                }
+                Err(e) => Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: {}", arg, e),
+                )),
+            }
         } else if command == CMD_SELECT_GLOB {
             let mut options = SelectOption::default();
             let mut pattern = "";
@@ -435,6 +616,38 @@ impl std::str::FromStr for Command {
                     format!("{}: {}", arg, e),
                 )),
             }
+        } else if command == CMD_SELECT_EXPR {
+            let mut options = SelectOption::default();
+            let mut words = Vec::new();
+            match shell_words::split(arg) {
+                Ok(args) => {
+                    for arg in args.iter() {
+                        match arg.as_str() {
+                            "--toggle=true" => options.toggle = true,
+                            "--all=true" => options.all = true,
+                            "--toggle=false" => options.toggle = false,
+                            "--all=false" => options.all = false,
+                            "--deselect=true" => options.reverse = true,
+                            "--deselect=false" => options.reverse = false,
+                            s => words.push(s),
+                        }
+                    }
+                    if words.is_empty() {
+                        return Err(AppError::new(
+                            AppErrorKind::InvalidParameters,
+                            format!("{}: Expected 1, got 0", command),
+                        ));
+                    }
+                    Ok(Self::SelectExpr {
+                        expr: words.join(" "),
+                        options,
+                    })
+                }
+                Err(e) => Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: {}", arg, e),
+                )),
+            }
         } else if command == CMD_SELECT_FZF {
             let mut options = SelectOption::default();
             match shell_words::split(arg) {
@@ -557,6 +770,7 @@ impl std::str::FromStr for Command {
                 "default" => Ok(Self::SetDisplayMode(DisplayMode::Default)),
                 "minimal" => Ok(Self::SetDisplayMode(DisplayMode::Minimal)),
                 "hsplit" => Ok(Self::SetDisplayMode(DisplayMode::HSplit)),
+                "dualpane" => Ok(Self::SetDisplayMode(DisplayMode::DualPane)),
                 _ => Err(AppError::new(
                     AppErrorKind::InvalidParameters,
                     format!("{}: Unknown option '{}'", command, arg),
@@ -582,7 +796,7 @@ impl std::str::FromStr for Command {
             }
         } else if command == CMD_TOUCH_FILE {
             Ok(Self::TouchFile {
-                file_name: arg.to_string(),
+                file_name: quoted_arg(arg),
             })
         } else if command == CMD_SWITCH_LINE_NUMBERS {
             let policy = match arg {
@@ -599,6 +813,52 @@ impl std::str::FromStr for Command {
                     format!("{}: {}", command, e),
                 )),
             }
+        } else if command == CMD_PUSH_MODE {
+            match arg {
+                "" => Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: Expected 1, got 0", command),
+                )),
+                arg => Ok(Self::PushMode {
+                    name: arg.to_string(),
+                }),
+            }
+        } else if command == CMD_MACRO_RECORD {
+            match macros::parse_register(arg) {
+                Some(register) => Ok(Self::MacroRecord { register }),
+                None => Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: Expected a single character register", command),
+                )),
+            }
+        } else if command == CMD_MACRO_PLAY {
+            let (register, count) = match arg.split_once(' ') {
+                Some((register, count)) => (register, count.trim()),
+                None => (arg, "1"),
+            };
+            let register = macros::parse_register(register).ok_or_else(|| {
+                AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: Expected a single character register", command),
+                )
+            })?;
+            match count.parse::<usize>() {
+                Ok(count) => Ok(Self::MacroPlay { register, count }),
+                Err(e) => Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: {}", command, e),
+                )),
+            }
+        } else if command == CMD_SCRIPT {
+            let mut args = split_script_args(command, arg)?;
+            if args.is_empty() {
+                return Err(AppError::new(
+                    AppErrorKind::InvalidParameters,
+                    format!("{}: Expected a script name", command),
+                ));
+            }
+            let name = args.remove(0);
+            Ok(Self::Script { name, args })
         } else if command == CMD_NUMBERED_COMMAND {
             let c = arg.chars().next();
             match c {
@@ -610,15 +870,19 @@ impl std::str::FromStr for Command {
             }
         } else if command == CMD_FILTER_GLOB {
             Ok(Self::FilterGlob {
-                pattern: arg.to_string(),
+                pattern: quoted_arg(arg),
             })
         } else if command == CMD_FILTER_REGEX {
             Ok(Self::FilterRegex {
-                pattern: arg.to_string(),
+                pattern: quoted_arg(arg),
             })
         } else if command == CMD_FILTER_STRING {
             Ok(Self::FilterString {
-                pattern: arg.to_string(),
+                pattern: quoted_arg(arg),
+            })
+        } else if command == CMD_FILTER_EXPR {
+            Ok(Self::FilterExpr {
+                expr: quoted_arg(arg),
             })
         } else if command == CMD_ZOXIDE {
             match arg {
@@ -642,6 +906,12 @@ impl std::str::FromStr for Command {
                     Ok(Self::Zoxide(args))
                 }
             }
+        } else if script::find_script(command).is_some() {
+            // scripts can be called by their name, like built-in commands
+            Ok(Self::Script {
+                name: command.to_string(),
+                args: split_script_args(command, arg)?,
+            })
         } else {
             Err(AppError::new(
                 AppErrorKind::UnrecognizedCommand,
@@ -650,3 +920,175 @@ impl std::str::FromStr for Command {
         }
     }
 }
+
+// an argument quoted as a whole, like the patterns written by `Display`, is unquoted,
+// anything else is taken as it is, so backslashes in patterns are kept
+fn quoted_arg(arg: &str) -> String {
+    let arg = arg.trim();
+    let is_quoted = ['\'', '"']
+        .iter()
+        .any(|q| arg.len() >= 2 && arg.starts_with(*q) && arg.ends_with(*q));
+    match shell_words::split(arg) {
+        Ok(mut words) if is_quoted && words.len() == 1 => words.remove(0),
+        _ => arg.to_string(),
+    }
+}
+
+fn split_script_args(command: &str, arg: &str) -> Result<Vec<String>, AppError> {
+    shell_words::split(arg).map_err(|e| {
+        AppError::new(
+            AppErrorKind::InvalidParameters,
+            format!("{}: {}", command, e),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn chains_are_split_outside_of_quotes() {
+        let steps = split_chain("cut_files; cd '/a;b' && shell echo \\&& \"x && y\"").unwrap();
+        assert_eq!(
+            steps,
+            vec![
+                (ChainOperator::Always, "cut_files"),
+                (ChainOperator::Always, " cd '/a;b' "),
+                (ChainOperator::OnSuccess, " shell echo \\&& \"x && y\""),
+            ]
+        );
+        assert!(split_chain("cut_files;; paste_files").is_err());
+        assert_eq!(
+            split_chain(r"filter_regex 'a\' ; cd -").unwrap(),
+            vec![
+                (ChainOperator::Always, r"filter_regex 'a\' "),
+                (ChainOperator::Always, " cd -"),
+            ]
+        );
+    }
+
+    #[test]
+    fn patterns_round_trip_through_chains() {
+        let patterns = ["a;b", "x && y", "two words", "it's", r"a\", ""];
+        for pattern in patterns {
+            let commands = [
+                Command::SearchGlob {
+                    pattern: pattern.to_string(),
+                },
+                Command::SearchRegex {
+                    pattern: pattern.to_string(),
+                },
+                Command::SearchIncremental {
+                    pattern: pattern.to_string(),
+                },
+                Command::FilterGlob {
+                    pattern: pattern.to_string(),
+                },
+                Command::FilterRegex {
+                    pattern: pattern.to_string(),
+                },
+                Command::FilterString {
+                    pattern: pattern.to_string(),
+                },
+                Command::SelectString {
+                    pattern: pattern.to_string(),
+                    options: SelectOption::default(),
+                },
+            ];
+            for command in commands {
+                let chain = format!("{} && cd -", command);
+                match Command::from_str(&chain) {
+                    Ok(Command::Sequence { steps }) => {
+                        assert_eq!(steps.len(), 2, "{}", chain);
+                        assert_eq!(steps[0].1.to_string(), command.to_string(), "{}", chain);
+                    }
+                    other => panic!("unexpected {:?} for {}", other, chain),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unquoted_patterns_are_kept_as_they_are() {
+        assert!(matches!(
+            Command::from_str(r"search_regex \.rs$"),
+            Ok(Command::SearchRegex { pattern }) if pattern == r"\.rs$"
+        ));
+        assert!(matches!(
+            Command::from_str(r"filter_regex \d+"),
+            Ok(Command::FilterRegex { pattern }) if pattern == r"\d+"
+        ));
+        assert!(matches!(
+            Command::from_str("search 'a b'"),
+            Ok(Command::SearchString { pattern }) if pattern == "a b"
+        ));
+    }
+
+    #[test]
+    fn quoted_names_are_not_split() {
+        let command = Command::from_str("rename 'x;quit'").unwrap();
+        assert!(
+            matches!(&command, Command::RenameFile { new_name } if new_name.to_str() == Some("x;quit"))
+        );
+        assert!(matches!(
+            Command::from_str(&command.to_string()),
+            Ok(Command::RenameFile { new_name }) if new_name.to_str() == Some("x;quit")
+        ));
+        assert!(matches!(
+            Command::from_str("rename my file.txt"),
+            Ok(Command::RenameFile { new_name }) if new_name.to_str() == Some("my file.txt")
+        ));
+    }
+
+    #[test]
+    fn sequences_and_variables_are_parsed() {
+        match Command::from_str("cut_files && cd %cwd; paste_files") {
+            Ok(Command::Sequence { steps }) => {
+                assert_eq!(steps.len(), 3);
+                assert_eq!(steps[1].0, ChainOperator::OnSuccess);
+                assert!(
+                    matches!(&steps[1].1, Command::Expand { template } if template == "cd %cwd")
+                );
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(Command::from_str("not_a_command %cwd").is_err());
+    }
+
+    #[test]
+    fn script_arguments_round_trip() {
+        let command = Command::from_str("script backup 'my file' -v").unwrap();
+        match &command {
+            Command::Script { name, args } => {
+                assert_eq!(name, "backup");
+                assert_eq!(args, &["my file", "-v"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        let displayed = command.to_string();
+        assert!(matches!(
+            Command::from_str(&displayed),
+            Ok(Command::Script { args, .. }) if args == ["my file", "-v"]
+        ));
+        assert!(Command::from_str("script").is_err());
+    }
+
+    #[test]
+    fn filter_expressions_round_trip() {
+        let command = Command::from_str("filter_expr 'size > 1M && name == \"a b\"'").unwrap();
+        assert!(
+            matches!(&command, Command::FilterExpr { expr } if expr == "size > 1M && name == \"a b\"")
+        );
+        assert!(matches!(
+            Command::from_str(&command.to_string()),
+            Ok(Command::FilterExpr { expr }) if expr == "size > 1M && name == \"a b\""
+        ));
+        assert!(matches!(
+            Command::from_str("select_expr --toggle=true type == dir"),
+            Ok(Command::SelectExpr { expr, options }) if expr == "type == dir" && options.toggle
+        ));
+    }
+}
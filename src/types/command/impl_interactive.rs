@@ -20,6 +20,9 @@ impl InteractiveExecute for Command {
             Self::FilterString { pattern } => {
                 filter_string::filter_string(app_state, pattern).ok();
             }
+            Self::FilterExpr { expr } => {
+                filter_expr::filter_expr(app_state, expr).ok();
+            }
             _ => (),
         }
     }
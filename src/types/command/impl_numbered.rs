@@ -11,7 +11,6 @@ use super::Command;
 // and then pressing key which some command is bound to. This is used mainly
 // for easier navigation. You don't have to implement this for every command
 impl NumberedExecute for Command {
-    #[allow(unused)] // backend and keymap_t args are not used, but they probably will be
     fn numbered_execute(
         &self,
         number_prefix: usize,
@@ -20,8 +19,31 @@ impl NumberedExecute for Command {
         keymap_t: &AppKeyMapping,
     ) -> AppResult {
         match self {
-            Self::CursorMoveUp { .. } => cursor_move::up(app_state, number_prefix),
-            Self::CursorMoveDown { .. } => cursor_move::down(app_state, number_prefix),
+            Self::CursorMoveUp { .. } => {
+                app_state
+                    .state
+                    .macro_state_mut()
+                    .record(&Self::CursorMoveUp {
+                        offset: number_prefix,
+                    });
+                cursor_move::up(app_state, number_prefix)
+            }
+            Self::CursorMoveDown { .. } => {
+                app_state
+                    .state
+                    .macro_state_mut()
+                    .record(&Self::CursorMoveDown {
+                        offset: number_prefix,
+                    });
+                cursor_move::down(app_state, number_prefix)
+            }
+            Self::MacroPlay { register, .. } => {
+                app_state.state.macro_state_mut().record(&Self::MacroPlay {
+                    register: *register,
+                    count: number_prefix,
+                });
+                macros::macro_play(app_state, backend, keymap_t, *register, number_prefix)
+            }
             _ => Err(AppError::new(
                 AppErrorKind::UnrecognizedCommand,
                 "Command cannot be prefixed by a number".to_string(),
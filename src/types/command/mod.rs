@@ -10,6 +10,7 @@ mod impl_numbered;
 use std::path;
 
 use crate::commands::case_sensitivity::SetType;
+use crate::commands::chain::ChainOperator;
 use crate::commands::quit::QuitAction;
 use crate::commands::select::SelectOption;
 use crate::commands::stdout::PostProcessor;
@@ -26,6 +27,28 @@ use crate::types::option::sort::SortMethod;
 pub enum Command {
     Escape,
     ToggleVisualMode,
+    PushMode {
+        name: String,
+    },
+    PopMode,
+    MacroRecord {
+        register: char,
+    },
+    MacroStop,
+    MacroPlay {
+        register: char,
+        count: usize,
+    },
+    Sequence {
+        steps: Vec<(ChainOperator, Command)>,
+    },
+    Expand {
+        template: String,
+    },
+    Script {
+        name: String,
+        args: Vec<String>,
+    },
     BulkRename,
 
     ChangeDirectory {
@@ -39,8 +62,12 @@ pub enum Command {
         suffix: String,
     },
 
-    CutFiles,
-    CopyFiles,
+    CutFiles {
+        other_pane: bool,
+    },
+    CopyFiles {
+        other_pane: bool,
+    },
     CopyFileName,
     CopyFileNameWithoutExtension,
     CopyFilePath {
@@ -52,7 +79,9 @@ pub enum Command {
     },
     PasteFiles {
         options: FileOperationOptions,
+        other_pane: bool,
     },
+    PasteFromClipboard,
 
     DeleteFiles {
         background: bool,
@@ -97,12 +126,15 @@ pub enum Command {
         path: path::PathBuf,
     },
     OpenFile,
+    ContextMenu,
+    Properties,
     OpenFileWith {
         index: Option<usize>,
     },
     Quit(QuitAction),
 
     ReloadDirList,
+    ReloadConfig,
     RenameFile {
         new_name: path::PathBuf,
     },
@@ -120,6 +152,9 @@ pub enum Command {
     SearchRegex {
         pattern: String,
     },
+    SearchExpr {
+        expr: String,
+    },
     SearchString {
         pattern: String,
     },
@@ -128,6 +163,16 @@ pub enum Command {
     },
     SearchNext,
     SearchPrev,
+    Grep {
+        pattern: String,
+        glob: Option<String>,
+        regex: bool,
+    },
+    Find {
+        pattern: String,
+        glob: bool,
+        regex: bool,
+    },
 
     SelectGlob {
         pattern: String,
@@ -141,6 +186,10 @@ pub enum Command {
         pattern: String,
         options: SelectOption,
     },
+    SelectExpr {
+        expr: String,
+        options: SelectOption,
+    },
 
     SetCaseSensitivity {
         case_sensitivity: CaseSensitivity,
@@ -152,15 +201,22 @@ pub enum Command {
         mode: SubprocessCallMode,
     },
     ShowTasks,
+    PaneSwitch,
+    DiskUsage,
 
     SignalSuspend,
 
     ToggleHiddenFiles,
+    ToggleIgnoredFiles,
+    ComputeSizes,
     SwitchLineNums(LineNumberStyle),
 
     Flat {
         depth: usize,
     },
+    TreeToggle,
+    TreeExpandAll,
+    TreeCollapse,
     NumberedCommand {
         initial: char,
     },
@@ -170,6 +226,7 @@ pub enum Command {
         reverse: Option<bool>,
     },
     SortReverse,
+    ViewReset,
 
     FilterGlob {
         pattern: String,
@@ -180,6 +237,9 @@ pub enum Command {
     FilterString {
         pattern: String,
     },
+    FilterExpr {
+        expr: String,
+    },
 
     NewTab {
         mode: NewTabMode,
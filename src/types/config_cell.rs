@@ -0,0 +1,33 @@
+use std::sync::{Arc, RwLock};
+
+/// Holds a config that can be swapped out while joshuto is running.
+///
+/// Readers get a snapshot of the current config, which stays valid
+/// even if the config is replaced in the meantime.
+#[derive(Debug, Default)]
+pub struct ConfigCell<T> {
+    inner: RwLock<Arc<T>>,
+}
+
+impl<T> ConfigCell<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: RwLock::new(Arc::new(value)),
+        }
+    }
+
+    pub fn get(&self) -> Arc<T> {
+        match self.inner.read() {
+            Ok(value) => Arc::clone(&value),
+            Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+        }
+    }
+
+    pub fn set(&self, value: T) {
+        let value = Arc::new(value);
+        match self.inner.write() {
+            Ok(mut inner) => *inner = value,
+            Err(poisoned) => *poisoned.into_inner() = value,
+        }
+    }
+}
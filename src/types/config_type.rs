@@ -7,6 +7,7 @@ pub enum ConfigType {
     Preview,
     Bookmarks,
     Icons,
+    Macros,
 }
 
 impl std::fmt::Display for ConfigType {
@@ -35,6 +36,7 @@ impl ConfigType {
             Self::Preview,
             Self::Bookmarks,
             Self::Icons,
+            Self::Macros,
         ]
     }
 
@@ -47,6 +49,7 @@ impl ConfigType {
             Self::Preview => "preview",
             Self::Bookmarks => "bookmarks",
             Self::Icons => "icons",
+            Self::Macros => "macros",
         }
     }
 
@@ -59,6 +62,7 @@ impl ConfigType {
             Self::Preview => "preview.toml",
             Self::Bookmarks => "bookmarks.toml",
             Self::Icons => "icons.toml",
+            Self::Macros => "macros.toml",
         }
     }
 
@@ -69,7 +73,7 @@ impl ConfigType {
             Self::Keymap => Some(KEYMAP_CONFIG),
             Self::Theme => Some(THEME_CONFIG),
             Self::Icons => Some(ICON_CONFIG),
-            Self::Mimetype | Self::Preview | Self::Bookmarks => None,
+            Self::Mimetype | Self::Preview | Self::Bookmarks | Self::Macros => None,
         }
     }
 }
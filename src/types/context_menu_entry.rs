@@ -0,0 +1,87 @@
+use serde::Deserialize;
+
+use crate::utils::mimetype::Mimetype;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ContextMenuEntry {
+    pub label: String,
+    pub command: String,
+    #[serde(default)]
+    pub ext: Vec<String>,
+    #[serde(default)]
+    pub mimetype: Vec<String>,
+}
+
+impl ContextMenuEntry {
+    /// An entry without any `ext` or `mimetype` filters applies to every file.
+    /// `mimetype` filters may either be a type (`image`) or a full mimetype (`image/png`).
+    pub fn matches(
+        &self,
+        ext: Option<&str>,
+        mimetype: Option<&Mimetype>,
+        case_insensitive: bool,
+    ) -> bool {
+        if self.ext.is_empty() && self.mimetype.is_empty() {
+            return true;
+        }
+        let ext_match = ext.map_or(false, |ext| {
+            self.ext.iter().any(|e| {
+                if case_insensitive {
+                    e.eq_ignore_ascii_case(ext)
+                } else {
+                    e == ext
+                }
+            })
+        });
+        let mimetype_match = mimetype.map_or(false, |mimetype| {
+            self.mimetype.iter().any(|m| match m.split_once('/') {
+                Some((ty, subtype)) => {
+                    ty == mimetype.get_type() && subtype == mimetype.get_subtype()
+                }
+                None => m == mimetype.get_type(),
+            })
+        });
+        ext_match || mimetype_match
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(ext: &[&str], mimetype: &[&str]) -> ContextMenuEntry {
+        ContextMenuEntry {
+            label: "label".to_string(),
+            command: "reload_dirlist".to_string(),
+            ext: ext.iter().map(|s| s.to_string()).collect(),
+            mimetype: mimetype.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_matches_without_filters() {
+        assert!(entry(&[], &[]).matches(None, None, false));
+        assert!(entry(&[], &[]).matches(Some("rs"), None, false));
+    }
+
+    #[test]
+    fn test_matches_ext() {
+        let e = entry(&["tar", "gz"], &[]);
+        assert!(e.matches(Some("gz"), None, false));
+        assert!(!e.matches(Some("GZ"), None, false));
+        assert!(e.matches(Some("GZ"), None, true));
+        assert!(!e.matches(Some("zip"), None, false));
+        assert!(!e.matches(None, None, false));
+    }
+
+    #[test]
+    fn test_matches_mimetype() {
+        let png = Mimetype::new("image".to_string(), "png".to_string());
+        let text = Mimetype::new("text".to_string(), "plain".to_string());
+        assert!(entry(&[], &["image"]).matches(None, Some(&png), false));
+        assert!(entry(&[], &["image/png"]).matches(None, Some(&png), false));
+        assert!(!entry(&[], &["image/jpeg"]).matches(None, Some(&png), false));
+        assert!(!entry(&[], &["image"]).matches(None, Some(&text), false));
+        assert!(entry(&["txt"], &["image"]).matches(Some("txt"), Some(&text), false));
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt::Debug;
 use std::io;
 use std::path;
-use std::sync::mpsc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 use std::thread;
 
 use ratatui_image::protocol::Protocol;
@@ -9,14 +10,19 @@ use signal_hook::consts::signal;
 use signal_hook::iterator::exfiltrator::SignalOnly;
 use signal_hook::iterator::SignalsInfo;
 
-use termion::event::Event;
-use termion::input::TermRead;
+use termion::event::{Event, MouseEvent};
+use termion::input::TermReadEventsAndRaw;
 
 use uuid::Uuid;
 
 use crate::error::AppResult;
-use crate::fs::JoshutoDirList;
+use crate::fs::{
+    DiskUsageNode, DiskUsageProgress, GitDir, GitRepoStatus, GrepMatch, GrepProgress,
+    JoshutoDirList,
+};
+use crate::ipc::IpcQuery;
 use crate::preview::preview_file::FilePreview;
+use crate::types::command::Command;
 use crate::types::io::IoTaskProgressMessage;
 use crate::types::io::IoTaskStat;
 
@@ -58,10 +64,67 @@ pub enum AppEvent {
         path: path::PathBuf,
         res: io::Result<PreviewData>,
     },
+    // directory size thread events, `size` is the running total until `done`
+    DirSize {
+        path: path::PathBuf,
+        size: Option<u64>,
+        // entries left out of the final size as they could not be read
+        unreadable: usize,
+        done: bool,
+    },
+    // disk usage scan thread events
+    DiskUsageProgress {
+        id: Uuid,
+        progress: DiskUsageProgress,
+    },
+    DiskUsageScanned {
+        id: Uuid,
+        res: Box<io::Result<DiskUsageNode>>,
+    },
+    // content search thread events
+    GrepMatches {
+        id: Uuid,
+        matches: Vec<GrepMatch>,
+        progress: GrepProgress,
+    },
+    GrepDone {
+        id: Uuid,
+        res: io::Result<GrepProgress>,
+    },
+    // find thread events
+    FindMatches {
+        id: Uuid,
+        paths: Vec<path::PathBuf>,
+    },
+    FindDone {
+        id: Uuid,
+        res: io::Result<()>,
+    },
+    // git status thread events
+    GitStatus {
+        dir: path::PathBuf,
+        res: Option<Box<(GitDir, GitRepoStatus)>>,
+    },
     // terminal size change events
     Signal(i32),
     // filesystem change events
     Filesystem(notify::Event),
+
+    // IPC socket events, answered with a line of JSON
+    IpcCommand {
+        command: Command,
+        reply: mpsc::Sender<String>,
+    },
+    IpcQuery {
+        query: IpcQuery,
+        reply: mpsc::Sender<String>,
+    },
+
+    // script events, only sent while a script runs
+    #[cfg(feature = "scripting")]
+    ScriptRequest(crate::scripting::ScriptRequest),
+    #[cfg(feature = "scripting")]
+    ScriptDone,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -73,6 +136,9 @@ pub struct Events {
     pub event_tx: mpsc::Sender<AppEvent>,
     event_rx: mpsc::Receiver<AppEvent>,
     pub input_tx: mpsc::Sender<()>,
+    // termion drops the modifiers of mouse events, so the input thread
+    // keeps whether ctrl was held during the last one
+    mouse_ctrl: Arc<AtomicBool>,
 }
 
 impl Events {
@@ -88,6 +154,12 @@ impl Events {
         Ok(event)
     }
 
+    /// Whether ctrl was held during the last mouse event,
+    /// only valid until the next `flush`
+    pub fn mouse_ctrl_held(&self) -> bool {
+        self.mouse_ctrl.load(Ordering::Relaxed)
+    }
+
     pub fn flush(&self) {
         loop {
             if self.input_tx.send(()).is_ok() {
@@ -120,13 +192,29 @@ impl std::default::Default for Events {
 
         // input thread
         let event_tx2 = event_tx.clone();
+        let mouse_ctrl = Arc::new(AtomicBool::new(false));
+        let mouse_ctrl2 = mouse_ctrl.clone();
         let _ = thread::spawn(move || {
             let stdin = io::stdin();
-            let mut events = stdin.events();
+            let mut events = stdin.events_and_raw();
 
             loop {
                 let _ = input_rx.recv();
-                if let Some(Ok(event)) = events.next() {
+                if let Some(Ok((event, raw))) = events.next() {
+                    let event = match event {
+                        Event::Mouse(_) => {
+                            mouse_ctrl2.store(false, Ordering::Relaxed);
+                            event
+                        }
+                        Event::Unsupported(_) => match parse_modified_mouse(&raw) {
+                            Some((mouse_event, ctrl)) => {
+                                mouse_ctrl2.store(ctrl, Ordering::Relaxed);
+                                Event::Mouse(mouse_event)
+                            }
+                            None => event,
+                        },
+                        event => event,
+                    };
                     let _ = event_tx2.send(AppEvent::Termion(event));
                 }
             }
@@ -136,6 +224,82 @@ impl std::default::Default for Events {
             event_tx,
             event_rx,
             input_tx,
+            mouse_ctrl,
+        }
+    }
+}
+
+/// Termion only understands mouse events without modifiers and reports the others
+/// as unsupported. Parses such an event with the modifiers left out,
+/// together with whether ctrl was held.
+fn parse_modified_mouse(raw: &[u8]) -> Option<(MouseEvent, bool)> {
+    const SHIFT: u16 = 4;
+    const ALT: u16 = 8;
+    const CTRL: u16 = 16;
+    let unmodified = |button: u16| button & !(SHIFT | ALT | CTRL);
+
+    // the button code and the parameters after it
+    let leading_number = |bytes: &[u8]| -> Option<(u16, String)> {
+        let s = std::str::from_utf8(bytes).ok()?;
+        let (button, rest) = s.split_once(';')?;
+        Some((button.parse().ok()?, rest.to_string()))
+    };
+    let (button, sequence) = match raw {
+        // X10: ESC [ M Cb Cx Cy
+        [b'\x1b', b'[', b'M', button, rest @ ..] => {
+            let button = u16::from(*button).checked_sub(32)?;
+            let mut sequence = vec![b'\x1b', b'[', b'M', unmodified(button) as u8 + 32];
+            sequence.extend_from_slice(rest);
+            (button, sequence)
         }
+        // SGR: ESC [ < Cb ; Cx ; Cy M
+        [b'\x1b', b'[', b'<', rest @ ..] => {
+            let (button, rest) = leading_number(rest)?;
+            let sequence = format!("\x1b[<{};{}", unmodified(button), rest);
+            (button, sequence.into_bytes())
+        }
+        // urxvt: ESC [ Cb ; Cx ; Cy M
+        [b'\x1b', b'[', rest @ ..] => {
+            let (button, rest) = leading_number(rest)?;
+            let button = button.checked_sub(32)?;
+            let sequence = format!("\x1b[{};{}", unmodified(button) + 32, rest);
+            (button, sequence.into_bytes())
+        }
+        _ => return None,
+    };
+
+    let mut bytes = sequence.into_iter().map(Ok::<u8, io::Error>);
+    let first = bytes.next()?.ok()?;
+    match termion::event::parse_event(first, &mut bytes) {
+        Ok(Event::Mouse(event)) => Some((event, button & CTRL != 0)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use termion::event::MouseButton;
+
+    use super::*;
+
+    #[test]
+    fn modified_mouse_events_are_parsed_in_every_encoding() {
+        let ctrl_click = Some((MouseEvent::Press(MouseButton::Left, 10, 5), true));
+        assert_eq!(parse_modified_mouse(b"\x1b[<16;10;5M"), ctrl_click);
+        assert_eq!(
+            parse_modified_mouse(&[0x1b, b'[', b'M', 32 + 16, 32 + 10, 32 + 5]),
+            ctrl_click
+        );
+        assert_eq!(parse_modified_mouse(b"\x1b[48;10;5M"), ctrl_click);
+        // shift is left out, but not reported
+        assert_eq!(
+            parse_modified_mouse(b"\x1b[<6;10;5M"),
+            Some((MouseEvent::Press(MouseButton::Right, 10, 5), false))
+        );
+        assert_eq!(
+            parse_modified_mouse(b"\x1b[<48;10;5M"),
+            Some((MouseEvent::Hold(10, 5), true))
+        );
+        assert_eq!(parse_modified_mouse(b"\x1b[A"), None);
     }
 }
@@ -12,6 +12,7 @@ use crate::traits::ToString;
 use crate::types::command::Command;
 use crate::types::config_type::ConfigType;
 use crate::types::keybind::{CommandKeybind, KeyMapping};
+use crate::types::state::AppState;
 use crate::utils::keyparse::str_to_event;
 
 pub enum KeymapError {
@@ -23,6 +24,12 @@ pub struct AppKeyMapping {
     pub default_view: KeyMapping,
     pub task_view: KeyMapping,
     pub help_view: KeyMapping,
+    // layered on top of default_view while in visual mode
+    pub visual_mode: KeyMapping,
+    // layered on top of default_view while a filter is active
+    pub filter_mode: KeyMapping,
+    // user-defined modes, entered with `push_mode`
+    pub modes: HashMap<String, KeyMapping>,
 }
 
 impl AppKeyMapping {
@@ -31,9 +38,42 @@ impl AppKeyMapping {
             default_view: KeyMapping::new(),
             task_view: KeyMapping::new(),
             help_view: KeyMapping::new(),
+            visual_mode: KeyMapping::new(),
+            filter_mode: KeyMapping::new(),
+            modes: HashMap::new(),
         }
     }
 
+    /// Looks up an event in the currently active keymap layers.
+    /// User-defined modes take precedence, starting with the most recently pushed one,
+    /// followed by visual mode and filter mode, falling through to `default_view`.
+    pub fn get(&self, app_state: &AppState, event: &Event) -> Option<&CommandKeybind> {
+        let mut layers: Vec<&KeyMapping> = app_state
+            .state
+            .mode_stack
+            .iter()
+            .rev()
+            .filter_map(|name| self.modes.get(name))
+            .collect();
+
+        let curr_tab = app_state.state.tab_state_ref().curr_tab_ref();
+        if let Some(curr_list) = curr_tab.curr_list_ref() {
+            if curr_list.get_visual_mode_anchor_index().is_some() {
+                layers.push(&self.visual_mode);
+            }
+            let is_filtered = curr_tab
+                .option_ref()
+                .dirlist_options_ref(&curr_list.file_path().to_path_buf())
+                .map_or(false, |opt| !opt.filter_state_ref().is_none());
+            if is_filtered {
+                layers.push(&self.filter_mode);
+            }
+        }
+        layers.push(&self.default_view);
+
+        layers.into_iter().find_map(|layer| layer.get(event))
+    }
+
     pub fn default_res() -> AppResult<Self> {
         let crude: AppKeyMappingRaw = toml::from_str(KEYMAP_CONFIG)?;
         let keymapping: Self = Self::from(crude);
@@ -107,6 +147,13 @@ impl From<AppKeyMappingRaw> for AppKeyMapping {
         keymaps.default_view = command_keymaps_vec_to_map(&raw.default_view.keymap);
         keymaps.task_view = command_keymaps_vec_to_map(&raw.task_view.keymap);
         keymaps.help_view = command_keymaps_vec_to_map(&raw.help_view.keymap);
+        keymaps.visual_mode = command_keymaps_vec_to_map(&raw.visual_mode.keymap);
+        keymaps.filter_mode = command_keymaps_vec_to_map(&raw.filter_mode.keymap);
+        keymaps.modes = raw
+            .mode
+            .iter()
+            .map(|(name, mode)| (name.clone(), command_keymaps_vec_to_map(&mode.keymap)))
+            .collect();
         keymaps
     }
 }
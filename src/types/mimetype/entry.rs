@@ -2,6 +2,9 @@ use serde::Deserialize;
 use std::env;
 use std::fmt;
 
+/// Placeholder in args for the line to open a file at
+pub const LINE_PLACEHOLDER: &str = "%l";
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ProgramEntry {
     #[serde(rename = "command")]
@@ -65,6 +68,23 @@ impl ProgramEntry {
         &self._args
     }
 
+    /// Args to run the program with.
+    /// Args containing `%l` are left out, as there is no line to open the file at.
+    pub fn get_command_args(&self) -> impl Iterator<Item = &String> {
+        self._args
+            .iter()
+            .filter(|arg| !arg.contains(LINE_PLACEHOLDER))
+    }
+
+    /// The same program with `%l` in its args replaced by `line`
+    pub fn at_line(&self, line: usize) -> Self {
+        let mut entry = self.clone();
+        for arg in entry._args.iter_mut() {
+            *arg = arg.replace(LINE_PLACEHOLDER, &line.to_string());
+        }
+        entry
+    }
+
     pub fn get_fork(&self) -> bool {
         self._fork
     }
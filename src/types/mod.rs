@@ -1,6 +1,8 @@
 pub mod command;
 pub mod completion_kind;
+pub mod config_cell;
 pub mod config_type;
+pub mod context_menu_entry;
 pub mod custom_command;
 pub mod event;
 pub mod io;
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ClipboardOption {
+    /// Backends in the order they are tried
+    #[serde(default = "default_backends")]
+    pub backends: Vec<ClipboardBackend>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClipboardBackend {
+    /// wl-copy and wl-paste, on Wayland
+    #[serde(rename = "wl-copy")]
+    WlCopy,
+    /// on X11
+    #[serde(rename = "xclip")]
+    Xclip,
+    /// on X11
+    #[serde(rename = "xsel")]
+    Xsel,
+    /// pbcopy and pbpaste, on macOS
+    #[serde(rename = "pbcopy")]
+    Pbcopy,
+    /// terminal escape sequence, works over SSH and in tmux but can only copy
+    #[serde(rename = "osc52")]
+    Osc52,
+}
+
+impl std::default::Default for ClipboardOption {
+    fn default() -> Self {
+        Self {
+            backends: default_backends(),
+        }
+    }
+}
+
+fn default_backends() -> Vec<ClipboardBackend> {
+    vec![
+        ClipboardBackend::WlCopy,
+        ClipboardBackend::Xclip,
+        ClipboardBackend::Xsel,
+        ClipboardBackend::Pbcopy,
+        ClipboardBackend::Osc52,
+    ]
+}
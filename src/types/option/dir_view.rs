@@ -0,0 +1,400 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppResult;
+use crate::types::option::line_mode::LineMode;
+use crate::types::option::search::CaseSensitivity;
+use crate::types::option::sort::{SortMethod, SortOption};
+use crate::types::state::MatchState;
+
+// file in the state directory the views are stored in
+const DIR_VIEWS_FILE: &str = "dir_views.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterKind {
+    String,
+    Glob,
+    Regex,
+    Expr,
+}
+
+/// A filter together with the pattern it was created from, so it can be stored
+#[derive(Clone, Debug)]
+pub struct DirViewFilter {
+    pub kind: FilterKind,
+    pub pattern: String,
+    pub case_sensitivity: CaseSensitivity,
+    pub state: MatchState,
+}
+
+impl DirViewFilter {
+    pub fn new(
+        kind: FilterKind,
+        pattern: &str,
+        case_sensitivity: CaseSensitivity,
+    ) -> AppResult<Self> {
+        let state = match kind {
+            FilterKind::String => MatchState::new_string(pattern, case_sensitivity),
+            FilterKind::Glob => MatchState::new_glob(pattern, case_sensitivity)?,
+            FilterKind::Regex => MatchState::new_regex(pattern, case_sensitivity)?,
+            FilterKind::Expr if pattern.trim().is_empty() => MatchState::None,
+            FilterKind::Expr => MatchState::new_expr(pattern)?,
+        };
+        Ok(Self {
+            kind,
+            pattern: pattern.to_string(),
+            case_sensitivity,
+            state,
+        })
+    }
+}
+
+/// View settings of a directory that differ from the settings of the tab
+#[derive(Clone, Debug, Default)]
+pub struct DirView {
+    pub sort_method: Option<SortMethod>,
+    pub reverse: Option<bool>,
+    pub linemode: Option<LineMode>,
+    pub show_hidden: Option<bool>,
+    pub filter: Option<DirViewFilter>,
+}
+
+impl DirView {
+    pub fn is_empty(&self) -> bool {
+        self.sort_method.is_none()
+            && self.reverse.is_none()
+            && self.linemode.is_none()
+            && self.show_hidden.is_none()
+            && self.filter.is_none()
+    }
+}
+
+/// View settings remembered per directory across sessions, see `persist_dir_views`
+#[derive(Clone, Debug, Default)]
+pub struct DirViews {
+    // the file the views are stored in, `None` if they are not remembered
+    path: Option<PathBuf>,
+    views: HashMap<PathBuf, DirView>,
+}
+
+impl DirViews {
+    /// Reads the views stored in the state directory.
+    /// A missing file is no error, the views are stored there once there are any.
+    pub fn load() -> Self {
+        let path = match xdg::BaseDirectories::with_prefix(crate::PROGRAM_NAME) {
+            Ok(dirs) => dirs.get_state_file(DIR_VIEWS_FILE),
+            Err(e) => {
+                eprintln!("{}, directory views will not be remembered", e);
+                return Self::default();
+            }
+        };
+        let views = read_views(&path).unwrap_or_else(|e| {
+            eprintln!("{}: {}, will be ignored", path.to_string_lossy(), e);
+            HashMap::new()
+        });
+        Self {
+            path: Some(path),
+            views,
+        }
+    }
+
+    /// Whether view commands change the view of the current directory
+    /// instead of the one of the tab
+    pub fn is_enabled(&self) -> bool {
+        self.path.is_some()
+    }
+
+    pub fn get(&self, dir: &Path) -> Option<&DirView> {
+        self.views.get(dir)
+    }
+
+    /// The sort options of the tab with the ones of the view of `dir` applied
+    pub fn sort_options<'a>(&self, dir: &Path, tab_sort: &'a SortOption) -> Cow<'a, SortOption> {
+        let view = match self.get(dir) {
+            Some(view) if view.sort_method.is_some() || view.reverse.is_some() => view,
+            _ => return Cow::Borrowed(tab_sort),
+        };
+        let mut sort_options = tab_sort.clone();
+        if let Some(method) = view.sort_method {
+            sort_options.set_sort_method(method);
+        }
+        if let Some(reverse) = view.reverse {
+            sort_options.reverse = reverse;
+        }
+        Cow::Owned(sort_options)
+    }
+
+    pub fn linemode(&self, dir: &Path) -> Option<LineMode> {
+        self.get(dir).and_then(|view| view.linemode)
+    }
+
+    pub fn show_hidden(&self, dir: &Path) -> Option<bool> {
+        self.get(dir).and_then(|view| view.show_hidden)
+    }
+
+    /// Changes the view of `dir` and stores all views
+    pub fn update<F>(&mut self, dir: &Path, update: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut DirView),
+    {
+        let view = self.views.entry(dir.to_path_buf()).or_default();
+        update(view);
+        if view.is_empty() {
+            self.views.remove(dir);
+        }
+        self.save(dir)
+    }
+
+    /// Forgets the view of `dir`, returns whether there was one
+    pub fn remove(&mut self, dir: &Path) -> io::Result<bool> {
+        if self.views.remove(dir).is_none() {
+            return Ok(false);
+        }
+        self.save(dir)?;
+        Ok(true)
+    }
+
+    // stores the view of `dir` along with the views other instances stored in the meantime,
+    // replacing the file at once so it is never read half written
+    fn save(&mut self, dir: &Path) -> io::Result<()> {
+        let path = match self.path.as_ref() {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        // a file that can not be parsed is left alone instead of losing the views in it
+        let mut views = read_views(path).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.to_string_lossy(), e),
+            )
+        })?;
+        match self.views.remove(dir) {
+            Some(view) => views.insert(dir.to_path_buf(), view),
+            None => views.remove(dir),
+        };
+        self.views = views;
+
+        let mut dir: Vec<DirViewRaw> = self
+            .views
+            .iter()
+            .map(|(path, view)| DirViewRaw::from_view(path, view))
+            .collect();
+        dir.sort_by(|a, b| a.path.cmp(&b.path));
+        let content = toml::to_string(&DirViewsRaw { dir })
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_file_name(format!(".{}.{}", DIR_VIEWS_FILE, process::id()));
+        fs::write(&temp_path, content)?;
+        fs::rename(&temp_path, path).map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            e
+        })
+    }
+}
+
+/// Reads the views stored in `path`, a missing file has none
+fn read_views(path: &Path) -> Result<HashMap<PathBuf, DirView>, toml::de::Error> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(_) => return Ok(HashMap::new()),
+    };
+    let raw: DirViewsRaw = toml::from_str(&content)?;
+    Ok(raw.dir.into_iter().map(DirView::from_raw).collect())
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct DirViewsRaw {
+    #[serde(default)]
+    dir: Vec<DirViewRaw>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct DirViewRaw {
+    path: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sort_method: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reverse: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    linemode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    show_hidden: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    filter: Option<DirViewFilterRaw>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct DirViewFilterRaw {
+    kind: FilterKind,
+    pattern: String,
+    #[serde(default)]
+    case_sensitivity: CaseSensitivity,
+}
+
+impl DirViewRaw {
+    fn from_view(path: &Path, view: &DirView) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            sort_method: view.sort_method.map(|m| m.as_str().to_string()),
+            reverse: view.reverse,
+            linemode: view.linemode.map(|l| l.as_string()),
+            show_hidden: view.show_hidden,
+            filter: view.filter.as_ref().map(|f| DirViewFilterRaw {
+                kind: f.kind,
+                pattern: f.pattern.clone(),
+                case_sensitivity: f.case_sensitivity,
+            }),
+        }
+    }
+}
+
+impl DirView {
+    fn from_raw(raw: DirViewRaw) -> (PathBuf, Self) {
+        let sort_method = raw.sort_method.as_deref().and_then(|s| {
+            let method = SortMethod::from_str(s);
+            if method.is_none() {
+                eprintln!("Unknown sort method '{}', will be ignored", s);
+            }
+            method
+        });
+        let linemode = raw
+            .linemode
+            .as_deref()
+            .and_then(|s| match LineMode::from_string(s) {
+                Ok(linemode) => Some(linemode),
+                Err(e) => {
+                    eprintln!("{}, will be ignored", e);
+                    None
+                }
+            });
+        let filter = raw.filter.and_then(|f| {
+            match DirViewFilter::new(f.kind, &f.pattern, f.case_sensitivity) {
+                Ok(filter) => Some(filter),
+                Err(e) => {
+                    eprintln!("{}, will be ignored", e);
+                    None
+                }
+            }
+        });
+        let view = Self {
+            sort_method,
+            reverse: raw.reverse,
+            linemode,
+            show_hidden: raw.show_hidden,
+            filter,
+        };
+        (raw.path, view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn views_are_stored_and_read_back() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let mut views = DirViews {
+            path: Some(root.join(DIR_VIEWS_FILE)),
+            views: HashMap::new(),
+        };
+        let downloads = Path::new("/home/user/Downloads");
+        views
+            .update(downloads, |view| {
+                view.sort_method = Some(SortMethod::Mtime);
+                view.reverse = Some(true);
+                view.filter = Some(
+                    DirViewFilter::new(FilterKind::Glob, "*.png", CaseSensitivity::Sensitive)
+                        .unwrap(),
+                );
+            })
+            .unwrap();
+        views
+            .update(Path::new("/tmp"), |view| view.show_hidden = Some(true))
+            .unwrap();
+
+        let content = fs::read_to_string(root.join(DIR_VIEWS_FILE)).unwrap();
+        let raw: DirViewsRaw = toml::from_str(&content).unwrap();
+        let read: HashMap<PathBuf, DirView> = raw.dir.into_iter().map(DirView::from_raw).collect();
+        let view = &read[downloads];
+        assert_eq!(view.sort_method, Some(SortMethod::Mtime));
+        assert_eq!(view.reverse, Some(true));
+        assert!(view.filter.as_ref().unwrap().state.is_match("a.png"));
+        assert_eq!(read[Path::new("/tmp")].show_hidden, Some(true));
+
+        let tab_sort = SortOption::default();
+        let sort_options = views.sort_options(downloads, &tab_sort);
+        assert_eq!(
+            sort_options.sort_methods.list.front(),
+            Some(&SortMethod::Mtime)
+        );
+        assert!(sort_options.reverse);
+
+        // views without any settings left are dropped
+        views
+            .update(Path::new("/tmp"), |view| view.show_hidden = None)
+            .unwrap();
+        assert!(views.get(Path::new("/tmp")).is_none());
+        assert!(views.remove(downloads).unwrap());
+        assert!(!views.remove(downloads).unwrap());
+    }
+
+    #[test]
+    fn views_of_other_instances_are_kept() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(DIR_VIEWS_FILE);
+        let mut first = DirViews {
+            path: Some(path.clone()),
+            views: HashMap::new(),
+        };
+        let mut second = first.clone();
+
+        first
+            .update(Path::new("/a"), |view| view.reverse = Some(true))
+            .unwrap();
+        second
+            .update(Path::new("/b"), |view| view.show_hidden = Some(true))
+            .unwrap();
+        assert_eq!(second.get(Path::new("/a")).unwrap().reverse, Some(true));
+
+        let stored = read_views(&path).unwrap();
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[Path::new("/b")].show_hidden, Some(true));
+
+        // removing a view only removes that one
+        first.remove(Path::new("/a")).unwrap();
+        let stored = read_views(&path).unwrap();
+        assert!(!stored.contains_key(Path::new("/a")));
+        assert_eq!(stored[Path::new("/b")].show_hidden, Some(true));
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn broken_files_are_not_overwritten() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(DIR_VIEWS_FILE);
+        fs::write(&path, "[[dir]\npath = ").unwrap();
+        let mut views = DirViews {
+            path: Some(path.clone()),
+            views: HashMap::new(),
+        };
+
+        assert!(views
+            .update(Path::new("/a"), |view| view.reverse = Some(true))
+            .is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "[[dir]\npath = ");
+    }
+}
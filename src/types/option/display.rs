@@ -1,18 +1,23 @@
+use std::collections::HashMap;
 use std::convert::From;
 
 use ratatui::layout::Constraint;
 
 use crate::{
-    config::display_raw::DisplayOptionRaw, fs::DirListDisplayOptions, tab::TabDisplayOption,
+    config::{column_option_raw::ColumnOptionRaw, display_raw::DisplayOptionRaw},
+    fs::DirListDisplayOptions,
+    tab::TabDisplayOption,
 };
 
-use super::line_mode::LineNumberStyle;
+use super::dir_view::DirViews;
+use super::line_mode::{ColumnAlignment, ColumnOption, LineModeArgs, LineNumberStyle, TimeFormat};
 
 #[derive(Clone, Copy, Debug)]
 pub enum DisplayMode {
     Default,
     Minimal,
     HSplit,
+    DualPane,
 }
 
 pub const fn default_column_ratio() -> (usize, usize, usize) {
@@ -28,11 +33,17 @@ pub struct DisplayOption {
     pub scroll_offset: usize,
     pub show_borders: bool,
     pub show_hidden: bool,
+    pub show_ignored: bool,
     pub show_icons: bool,
     pub line_number_style: LineNumberStyle,
+    pub column_headers: bool,
+    pub time_format: TimeFormat,
+    pub columns: HashMap<LineModeArgs, ColumnOption>,
     pub default_layout: [Constraint; 3],
     pub no_preview_layout: [Constraint; 3],
     pub default_tab_display_option: TabDisplayOption,
+    /// view settings of single directories, only remembered if `persist_dir_views` is set
+    pub dir_views: DirViews,
 }
 
 impl From<DisplayOptionRaw> for DisplayOption {
@@ -40,6 +51,7 @@ impl From<DisplayOptionRaw> for DisplayOption {
         let mode = match raw.mode.as_str() {
             "hsplit" => DisplayMode::HSplit,
             "minimal" => DisplayMode::Minimal,
+            "dualpane" => DisplayMode::DualPane,
             _ => DisplayMode::Default,
         };
 
@@ -51,6 +63,26 @@ impl From<DisplayOptionRaw> for DisplayOption {
 
         let total = (left + mid + right) as u32;
 
+        let time_format = match raw.time_format.as_deref().map(TimeFormat::from_string) {
+            Some(Ok(format)) => format,
+            Some(Err(e)) => {
+                eprintln!("{}, will be ignored", e);
+                TimeFormat::default()
+            }
+            None => TimeFormat::default(),
+        };
+        let columns = raw
+            .columns
+            .iter()
+            .filter_map(|(name, raw)| match name.parse::<LineModeArgs>() {
+                Ok(arg) => Some((arg, column_option_from_raw(arg, raw, &time_format))),
+                Err(e) => {
+                    eprintln!("{}, column will be ignored", e);
+                    None
+                }
+            })
+            .collect();
+
         let default_layout = [
             Constraint::Ratio(left as u32, total),
             Constraint::Ratio(mid as u32, total),
@@ -69,8 +101,12 @@ impl From<DisplayOptionRaw> for DisplayOption {
             scroll_offset: raw.scroll_offset,
             show_borders: raw.show_borders,
             show_hidden: raw.show_hidden,
+            show_ignored: raw.show_ignored,
             show_icons: raw.show_icons,
             line_number_style: raw.line_number_style,
+            column_headers: raw.column_headers,
+            time_format,
+            columns,
 
             default_layout,
             no_preview_layout,
@@ -80,11 +116,26 @@ impl From<DisplayOptionRaw> for DisplayOption {
                 linemode: raw.linemode,
                 ..Default::default()
             },
+            dir_views: if raw.persist_dir_views {
+                DirViews::load()
+            } else {
+                DirViews::default()
+            },
         }
     }
 }
 
 impl DisplayOption {
+    pub fn column_option(&self, arg: LineModeArgs) -> ColumnOption {
+        self.columns
+            .get(&arg)
+            .cloned()
+            .unwrap_or_else(|| ColumnOption {
+                time_format: self.time_format.clone(),
+                ..ColumnOption::new(arg)
+            })
+    }
+
     pub fn filter_func(
         &self,
     ) -> fn(&walkdir::DirEntry, &DisplayOption, &DirListDisplayOptions) -> bool {
@@ -115,15 +166,49 @@ impl std::default::Default for DisplayOption {
             scroll_offset: 4,
             show_borders: true,
             show_hidden: false,
+            show_ignored: true,
             show_icons: false,
             line_number_style: LineNumberStyle::None,
+            column_headers: false,
+            time_format: TimeFormat::default(),
+            columns: HashMap::new(),
             default_layout,
             no_preview_layout,
             default_tab_display_option: TabDisplayOption::default(),
+            dir_views: DirViews::default(),
         }
     }
 }
 
+fn column_option_from_raw(
+    arg: LineModeArgs,
+    raw: &ColumnOptionRaw,
+    time_format: &TimeFormat,
+) -> ColumnOption {
+    let mut option = ColumnOption::new(arg);
+    option.width = raw.width;
+    if let Some(header) = raw.header.as_ref() {
+        option.header = header.clone();
+    }
+    if let Some(style) = raw.style.as_ref() {
+        option.style = style.as_style();
+    }
+    match raw.align.as_deref().map(ColumnAlignment::from_string) {
+        Some(Ok(align)) => option.align = align,
+        Some(Err(e)) => eprintln!("{}, will be ignored", e),
+        None => {}
+    }
+    option.time_format = match raw.time_format.as_deref().map(TimeFormat::from_string) {
+        Some(Ok(format)) => format,
+        Some(Err(e)) => {
+            eprintln!("{}, will be ignored", e);
+            time_format.clone()
+        }
+        None => time_format.clone(),
+    };
+    option
+}
+
 fn is_hidden(entry: &walkdir::DirEntry) -> bool {
     entry
         .file_name()
@@ -137,7 +222,8 @@ fn filter(
     opt: &DisplayOption,
     dirlist_opts: &DirListDisplayOptions,
 ) -> bool {
-    if !opt.show_hidden && is_hidden(entry) {
+    let show_hidden = dirlist_opts.show_hidden.unwrap_or(opt.show_hidden);
+    if !show_hidden && is_hidden(entry) {
         return false;
     }
 
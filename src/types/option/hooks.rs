@@ -0,0 +1,111 @@
+use std::ffi::OsStr;
+use std::path;
+use std::process;
+use std::thread;
+
+use crate::config::hooks_raw::HooksRaw;
+use crate::utils::unix;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Hook {
+    Cd,
+    Open,
+    Select,
+    TabChange,
+    IoTaskDone,
+    Quit,
+}
+
+impl Hook {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cd => "on_cd",
+            Self::Open => "on_open",
+            Self::Select => "on_select",
+            Self::TabChange => "on_tab_change",
+            Self::IoTaskDone => "on_io_task_done",
+            Self::Quit => "on_quit",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct HookOption {
+    pub on_cd: Option<path::PathBuf>,
+    pub on_open: Option<path::PathBuf>,
+    pub on_select: Option<path::PathBuf>,
+    pub on_tab_change: Option<path::PathBuf>,
+    pub on_io_task_done: Option<path::PathBuf>,
+    pub on_quit: Option<path::PathBuf>,
+}
+
+impl HookOption {
+    pub fn script(&self, hook: Hook) -> Option<&path::Path> {
+        match hook {
+            Hook::Cd => self.on_cd.as_deref(),
+            Hook::Open => self.on_open.as_deref(),
+            Hook::Select => self.on_select.as_deref(),
+            Hook::TabChange => self.on_tab_change.as_deref(),
+            Hook::IoTaskDone => self.on_io_task_done.as_deref(),
+            Hook::Quit => self.on_quit.as_deref(),
+        }
+    }
+
+    /// Runs the script of a hook in the background, if one is configured.
+    ///
+    /// The name of the hook is passed in `$JOSHUTO_HOOK`.
+    /// The output of the script is discarded, so it does not interfere with the UI.
+    pub fn run<I, S>(&self, hook: Hook, args: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        if let Some(mut command) = self.command(hook, args) {
+            let _ = thread::spawn(move || {
+                let _ = command.status();
+            });
+        }
+    }
+
+    /// Like `run`, but waits for the script to finish.
+    /// Used for hooks after which joshuto exits.
+    pub fn run_and_wait<I, S>(&self, hook: Hook, args: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        if let Some(mut command) = self.command(hook, args) {
+            let _ = command.status();
+        }
+    }
+
+    fn command<I, S>(&self, hook: Hook, args: I) -> Option<process::Command>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let script = self.script(hook)?;
+        let mut command = process::Command::new(script);
+        command
+            .args(args)
+            .env("JOSHUTO_HOOK", hook.as_str())
+            .stdin(process::Stdio::null())
+            .stdout(process::Stdio::null())
+            .stderr(process::Stdio::null());
+        Some(command)
+    }
+}
+
+impl From<HooksRaw> for HookOption {
+    fn from(raw: HooksRaw) -> Self {
+        let expand = |s: Option<String>| s.map(|s| unix::expand_shell_string(&s));
+        Self {
+            on_cd: expand(raw.on_cd),
+            on_open: expand(raw.on_open),
+            on_select: expand(raw.on_select),
+            on_tab_change: expand(raw.on_tab_change),
+            on_io_task_done: expand(raw.on_io_task_done),
+            on_quit: expand(raw.on_quit),
+        }
+    }
+}
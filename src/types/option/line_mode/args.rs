@@ -1,25 +1,113 @@
-#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+use std::str::FromStr;
+
+use crate::error::{AppError, AppErrorKind};
+
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum LineModeArgs {
     Size,
     ModifyTime,
     AccessTime,
+    ChangeTime,
+    BirthTime,
     User,
     Group,
     Permission,
+    Octal,
+    Inode,
+    Links,
+    LinkTarget,
+    FileType,
+    Mimetype,
+    Git,
     #[default]
     Null,
 }
 
+impl LineModeArgs {
+    /// Number of fields that can be shown, excluding `Null`
+    pub const COUNT: usize = 15;
+
+    pub const fn is_time(&self) -> bool {
+        matches!(
+            self,
+            Self::ModifyTime | Self::AccessTime | Self::ChangeTime | Self::BirthTime
+        )
+    }
+
+    /// Column header used unless configured otherwise
+    pub const fn default_header(&self) -> &'static str {
+        match self {
+            Self::Size => "Size",
+            Self::ModifyTime => "Modified",
+            Self::AccessTime => "Accessed",
+            Self::ChangeTime => "Changed",
+            Self::BirthTime => "Created",
+            Self::User => "User",
+            Self::Group => "Group",
+            Self::Permission => "Permissions",
+            Self::Octal => "Mode",
+            Self::Inode => "Inode",
+            Self::Links => "Links",
+            Self::LinkTarget => "Target",
+            Self::FileType => "Type",
+            Self::Mimetype => "Mimetype",
+            Self::Git => "Git",
+            Self::Null => "",
+        }
+    }
+}
+
 impl AsRef<str> for LineModeArgs {
     fn as_ref(&self) -> &str {
         match self {
             LineModeArgs::Size => "size",
             LineModeArgs::ModifyTime => "mtime",
             LineModeArgs::AccessTime => "atime",
+            LineModeArgs::ChangeTime => "ctime",
+            LineModeArgs::BirthTime => "btime",
             LineModeArgs::User => "user",
             LineModeArgs::Group => "group",
             LineModeArgs::Permission => "perm",
+            LineModeArgs::Octal => "octal",
+            LineModeArgs::Inode => "inode",
+            LineModeArgs::Links => "nlink",
+            LineModeArgs::LinkTarget => "target",
+            LineModeArgs::FileType => "type",
+            LineModeArgs::Mimetype => "mimetype",
+            LineModeArgs::Git => "git",
             LineModeArgs::Null => unreachable!(),
         }
     }
 }
+
+impl FromStr for LineModeArgs {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "size" => Ok(Self::Size),
+            "mtime" => Ok(Self::ModifyTime),
+            "atime" => Ok(Self::AccessTime),
+            "ctime" => Ok(Self::ChangeTime),
+            "btime" => Ok(Self::BirthTime),
+            "user" => Ok(Self::User),
+            "group" => Ok(Self::Group),
+            "perm" => Ok(Self::Permission),
+            "octal" => Ok(Self::Octal),
+            "inode" => Ok(Self::Inode),
+            "nlink" => Ok(Self::Links),
+            "target" => Ok(Self::LinkTarget),
+            "type" => Ok(Self::FileType),
+            "mimetype" => Ok(Self::Mimetype),
+            "git" if cfg!(feature = "git") => Ok(Self::Git),
+            "git" => Err(AppError::new(
+                AppErrorKind::InvalidParameters,
+                "Linemode 'git' requires joshuto to be built with the `git` feature".to_string(),
+            )),
+            e => Err(AppError::new(
+                AppErrorKind::InvalidParameters,
+                format!("Linemode '{}' unknown.", e),
+            )),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::time;
+
+use chrono::format::{Item, StrftimeItems};
+use ratatui::style::Style;
+
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::utils::format;
+
+use super::LineModeArgs;
+
+pub const DEFAULT_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnAlignment {
+    Left,
+    Right,
+}
+
+impl ColumnAlignment {
+    pub fn from_string(s: &str) -> AppResult<Self> {
+        match s {
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            s => Err(AppError::new(
+                AppErrorKind::InvalidParameters,
+                format!("Unknown column alignment '{}'", s),
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimeFormat {
+    /// "3h ago"
+    Relative,
+    Strftime(String),
+}
+
+impl TimeFormat {
+    pub fn from_string(s: &str) -> AppResult<Self> {
+        if s == "relative" {
+            return Ok(Self::Relative);
+        }
+        // chrono panics when formatting with an invalid format string
+        if StrftimeItems::new(s).any(|item| matches!(item, Item::Error)) {
+            return Err(AppError::new(
+                AppErrorKind::InvalidParameters,
+                format!("Invalid time format '{}'", s),
+            ));
+        }
+        Ok(Self::Strftime(s.to_string()))
+    }
+
+    pub fn format(&self, time: time::SystemTime) -> String {
+        match self {
+            Self::Relative => format::time_to_relative_string(time, time::SystemTime::now()),
+            Self::Strftime(s) => format::time_to_string_with_format(time, s),
+        }
+    }
+}
+
+impl std::default::Default for TimeFormat {
+    fn default() -> Self {
+        Self::Strftime(DEFAULT_TIME_FORMAT.to_string())
+    }
+}
+
+/// How a linemode field is laid out as a column in the detailed view
+#[derive(Clone, Debug)]
+pub struct ColumnOption {
+    /// fixed width, otherwise as wide as the widest value on screen
+    pub width: Option<usize>,
+    pub align: ColumnAlignment,
+    pub header: String,
+    /// patched onto the style of the entry
+    pub style: Style,
+    /// only used by time columns
+    pub time_format: TimeFormat,
+}
+
+impl ColumnOption {
+    pub fn new(arg: LineModeArgs) -> Self {
+        let align = match arg {
+            LineModeArgs::Size | LineModeArgs::Inode | LineModeArgs::Links => {
+                ColumnAlignment::Right
+            }
+            _ => ColumnAlignment::Left,
+        };
+        Self {
+            width: None,
+            align,
+            header: arg.default_header().to_string(),
+            style: Style::default(),
+            time_format: TimeFormat::default(),
+        }
+    }
+}
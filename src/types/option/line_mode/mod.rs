@@ -1,36 +1,34 @@
 mod args;
+mod column;
 mod line_number;
 
 pub use args::*;
+pub use column::*;
 pub use line_number::*;
 
-use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::error::AppResult;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct LineMode {
-    pub mode: [LineModeArgs; 7],
+    pub mode: [LineModeArgs; LineModeArgs::COUNT],
     pub size: usize,
 }
 
 impl LineMode {
     pub const fn all() -> Self {
-        Self {
-            mode: [
-                LineModeArgs::Size,
-                LineModeArgs::ModifyTime,
-                LineModeArgs::AccessTime,
-                LineModeArgs::User,
-                LineModeArgs::Group,
-                LineModeArgs::Permission,
-                LineModeArgs::Null,
-            ],
-            size: 7,
-        }
+        let mut mode = [LineModeArgs::Null; LineModeArgs::COUNT];
+        mode[0] = LineModeArgs::Size;
+        mode[1] = LineModeArgs::ModifyTime;
+        mode[2] = LineModeArgs::AccessTime;
+        mode[3] = LineModeArgs::User;
+        mode[4] = LineModeArgs::Group;
+        mode[5] = LineModeArgs::Permission;
+        Self { mode, size: 6 }
     }
 
     pub const fn empty() -> Self {
         Self {
-            mode: [LineModeArgs::Null; 7],
+            mode: [LineModeArgs::Null; LineModeArgs::COUNT],
             size: 0,
         }
     }
@@ -47,7 +45,7 @@ impl LineMode {
 
 impl Default for LineMode {
     fn default() -> Self {
-        let mut mode = [Default::default(); 7];
+        let mut mode = [Default::default(); LineModeArgs::COUNT];
         mode[0] = LineModeArgs::Size;
 
         Self { size: 1, mode }
@@ -63,20 +61,7 @@ impl LineMode {
                 let mut line_mode = LineMode::empty();
 
                 for mode in name.split('|').map(|mode| mode.trim()) {
-                    match mode {
-                        "size" => line_mode.add_mode(LineModeArgs::Size),
-                        "mtime" => line_mode.add_mode(LineModeArgs::ModifyTime),
-                        "atime" => line_mode.add_mode(LineModeArgs::AccessTime),
-                        "user" => line_mode.add_mode(LineModeArgs::User),
-                        "group" => line_mode.add_mode(LineModeArgs::Group),
-                        "perm" => line_mode.add_mode(LineModeArgs::Permission),
-                        e => {
-                            return Err(AppError::new(
-                                AppErrorKind::InvalidParameters,
-                                format!("Linemode '{}' unknown.", e),
-                            ))
-                        }
-                    }
+                    line_mode.add_mode(mode.parse()?);
                 }
 
                 Ok(line_mode)
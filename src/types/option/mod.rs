@@ -1,4 +1,7 @@
+pub mod clipboard;
+pub mod dir_view;
 pub mod display;
+pub mod hooks;
 pub mod line_mode;
 pub mod preview;
 pub mod search;
@@ -3,31 +3,41 @@ use std::collections::VecDeque;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
-#[serde(untagged)]
+#[serde(rename_all = "lowercase")]
 pub enum SortMethod {
     #[default]
-    #[serde(rename = "lexical")]
     Lexical,
-    #[serde(rename = "mtime")]
     Mtime,
-    #[serde(rename = "natural")]
     Natural,
-    #[serde(rename = "size")]
     Size,
-    #[serde(rename = "exit")]
     Ext,
+    Atime,
+    Ctime,
+    Btime,
+    Type,
+    Version,
+    Random,
+    Mimetype,
 }
 
 impl SortMethod {
+    pub const ALL: [SortMethod; 12] = [
+        SortMethod::Lexical,
+        SortMethod::Mtime,
+        SortMethod::Natural,
+        SortMethod::Size,
+        SortMethod::Ext,
+        SortMethod::Atime,
+        SortMethod::Ctime,
+        SortMethod::Btime,
+        SortMethod::Type,
+        SortMethod::Version,
+        SortMethod::Random,
+        SortMethod::Mimetype,
+    ];
+
     pub fn from_str(s: &str) -> Option<Self> {
-        match s {
-            "lexical" => Some(SortMethod::Lexical),
-            "mtime" => Some(SortMethod::Mtime),
-            "natural" => Some(SortMethod::Natural),
-            "size" => Some(SortMethod::Size),
-            "ext" => Some(SortMethod::Ext),
-            _ => None,
-        }
+        Self::ALL.into_iter().find(|method| method.as_str() == s)
     }
     pub const fn as_str(&self) -> &str {
         match *self {
@@ -36,6 +46,13 @@ impl SortMethod {
             SortMethod::Natural => "natural",
             SortMethod::Size => "size",
             SortMethod::Ext => "ext",
+            SortMethod::Atime => "atime",
+            SortMethod::Ctime => "ctime",
+            SortMethod::Btime => "btime",
+            SortMethod::Type => "type",
+            SortMethod::Version => "version",
+            SortMethod::Random => "random",
+            SortMethod::Mimetype => "mimetype",
         }
     }
 }
@@ -52,9 +69,10 @@ pub struct SortMethodList {
 }
 
 impl SortMethodList {
+    /// Sorts by `st` first, the other methods keep their order as tie-breakers
     pub fn reorganize(&mut self, st: SortMethod) {
+        self.list.retain(|method| *method != st);
         self.list.push_front(st);
-        self.list.pop_back();
     }
 }
 
@@ -1,11 +1,15 @@
 use std::cmp;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::time;
 
 use serde::{Deserialize, Serialize};
 
 use crate::config::sort_option_raw::SortOptionRaw;
-use crate::fs::JoshutoDirEntry;
+use crate::fs::{FileType, JoshutoDirEntry, LinkType};
+use crate::utils::unix;
 
 use crate::types::option::sort::{SortMethod, SortMethodList};
 
@@ -15,10 +19,16 @@ pub struct SortOption {
     pub case_sensitive: bool,
     pub reverse: bool,
     pub sort_methods: SortMethodList,
+    // order of the random sort, a new one is drawn every time it is chosen
+    #[serde(default)]
+    pub random_seed: u64,
 }
 
 impl SortOption {
     pub fn set_sort_method(&mut self, method: SortMethod) {
+        if method == SortMethod::Random {
+            self.random_seed = rand::random();
+        }
         self.sort_methods.reorganize(method);
     }
 
@@ -61,6 +71,23 @@ impl SortOption {
                     }
                 }
                 SortMethod::Size => size_sort(f1, f2),
+                SortMethod::Atime => f1.metadata.accessed().cmp(&f2.metadata.accessed()),
+                SortMethod::Ctime => f1.metadata.changed().cmp(&f2.metadata.changed()),
+                SortMethod::Btime => f1.metadata.created().cmp(&f2.metadata.created()),
+                SortMethod::Type => type_rank(f1).cmp(&type_rank(f2)),
+                SortMethod::Version => {
+                    if self.case_sensitive {
+                        version_compare(f1.file_name(), f2.file_name())
+                    } else {
+                        let f1_name = f1.file_name().to_lowercase();
+                        let f2_name = f2.file_name().to_lowercase();
+                        version_compare(&f1_name, &f2_name)
+                    }
+                }
+                SortMethod::Random => {
+                    random_key(self.random_seed, f1).cmp(&random_key(self.random_seed, f2))
+                }
+                SortMethod::Mimetype => mimetype(f1).cmp(mimetype(f2)),
             };
 
             if self.reverse {
@@ -82,17 +109,35 @@ impl std::default::Default for SortOption {
             case_sensitive: false,
             reverse: false,
             sort_methods: SortMethodList::default(),
+            random_seed: rand::random(),
         }
     }
 }
 
 impl From<SortOptionRaw> for SortOption {
     fn from(raw: SortOptionRaw) -> Self {
+        let parse = |s: &str| {
+            let method = SortMethod::from_str(s);
+            if method.is_none() {
+                eprintln!("Unknown sort method '{}', will be ignored", s);
+            }
+            method
+        };
         let sort_method = raw
             .sort_method
-            .and_then(|s| SortMethod::from_str(&s))
+            .as_deref()
+            .and_then(parse)
             .unwrap_or(SortMethod::Natural);
-        let mut sort_methods = SortMethodList::default();
+        // without a tie-break chain, the other methods break ties in their default order
+        let mut sort_methods = match raw.tie_break.as_ref() {
+            Some(tie_break) => SortMethodList {
+                list: tie_break
+                    .iter()
+                    .filter_map(|s| parse(s))
+                    .collect::<VecDeque<_>>(),
+            },
+            None => SortMethodList::default(),
+        };
         sort_methods.reorganize(sort_method);
 
         Self {
@@ -100,6 +145,7 @@ impl From<SortOptionRaw> for SortOption {
             case_sensitive: raw.case_sensitive,
             reverse: raw.reverse,
             sort_methods,
+            random_seed: rand::random(),
         }
     }
 }
@@ -120,7 +166,13 @@ fn mtime_sort(file1: &JoshutoDirEntry, file2: &JoshutoDirEntry) -> cmp::Ordering
 }
 
 fn size_sort(file1: &JoshutoDirEntry, file2: &JoshutoDirEntry) -> cmp::Ordering {
-    file1.metadata.len().cmp(&file2.metadata.len())
+    // directories whose size was computed are compared by the size of their contents
+    let size = |file: &JoshutoDirEntry| {
+        file.metadata
+            .recursive_size()
+            .unwrap_or(file.metadata.len())
+    };
+    size(file1).cmp(&size(file2))
 }
 
 fn ext_sort(file1: &JoshutoDirEntry, file2: &JoshutoDirEntry) -> cmp::Ordering {
@@ -128,3 +180,167 @@ fn ext_sort(file1: &JoshutoDirEntry, file2: &JoshutoDirEntry) -> cmp::Ordering {
     let f2_ext = file2.ext().unwrap_or_default();
     alphanumeric_sort::compare_str(f1_ext, f2_ext)
 }
+
+/// Directories first, then links, executables and everything else
+fn type_rank(file: &JoshutoDirEntry) -> u8 {
+    if let LinkType::Symlink { .. } = file.metadata.link_type() {
+        return 1;
+    }
+    match file.metadata.file_type() {
+        FileType::Directory => 0,
+        FileType::File if unix::is_executable(file.metadata.mode) => 2,
+        _ => 3,
+    }
+}
+
+fn random_key(seed: u64, file: &JoshutoDirEntry) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    file.file_name().hash(&mut hasher);
+    hasher.finish()
+}
+
+fn mimetype(file: &JoshutoDirEntry) -> &'static str {
+    if file.metadata.is_dir() {
+        "inode/directory"
+    } else {
+        mime_guess::from_path(file.file_name())
+            .first_raw()
+            .unwrap_or_default()
+    }
+}
+
+/// Splits off the leading run of digits or of other characters
+fn split_chunk(s: &str) -> (&str, &str) {
+    let is_digit = s.starts_with(|c: char| c.is_ascii_digit());
+    let end = s
+        .find(|c: char| c.is_ascii_digit() != is_digit)
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Whether `rest` of a name starts a pre-release suffix like `-rc1` or `~beta`
+fn is_pre_release(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    matches!(chars.next(), Some('-' | '~')) && chars.next().map_or(false, |c| c.is_alphabetic())
+}
+
+/// Compares names with version numbers: runs of digits by their value,
+/// so `1.10` comes after `1.9`, and a pre-release like `1.0.0-rc1` before `1.0.0`
+fn version_compare(name1: &str, name2: &str) -> cmp::Ordering {
+    let (mut rest1, mut rest2) = (name1, name2);
+    let mut after_number = false;
+    loop {
+        // what follows a number is ranked by both suffixes before comparing it further,
+        // so a pre-release comes before anything else, keeping the order transitive
+        if after_number {
+            let ordering = is_pre_release(rest2).cmp(&is_pre_release(rest1));
+            if ordering != cmp::Ordering::Equal {
+                return ordering;
+            }
+        }
+        if rest1.is_empty() || rest2.is_empty() {
+            return rest1.len().cmp(&rest2.len());
+        }
+
+        let (chunk1, next1) = split_chunk(rest1);
+        let (chunk2, next2) = split_chunk(rest2);
+        after_number = chunk1.starts_with(|c: char| c.is_ascii_digit());
+        let ordering = if after_number && chunk2.starts_with(|c: char| c.is_ascii_digit()) {
+            let digits1 = chunk1.trim_start_matches('0');
+            let digits2 = chunk2.trim_start_matches('0');
+            digits1
+                .len()
+                .cmp(&digits2.len())
+                .then_with(|| digits1.cmp(digits2))
+        } else {
+            chunk1.cmp(chunk2)
+        };
+        if ordering != cmp::Ordering::Equal {
+            return ordering;
+        }
+        rest1 = next1;
+        rest2 = next2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_are_compared_by_their_numbers() {
+        let mut names = vec![
+            "app-1.10.0.tar.gz",
+            "app-1.2.0.tar.gz",
+            "app-1.2.0-rc1.tar.gz",
+            "app-1.9.3.tar.gz",
+            "app-1.2.0-beta.tar.gz",
+            "app-01.2.1.tar.gz",
+            "notes",
+            "notes-old",
+        ];
+        names.sort_by(|a, b| version_compare(a, b));
+        assert_eq!(
+            names,
+            [
+                "app-1.2.0-beta.tar.gz",
+                "app-1.2.0-rc1.tar.gz",
+                "app-1.2.0.tar.gz",
+                "app-01.2.1.tar.gz",
+                "app-1.9.3.tar.gz",
+                "app-1.10.0.tar.gz",
+                "notes",
+                "notes-old",
+            ]
+        );
+        assert_eq!(
+            version_compare("v2.0", "v2.0-alpha"),
+            cmp::Ordering::Greater
+        );
+        assert_eq!(version_compare("1.0", "1.0"), cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn version_order_is_transitive() {
+        let names = [
+            "v1", "v1-alpha", "v1-beta", "v1-2", "v1-rc1", "v1.0", "v1.0-rc", "v1~beta", "v1a",
+            "v1_2", "v01", "v10", "v2", "v", "v-1", "1", "1-", "1-a", "a", "",
+        ];
+        for a in names {
+            for b in names {
+                let ab = version_compare(a, b);
+                assert_eq!(ab, version_compare(b, a).reverse(), "{} {}", a, b);
+                for c in names {
+                    let bc = version_compare(b, c);
+                    if ab != cmp::Ordering::Greater && bc != cmp::Ordering::Greater {
+                        assert_ne!(
+                            version_compare(a, c),
+                            cmp::Ordering::Greater,
+                            "{} <= {} <= {}",
+                            a,
+                            b,
+                            c
+                        );
+                    }
+                }
+            }
+        }
+        assert_eq!(version_compare("v1-alpha", "v1-2"), cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn chosen_method_moves_to_the_front_of_the_chain() {
+        let mut list = SortMethodList {
+            list: [SortMethod::Natural, SortMethod::Size]
+                .into_iter()
+                .collect(),
+        };
+        list.reorganize(SortMethod::Mtime);
+        list.reorganize(SortMethod::Size);
+        assert_eq!(
+            Vec::from(list.list),
+            [SortMethod::Size, SortMethod::Mtime, SortMethod::Natural]
+        );
+    }
+}
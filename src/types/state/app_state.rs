@@ -8,9 +8,11 @@ use ratatui_image::picker::Picker;
 use crate::commands::quit::QuitAction;
 use crate::config::app::AppConfig;
 use crate::types::event::{AppEvent, Events};
+use crate::types::keymap::AppKeyMapping;
 use crate::types::option::preview::PreviewProtocol;
 use crate::types::state::{
-    CommandLineState, MessageQueue, PreviewState, TabState, UiState, WorkerState,
+    CommandLineState, DirSizeState, GitState, MacroState, MessageQueue, MouseState, PreviewState,
+    TabState, UiState, WorkerState,
 };
 
 use crate::{Args, THEME_T};
@@ -25,13 +27,15 @@ pub struct AppState {
     // args from the command line
     pub args: Args,
     pub state: FileManagerState,
+    // keymap loaded by `reload_config`, picked up by the main loop
+    pub pending_keymap: Option<AppKeyMapping>,
 }
 
 impl AppState {
     pub fn new(config: AppConfig, args: Args) -> Self {
         let picker = if config.preview_options.preview_shown_hook_script.is_none() {
             Picker::from_termios().ok().and_then(|mut picker| {
-                picker.background_color = match THEME_T.preview_background {
+                picker.background_color = match THEME_T.get().preview_background {
                     Color::Rgb(r, g, b) => Some(image::Rgb([r, g, b])),
                     _ => None,
                 };
@@ -55,6 +59,8 @@ impl AppState {
         let event_tx = events.event_tx.clone();
 
         let commandline_state = CommandLineState::new();
+        let git_state = GitState::new(event_tx.clone());
+        let dir_size_state = DirSizeState::new(event_tx.clone());
 
         let event_tx_for_fs_notification = event_tx.clone();
         let watcher = notify::recommended_watcher(move |res| {
@@ -73,7 +79,7 @@ impl AppState {
         };
         let xdg_thumb_size = config.preview_options.xdg_thumb_size;
 
-        Self {
+        let mut app_state = Self {
             config,
             quit: QuitAction::DoNot,
             events,
@@ -93,12 +99,23 @@ impl AppState {
                     event_tx,
                 ),
                 ui_state: UiState { layout: vec![] },
+                mouse_state: MouseState::default(),
                 commandline_state,
                 watcher,
                 watched_paths,
+                watched_config_dirs: Vec::new(),
                 last_stdout: None,
+                mode_stack: Vec::new(),
+                macro_state: MacroState::default(),
+                git_state,
+                dir_size_state,
             },
-        }
+            pending_keymap: None,
+        };
+        app_state
+            .state
+            .watch_config_dirs(app_state.config.watch_config);
+        app_state
     }
 
     // event related
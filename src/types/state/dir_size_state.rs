@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use std::time::Instant;
+
+use crate::fs::compute_dir_size;
+use crate::types::event::AppEvent;
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+pub struct DirSizeState {
+    // directories queued or being computed
+    pub pending: HashSet<PathBuf>,
+    // when the current batch of computations started, for the spinner
+    pub started: Option<Instant>,
+    // sends directories to the worker thread, which is started on first use
+    sender: Option<Sender<PathBuf>>,
+    // for telling main thread about the progress
+    pub event_tx: Sender<AppEvent>,
+}
+
+impl DirSizeState {
+    pub fn new(event_tx: Sender<AppEvent>) -> Self {
+        Self {
+            pending: HashSet::new(),
+            started: None,
+            sender: None,
+            event_tx,
+        }
+    }
+
+    /// Queues directories whose size will be computed one after another in the background.
+    /// Returns the number of newly queued directories.
+    pub fn compute<I>(&mut self, paths: I) -> usize
+    where
+        I: IntoIterator<Item = PathBuf>,
+    {
+        let event_tx = self.event_tx.clone();
+        let sender = self.sender.get_or_insert_with(|| {
+            let (sender, receiver) = mpsc::channel::<PathBuf>();
+            thread::spawn(move || {
+                for path in receiver {
+                    let res = compute_dir_size(&path, |size| {
+                        let _ = event_tx.send(AppEvent::DirSize {
+                            path: path.clone(),
+                            size: Some(size),
+                            unreadable: 0,
+                            done: false,
+                        });
+                    });
+                    let _ = event_tx.send(AppEvent::DirSize {
+                        path,
+                        size: res.as_ref().ok().map(|total| total.size),
+                        unreadable: res.map_or(0, |total| total.unreadable),
+                        done: true,
+                    });
+                }
+            });
+            sender
+        });
+
+        let mut count = 0;
+        for path in paths {
+            if !self.pending.contains(&path) && sender.send(path.clone()).is_ok() {
+                self.pending.insert(path);
+                count += 1;
+            }
+        }
+        if count > 0 && self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+        count
+    }
+
+    pub fn finish(&mut self, path: &Path) {
+        self.pending.remove(path);
+        if self.pending.is_empty() {
+            self.started = None;
+        }
+    }
+
+    pub fn is_computing(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Spinner and number of remaining directories, while sizes are computed
+    pub fn status(&self) -> Option<String> {
+        let started = self.started?;
+        let frame = (started.elapsed().as_millis() / 100) as usize % SPINNER.len();
+        Some(format!("{} du:{}", SPINNER[frame], self.pending.len()))
+    }
+}
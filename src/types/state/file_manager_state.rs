@@ -4,12 +4,14 @@ use std::path;
 use notify::{RecursiveMode, Watcher};
 
 use crate::types::state::{
-    CommandLineState, LocalStateState, MatchState, MessageQueue, PreviewState, TabState, UiState,
-    WorkerState,
+    CommandLineState, DirSizeState, GitState, LocalStateState, MacroState, MatchState,
+    MessageQueue, MouseState, PreviewState, TabState, UiState, WorkerState,
 };
 
 use super::ThreadPool;
 
+use crate::CONFIG_HIERARCHY;
+
 pub struct FileManagerState {
     // app_state related to tabs
     pub tab_state: TabState,
@@ -29,12 +31,24 @@ pub struct FileManagerState {
     pub commandline_state: CommandLineState,
     // user interface app_state; data which is input to both, the UI rendering and the app state
     pub ui_state: UiState,
+    // app_state related to clicking and dragging with the mouse
+    pub mouse_state: MouseState,
     // filesystem watcher to inform about changes in shown directories
     pub watcher: notify::RecommendedWatcher,
     // list of watched paths; seems not to be possible to get them from a notify::Watcher
     pub watched_paths: HashSet<path::PathBuf>,
+    // config directories watched for changes, if `watch_config` is enabled
+    pub watched_config_dirs: Vec<path::PathBuf>,
     // the stdout of the last `shell` command
     pub last_stdout: Option<String>,
+    // user-defined keymap modes entered with `push_mode`, the last one is the active one
+    pub mode_stack: Vec<String>,
+    // app_state related to macro recording and playback
+    pub macro_state: MacroState,
+    // git status of the visited directories
+    pub git_state: GitState,
+    // recursive directory sizes being computed
+    pub dir_size_state: DirSizeState,
 }
 
 impl FileManagerState {
@@ -57,7 +71,9 @@ impl FileManagerState {
 
         // remove paths from watcher which don't need to be watched anymore...
         for old_watched_path in &self.watched_paths {
-            if !new_paths_to_watch.contains(old_watched_path.as_path()) {
+            if !new_paths_to_watch.contains(old_watched_path.as_path())
+                && !self.watched_config_dirs.contains(old_watched_path)
+            {
                 let _ = self.watcher.unwatch(old_watched_path.as_path());
             }
         }
@@ -73,6 +89,36 @@ impl FileManagerState {
         self.watched_paths = new_paths_to_watch;
     }
 
+    /// Reads the git status of the current directory, if it is not known yet.
+    pub fn update_git_status(&mut self) {
+        let cwd = self.tab_state.curr_tab_ref().get_cwd().to_path_buf();
+        self.git_state.update(&cwd);
+    }
+
+    /// Starts or stops watching the config directories for changes.
+    pub fn watch_config_dirs(&mut self, enable: bool) {
+        if enable != self.watched_config_dirs.is_empty() {
+            return;
+        }
+        if enable {
+            for dir in CONFIG_HIERARCHY.iter().filter(|p| p.is_dir()) {
+                if self
+                    .watcher
+                    .watch(dir.as_path(), RecursiveMode::NonRecursive)
+                    .is_ok()
+                {
+                    self.watched_config_dirs.push(dir.clone());
+                }
+            }
+        } else {
+            for dir in self.watched_config_dirs.drain(..) {
+                if !self.watched_paths.contains(&dir) {
+                    let _ = self.watcher.unwatch(dir.as_path());
+                }
+            }
+        }
+    }
+
     pub fn tab_state_ref(&self) -> &TabState {
         &self.tab_state
     }
@@ -87,6 +133,27 @@ impl FileManagerState {
         &mut self.message_queue
     }
 
+    pub fn dir_size_state_ref(&self) -> &DirSizeState {
+        &self.dir_size_state
+    }
+    pub fn dir_size_state_mut(&mut self) -> &mut DirSizeState {
+        &mut self.dir_size_state
+    }
+
+    pub fn git_state_ref(&self) -> &GitState {
+        &self.git_state
+    }
+    pub fn git_state_mut(&mut self) -> &mut GitState {
+        &mut self.git_state
+    }
+
+    pub fn macro_state_ref(&self) -> &MacroState {
+        &self.macro_state
+    }
+    pub fn macro_state_mut(&mut self) -> &mut MacroState {
+        &mut self.macro_state
+    }
+
     // local state related
     pub fn set_local_state(&mut self, state: LocalStateState) {
         self.local_state = Some(state);
@@ -116,6 +183,10 @@ impl FileManagerState {
         &mut self.ui_state
     }
 
+    pub fn mouse_state_mut(&mut self) -> &mut MouseState {
+        &mut self.mouse_state
+    }
+
     pub fn worker_state_ref(&self) -> &WorkerState {
         &self.worker_state
     }
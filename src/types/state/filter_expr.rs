@@ -0,0 +1,494 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::{Duration, SystemTime};
+
+use globset::{GlobBuilder, GlobMatcher};
+use nix::sys::stat::mode_t;
+
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::fs::{FileType, JoshutoDirEntry, LinkType};
+use crate::utils::unix;
+
+/// An expression over the metadata of an entry,
+/// like `size > 100M && mtime < 7d && type == file`
+#[derive(Clone, Debug)]
+pub struct FilterExpr {
+    source: String,
+    root: Node,
+}
+
+impl FilterExpr {
+    pub fn parse(source: &str) -> AppResult<Self> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let root = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            return Err(parse_error(format!("Unexpected '{token}'")));
+        }
+        Ok(Self {
+            source: source.trim().to_string(),
+            root,
+        })
+    }
+
+    pub fn is_match(&self, entry: &JoshutoDirEntry) -> bool {
+        self.root.eval(entry, SystemTime::now())
+    }
+}
+
+impl Display for FilterExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.source)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    And(Box<Node>, Box<Node>),
+    Or(Box<Node>, Box<Node>),
+    Not(Box<Node>),
+    Compare(Comparison),
+}
+
+impl Node {
+    fn eval(&self, entry: &JoshutoDirEntry, now: SystemTime) -> bool {
+        match self {
+            Self::And(lhs, rhs) => lhs.eval(entry, now) && rhs.eval(entry, now),
+            Self::Or(lhs, rhs) => lhs.eval(entry, now) || rhs.eval(entry, now),
+            Self::Not(node) => !node.eval(entry, now),
+            Self::Compare(comparison) => comparison.eval(entry, now),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    // glob match, only for names
+    Glob,
+    // any of the bits set, only for permissions
+    BitAnd,
+}
+
+impl Op {
+    fn compare<T: PartialOrd>(self, lhs: T, rhs: T) -> bool {
+        match self {
+            Self::Eq => lhs == rhs,
+            Self::Ne => lhs != rhs,
+            Self::Lt => lhs < rhs,
+            Self::Le => lhs <= rhs,
+            Self::Gt => lhs > rhs,
+            Self::Ge => lhs >= rhs,
+            Self::Glob | Self::BitAnd => false,
+        }
+    }
+
+    fn is_equality(self) -> bool {
+        matches!(self, Self::Eq | Self::Ne)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TimeField {
+    Modified,
+    Accessed,
+    Changed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EntryKind {
+    File,
+    Directory,
+    Link,
+    Socket,
+    Block,
+    Character,
+    Pipe,
+}
+
+impl EntryKind {
+    fn of(entry: &JoshutoDirEntry) -> Self {
+        if let LinkType::Symlink { .. } = entry.metadata.link_type() {
+            return Self::Link;
+        }
+        match entry.metadata.file_type() {
+            FileType::File => Self::File,
+            FileType::Directory => Self::Directory,
+            FileType::Link => Self::Link,
+            FileType::Socket => Self::Socket,
+            FileType::Block => Self::Block,
+            FileType::Character => Self::Character,
+            FileType::Pipe => Self::Pipe,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Comparison {
+    Size(Op, u64),
+    // how long ago the time was
+    Age(TimeField, Op, Duration),
+    Kind(Op, EntryKind),
+    User(Op, String),
+    Group(Op, String),
+    Perm(Op, mode_t),
+    Name(Op, String),
+    NameGlob(GlobMatcher),
+    Ext(Op, String),
+}
+
+impl Comparison {
+    fn eval(&self, entry: &JoshutoDirEntry, now: SystemTime) -> bool {
+        let metadata = &entry.metadata;
+        match self {
+            Self::Size(op, size) => {
+                let len = metadata.recursive_size().unwrap_or_else(|| metadata.len());
+                op.compare(len, *size)
+            }
+            Self::Age(field, op, age) => {
+                let time = match field {
+                    TimeField::Modified => metadata.modified(),
+                    TimeField::Accessed => metadata.accessed(),
+                    TimeField::Changed => metadata.changed(),
+                };
+                // times in the future are just now
+                let entry_age = now.duration_since(time).unwrap_or_default();
+                op.compare(entry_age, *age)
+            }
+            Self::Kind(op, kind) => (EntryKind::of(entry) == *kind) == (*op == Op::Eq),
+            Self::User(op, user) => {
+                let is_user = user == &metadata.uid.to_string()
+                    || unix::uid_to_string(metadata.uid).map_or(false, |name| &name == user);
+                is_user == (*op == Op::Eq)
+            }
+            Self::Group(op, group) => {
+                let is_group = group == &metadata.gid.to_string()
+                    || unix::gid_to_string(metadata.gid).map_or(false, |name| &name == group);
+                is_group == (*op == Op::Eq)
+            }
+            Self::Perm(op, bits) => {
+                let mode = metadata.mode.bits() & 0o7777;
+                match op {
+                    Op::BitAnd => mode & bits != 0,
+                    op => op.compare(mode, *bits),
+                }
+            }
+            Self::Name(op, name) => op.compare(entry.file_name(), name.as_str()),
+            Self::NameGlob(glob) => glob.is_match(entry.file_name()),
+            Self::Ext(op, ext) => op.compare(entry.ext().unwrap_or(""), ext.as_str()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+    Op(Op),
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let s = match self {
+            Self::Word(s) | Self::Quoted(s) => return write!(f, "{s}"),
+            Self::And => "&&",
+            Self::Or => "||",
+            Self::Not => "!",
+            Self::LParen => "(",
+            Self::RParen => ")",
+            Self::Op(Op::Eq) => "==",
+            Self::Op(Op::Ne) => "!=",
+            Self::Op(Op::Lt) => "<",
+            Self::Op(Op::Le) => "<=",
+            Self::Op(Op::Gt) => ">",
+            Self::Op(Op::Ge) => ">=",
+            Self::Op(Op::Glob) => "~",
+            Self::Op(Op::BitAnd) => "&",
+        };
+        write!(f, "{s}")
+    }
+}
+
+fn parse_error(cause: String) -> AppError {
+    AppError::new(AppErrorKind::InvalidParameters, cause)
+}
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !"()!=<>~&|'\"".contains(c)
+}
+
+fn tokenize(source: &str) -> AppResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '~' => Token::Op(Op::Glob),
+            '&' if chars.next_if_eq(&'&').is_some() => Token::And,
+            '&' => Token::Op(Op::BitAnd),
+            '|' if chars.next_if_eq(&'|').is_some() => Token::Or,
+            '!' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Ne),
+            '!' => Token::Not,
+            '=' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Eq),
+            '<' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Le),
+            '<' => Token::Op(Op::Lt),
+            '>' if chars.next_if_eq(&'=').is_some() => Token::Op(Op::Ge),
+            '>' => Token::Op(Op::Gt),
+            '\'' | '"' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some(ch) if ch == c => break,
+                        Some(ch) => s.push(ch),
+                        None => return Err(parse_error(format!("Missing closing {c}"))),
+                    }
+                }
+                Token::Quoted(s)
+            }
+            c if is_word_char(c) => {
+                let mut s = c.to_string();
+                while let Some(ch) = chars.next_if(|ch| is_word_char(*ch)) {
+                    s.push(ch);
+                }
+                Token::Word(s)
+            }
+            c => return Err(parse_error(format!("Unexpected '{c}'"))),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> AppResult<Node> {
+        let mut node = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            node = Node::Or(Box::new(node), Box::new(self.parse_and()?));
+        }
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> AppResult<Node> {
+        let mut node = self.parse_unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            node = Node::And(Box::new(node), Box::new(self.parse_unary()?));
+        }
+        Ok(node)
+    }
+
+    fn parse_unary(&mut self) -> AppResult<Node> {
+        match self.next() {
+            Some(Token::Not) => Ok(Node::Not(Box::new(self.parse_unary()?))),
+            Some(Token::LParen) => {
+                let node = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(node),
+                    _ => Err(parse_error("Missing closing )".to_string())),
+                }
+            }
+            Some(Token::Word(field)) => {
+                let op = match self.next() {
+                    Some(Token::Op(op)) => op,
+                    _ => return Err(parse_error(format!("Expected an operator after {field}"))),
+                };
+                let value = match self.next() {
+                    Some(Token::Word(value)) | Some(Token::Quoted(value)) => value,
+                    _ => return Err(parse_error(format!("Expected a value after {field}"))),
+                };
+                Ok(Node::Compare(parse_comparison(&field, op, &value)?))
+            }
+            Some(token) => Err(parse_error(format!("Unexpected '{token}'"))),
+            None => Err(parse_error("Unexpected end of expression".to_string())),
+        }
+    }
+}
+
+fn parse_comparison(field: &str, op: Op, value: &str) -> AppResult<Comparison> {
+    let unsupported = || parse_error(format!("Operator not supported for {field}"));
+    let time_field = match field {
+        "mtime" => Some(TimeField::Modified),
+        "atime" => Some(TimeField::Accessed),
+        "ctime" => Some(TimeField::Changed),
+        _ => None,
+    };
+    if let Some(time_field) = time_field {
+        if matches!(op, Op::Glob | Op::BitAnd) {
+            return Err(unsupported());
+        }
+        return Ok(Comparison::Age(time_field, op, parse_age(value)?));
+    }
+
+    match field {
+        "size" if !matches!(op, Op::Glob | Op::BitAnd) => {
+            Ok(Comparison::Size(op, parse_size(value)?))
+        }
+        "type" if op.is_equality() => Ok(Comparison::Kind(op, parse_kind(value)?)),
+        "user" if op.is_equality() => Ok(Comparison::User(op, value.to_string())),
+        "group" if op.is_equality() => Ok(Comparison::Group(op, value.to_string())),
+        "perm" if op.is_equality() || op == Op::BitAnd => {
+            let bits = mode_t::from_str_radix(value, 8)
+                .ok()
+                .filter(|bits| *bits <= 0o7777)
+                .ok_or_else(|| parse_error(format!("Invalid permissions '{value}'")))?;
+            Ok(Comparison::Perm(op, bits))
+        }
+        "name" if op == Op::Glob => {
+            let glob = GlobBuilder::new(value).build()?.compile_matcher();
+            Ok(Comparison::NameGlob(glob))
+        }
+        "name" if op != Op::BitAnd => Ok(Comparison::Name(op, value.to_string())),
+        "ext" if op.is_equality() => Ok(Comparison::Ext(op, value.to_string())),
+        "size" | "type" | "user" | "group" | "perm" | "name" | "ext" => Err(unsupported()),
+        _ => Err(parse_error(format!("Unknown field '{field}'"))),
+    }
+}
+
+/// Splits `10M` into `10` and `M`
+fn split_number(value: &str) -> (&str, &str) {
+    let end = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    value.split_at(end)
+}
+
+fn parse_size(value: &str) -> AppResult<u64> {
+    let invalid = || parse_error(format!("Invalid size '{value}'"));
+    let (number, unit) = split_number(value);
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    let unit = unit.trim_end_matches(['b', 'B']).to_uppercase();
+    let factor: u64 = match unit.as_str() {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        "T" => 1 << 40,
+        _ => return Err(invalid()),
+    };
+    Ok((number * factor as f64) as u64)
+}
+
+fn parse_age(value: &str) -> AppResult<Duration> {
+    let invalid = || parse_error(format!("Invalid time '{value}', expected e.g. 30m or 7d"));
+    let (number, unit) = split_number(value);
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    let secs: u64 = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        "y" => 365 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    Ok(Duration::from_secs_f64(number * secs as f64))
+}
+
+fn parse_kind(value: &str) -> AppResult<EntryKind> {
+    match value {
+        "file" => Ok(EntryKind::File),
+        "dir" | "directory" => Ok(EntryKind::Directory),
+        "link" | "symlink" => Ok(EntryKind::Link),
+        "socket" => Ok(EntryKind::Socket),
+        "block" => Ok(EntryKind::Block),
+        "char" => Ok(EntryKind::Character),
+        "pipe" | "fifo" => Ok(EntryKind::Pipe),
+        _ => Err(parse_error(format!("Unknown type '{value}'"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use filetime::FileTime;
+    use temp_dir::TempDir;
+
+    use crate::types::option::display::DisplayOption;
+
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        assert!(FilterExpr::parse("size > 10X").is_err());
+        assert!(FilterExpr::parse("mtime < 7").is_err());
+        assert!(FilterExpr::parse("colour == red").is_err());
+        assert!(FilterExpr::parse("type > file").is_err());
+        assert!(FilterExpr::parse("(size > 1").is_err());
+        assert!(FilterExpr::parse("size > 1 &&").is_err());
+        assert!(FilterExpr::parse("name == 'a").is_err());
+        assert!(FilterExpr::parse("perm & 999").is_err());
+        assert_eq!(
+            FilterExpr::parse(" size > 1K ").unwrap().to_string(),
+            "size > 1K"
+        );
+    }
+
+    #[test]
+    fn entries_are_matched_by_metadata() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("dir")).unwrap();
+        fs::write(root.join("big.txt"), vec![b'a'; 2048]).unwrap();
+        fs::write(root.join("small.rs"), "fn main() {}").unwrap();
+        fs::set_permissions(root.join("small.rs"), fs::Permissions::from_mode(0o755)).unwrap();
+        let week_ago = SystemTime::now() - Duration::from_secs(8 * 24 * 60 * 60);
+        filetime::set_file_mtime(root.join("big.txt"), FileTime::from_system_time(week_ago))
+            .unwrap();
+
+        let options = DisplayOption::default();
+        let entries: Vec<JoshutoDirEntry> = ["big.txt", "dir", "small.rs"]
+            .iter()
+            .map(|name| JoshutoDirEntry::from_path(&root.join(name), root, &options).unwrap())
+            .collect();
+        let matching = |source: &str| -> Vec<&str> {
+            let expr = FilterExpr::parse(source).unwrap();
+            entries
+                .iter()
+                .filter(|e| expr.is_match(e))
+                .map(|e| e.file_name())
+                .collect()
+        };
+
+        assert_eq!(matching("size > 1K && type == file"), ["big.txt"]);
+        assert_eq!(matching("mtime < 7d"), ["dir", "small.rs"]);
+        assert_eq!(matching("!(type == dir) && mtime >= 1w"), ["big.txt"]);
+        assert_eq!(
+            matching("ext == rs || name ~ 'b*'"),
+            ["big.txt", "small.rs"]
+        );
+        assert_eq!(matching("perm & 100 && type != dir"), ["small.rs"]);
+        assert_eq!(
+            matching("perm == 755 && name == \"small.rs\""),
+            ["small.rs"]
+        );
+    }
+}
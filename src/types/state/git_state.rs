@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::thread;
+
+use crate::fs::{read_git_status, GitDir, GitRepoStatus, GitStatus};
+use crate::types::event::AppEvent;
+
+/// Git status of the files of a directory
+#[derive(Clone, Copy, Debug)]
+pub struct GitDirStatus<'a> {
+    pub repo: &'a GitRepoStatus,
+    pub prefix: &'a Path,
+}
+
+impl<'a> GitDirStatus<'a> {
+    pub fn status(&self, file_name: &str) -> Option<GitStatus> {
+        self.repo.status(&self.prefix.join(file_name))
+    }
+
+    pub fn branch(&self) -> Option<&'a str> {
+        self.repo.branch.as_deref()
+    }
+}
+
+pub struct GitState {
+    // repository of each visited directory, `None` if it is not inside one
+    pub dirs: HashMap<PathBuf, Option<GitDir>>,
+    // status of each repository by its root
+    pub repos: HashMap<PathBuf, GitRepoStatus>,
+    // directories whose status is being read
+    pub pending: HashSet<PathBuf>,
+    // directories whose status has to be read again
+    pub outdated: HashSet<PathBuf>,
+    // for telling main thread when a status is ready
+    pub event_tx: Sender<AppEvent>,
+}
+
+impl GitState {
+    pub fn new(event_tx: Sender<AppEvent>) -> Self {
+        Self {
+            dirs: HashMap::new(),
+            repos: HashMap::new(),
+            pending: HashSet::new(),
+            outdated: HashSet::new(),
+            event_tx,
+        }
+    }
+
+    /// Reads the git status of a directory in the background, unless it is known already.
+    /// Does nothing if joshuto was built without the `git` feature.
+    pub fn update(&mut self, dir: &Path) {
+        if !cfg!(feature = "git") {
+            return;
+        }
+        let is_known = self.dirs.contains_key(dir) && !self.outdated.contains(dir);
+        if is_known || self.pending.contains(dir) {
+            return;
+        }
+        self.pending.insert(dir.to_path_buf());
+
+        let dir = dir.to_path_buf();
+        let event_tx = self.event_tx.clone();
+        let _ = thread::spawn(move || {
+            let res = read_git_status(&dir).map(Box::new);
+            let _ = event_tx.send(AppEvent::GitStatus { dir, res });
+        });
+    }
+
+    pub fn insert(&mut self, dir: PathBuf, res: Option<(GitDir, GitRepoStatus)>) {
+        self.pending.remove(&dir);
+        self.outdated.remove(&dir);
+        match res {
+            Some((git_dir, repo_status)) => {
+                self.repos.insert(git_dir.root.clone(), repo_status);
+                self.dirs.insert(dir, Some(git_dir));
+            }
+            None => {
+                self.dirs.insert(dir, None);
+            }
+        }
+    }
+
+    /// Marks the repositories containing any of the paths as outdated.
+    /// The old status is kept until the new one is read.
+    pub fn invalidate(&mut self, paths: &[PathBuf]) {
+        let is_affected = |dir: &Path| paths.iter().any(|path| path.starts_with(dir));
+        let roots: HashSet<PathBuf> = self
+            .dirs
+            .iter()
+            .filter_map(|(dir, git_dir)| match git_dir {
+                Some(git_dir) if is_affected(dir) || is_affected(&git_dir.root) => {
+                    Some(git_dir.root.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        let outdated = self.dirs.iter().filter_map(|(dir, git_dir)| match git_dir {
+            Some(git_dir) if roots.contains(&git_dir.root) => Some(dir.clone()),
+            // new repositories might have been created
+            None if is_affected(dir) => Some(dir.clone()),
+            _ => None,
+        });
+        self.outdated.extend(outdated);
+    }
+
+    pub fn dir_status(&self, dir: &Path) -> Option<GitDirStatus<'_>> {
+        let git_dir = self.dirs.get(dir)?.as_ref()?;
+        let repo = self.repos.get(&git_dir.root)?;
+        Some(GitDirStatus {
+            repo,
+            prefix: git_dir.prefix.as_path(),
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use crate::types::command::Command;
+
+#[derive(Clone, Debug, Default)]
+pub struct MacroState {
+    // register and commands of the macro currently being recorded
+    recording: Option<(char, Vec<Command>)>,
+    // number of macros currently being played back, macros can play other macros
+    playback_depth: usize,
+}
+
+impl MacroState {
+    pub fn recording_register(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    pub fn start_recording(&mut self, register: char) {
+        self.recording = Some((register, Vec::new()));
+    }
+
+    pub fn stop_recording(&mut self) -> Option<(char, Vec<Command>)> {
+        self.recording.take()
+    }
+
+    /// Adds an executed command to the macro being recorded.
+    /// Commands executed by a macro playback are not recorded, only the playback itself.
+    pub fn record(&mut self, command: &Command) {
+        if self.playback_depth > 0 {
+            return;
+        }
+        if let Some((_, commands)) = self.recording.as_mut() {
+            match command {
+                // prompts, the commands they result in are recorded instead
+                Command::CommandLine { .. }
+                | Command::NumberedCommand { .. }
+                | Command::ContextMenu => {}
+                Command::MacroRecord { .. } | Command::MacroStop => {}
+                // the commands they run are recorded instead
+                Command::Sequence { .. } | Command::Expand { .. } | Command::Script { .. } => {}
+                command => commands.push(command.clone()),
+            }
+        }
+    }
+
+    pub fn playback_depth(&self) -> usize {
+        self.playback_depth
+    }
+
+    pub fn enter_playback(&mut self) {
+        self.playback_depth += 1;
+    }
+
+    pub fn leave_playback(&mut self) {
+        self.playback_depth = self.playback_depth.saturating_sub(1);
+    }
+}
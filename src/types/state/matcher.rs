@@ -3,7 +3,9 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 use globset::{GlobBuilder, GlobMatcher};
 use regex::{Regex, RegexBuilder};
 
-use crate::{error::AppResult, types::option::search::CaseSensitivity};
+use crate::{error::AppResult, fs::JoshutoDirEntry, types::option::search::CaseSensitivity};
+
+use super::FilterExpr;
 
 #[derive(Clone, Debug, Default)]
 pub enum MatchState {
@@ -13,6 +15,7 @@ pub enum MatchState {
         pattern: String,
         actual_case_sensitivity: CaseSensitivity,
     },
+    Expr(FilterExpr),
     #[default]
     None,
 }
@@ -92,6 +95,12 @@ impl MatchState {
         }
     }
 
+    pub fn new_expr(expr: &str) -> AppResult<Self> {
+        Ok(Self::Expr(FilterExpr::parse(expr)?))
+    }
+
+    /// Whether `main` matches. Expressions need the metadata of an entry
+    /// and match every name, see `is_match_entry`.
     pub fn is_match(&self, main: &str) -> bool {
         match self {
             Self::Glob(glob_matcher) => Self::is_match_glob(main, glob_matcher),
@@ -100,7 +109,15 @@ impl MatchState {
                 pattern,
                 actual_case_sensitivity,
             } => Self::is_match_string(main, pattern, *actual_case_sensitivity),
-            Self::None => true,
+            Self::Expr(_) | Self::None => true,
+        }
+    }
+
+    /// Whether `entry` matches, by its metadata for expressions and by its name otherwise
+    pub fn is_match_entry(&self, entry: &JoshutoDirEntry) -> bool {
+        match self {
+            Self::Expr(expr) => expr.is_match(entry),
+            _ => self.is_match(entry.file_name()),
         }
     }
 
@@ -138,6 +155,7 @@ impl Display for MatchState {
             Self::Glob(glob_matcher) => write!(f, "{}", glob_matcher.glob().glob()),
             Self::Regex(regex) => write!(f, "{}", regex.as_str()),
             Self::String { pattern, .. } => write!(f, "{pattern}"),
+            Self::Expr(expr) => write!(f, "{expr}"),
             Self::None => Ok(()),
         }
     }
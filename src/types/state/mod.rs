@@ -1,9 +1,14 @@
 mod app_state;
 mod commandline_state;
+mod dir_size_state;
 mod file_manager_state;
+mod filter_expr;
+mod git_state;
 mod local_state;
+mod macro_state;
 mod matcher;
 mod message_queue;
+mod mouse_state;
 mod preview_state;
 mod tab_state;
 mod thread_pool;
@@ -12,10 +17,15 @@ mod worker_state;
 
 pub use app_state::*;
 pub use commandline_state::*;
+pub use dir_size_state::*;
 pub use file_manager_state::*;
+pub use filter_expr::*;
+pub use git_state::*;
 pub use local_state::*;
+pub use macro_state::*;
 pub use matcher::*;
 pub use message_queue::*;
+pub use mouse_state::*;
 pub use preview_state::*;
 pub use tab_state::*;
 pub use thread_pool::*;
@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+// a second click on the same entry within this time opens it
+const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(400);
+
+/// State that spans several mouse events
+#[derive(Clone, Debug, Default)]
+pub struct MouseState {
+    // the entry clicked last and when, to recognize double clicks
+    last_click: Option<(PathBuf, Instant)>,
+    // whether the left button went down on an entry of the current view and is still held
+    pub pressed: bool,
+    // whether dragging since then started visual mode
+    pub dragging: bool,
+}
+
+impl MouseState {
+    /// Registers a click on `path`, returns whether it completes a double click
+    pub fn click(&mut self, path: &Path) -> bool {
+        let now = Instant::now();
+        match self.last_click.take() {
+            Some((last_path, time))
+                if last_path == path && now.duration_since(time) <= DOUBLE_CLICK_TIME =>
+            {
+                true
+            }
+            _ => {
+                self.last_click = Some((path.to_path_buf(), now));
+                false
+            }
+        }
+    }
+}
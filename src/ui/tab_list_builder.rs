@@ -57,13 +57,11 @@ impl TabLabel {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 enum TabBarElement {
     // Note: The Tab-Elements also store the index, eventhough it's not used
-    // for the tab-bar rendering at all. The reason is that this would allow
-    // an easier implementation for other features later on, like changing
-    // tabs by mouse clicks.
-    // It's a valuable information that comes for free here.
+    // for the tab-bar rendering at all. It's used to find the tab under a
+    // mouse click, see `tab_index_at`.
     DividerII,
     DividerIA,
     DividerAI,
@@ -513,6 +511,109 @@ pub fn factor_tab_bar_spans<'a>(
     tab_bar
 }
 
+/// The index of the tab shown at column `x` of the tab bar.
+/// The scroll tags stand for the tab hidden next to the shown ones.
+pub fn tab_index_at(
+    available_width: usize,
+    tab_paths: &[&Path],
+    current_index: usize,
+    config: &TabTheme,
+    x: usize,
+) -> Option<usize> {
+    let reps: Vec<TabLabel> = tab_paths.iter().map(|p| TabLabel::from_path(p)).collect();
+    let rep_refs: Vec<&TabLabel> = reps.iter().collect();
+    let tab_bar_elements =
+        factor_tab_bar_sequence(available_width, &rep_refs, current_index, config);
+    element_tab_index_at(tab_bar_elements, config, x)
+}
+
+fn element_tab_index_at(
+    tab_bar_elements: Vec<TabBarElement>,
+    config: &TabTheme,
+    x: usize,
+) -> Option<usize> {
+    let spans = factor_tab_bar_spans_from_sequence(tab_bar_elements.clone(), config);
+    let mut end = 0;
+    let hit = spans.iter().position(|span| {
+        end += span.width();
+        x < end
+    })?;
+
+    let tab_index = |element: Option<&TabBarElement>| match element {
+        Some(TabBarElement::TabA(ix, _)) | Some(TabBarElement::TabI(ix, _)) => Some(*ix),
+        _ => None,
+    };
+    let mut shown_tabs = tab_bar_elements.iter().filter_map(|e| tab_index(Some(e)));
+    match &tab_bar_elements[hit] {
+        TabBarElement::PrefixA | TabBarElement::PrefixI => tab_index(tab_bar_elements.get(hit + 1)),
+        TabBarElement::PostfixA | TabBarElement::PostfixI => {
+            tab_index(hit.checked_sub(1).and_then(|i| tab_bar_elements.get(i)))
+        }
+        TabBarElement::ScrollFrontPrefix
+        | TabBarElement::ScrollFront(_)
+        | TabBarElement::ScrollFrontPostfix => shown_tabs.next()?.checked_sub(1),
+        TabBarElement::ScrollBackPrefix
+        | TabBarElement::ScrollBack(_)
+        | TabBarElement::ScrollBackPostfix => {
+            let hidden = tab_bar_elements.iter().find_map(|e| match e {
+                TabBarElement::ScrollBack(n) => Some(*n),
+                _ => None,
+            })?;
+            if hidden > 0 {
+                shown_tabs.next_back().map(|ix| ix + 1)
+            } else {
+                None
+            }
+        }
+        element => tab_index(Some(element)),
+    }
+}
+
+#[cfg(test)]
+mod tests_tab_index_at {
+    use crate::config::theme::{tab::TabTheme, tab_raw::TabThemeRaw};
+
+    use super::{element_tab_index_at, TabBarElement};
+
+    #[test]
+    /// Tabs are hit on their label and on their pre- and postfix,
+    /// the scroll tags lead to the hidden tabs.
+    fn clicks_hit_the_tab_under_them() {
+        let config = TabTheme::from(TabThemeRaw::default());
+        let elements = vec![
+            TabBarElement::ScrollFrontPrefix,
+            TabBarElement::ScrollFront(1),
+            TabBarElement::ScrollFrontPostfix,
+            TabBarElement::PrefixI,
+            TabBarElement::TabI(1, "2: b".to_string()),
+            TabBarElement::PostfixI,
+            TabBarElement::DividerIA,
+            TabBarElement::PrefixA,
+            TabBarElement::TabA(2, "3: c".to_string()),
+            TabBarElement::PostfixA,
+            TabBarElement::ScrollBackPrefix,
+            TabBarElement::ScrollBack(0),
+            TabBarElement::ScrollBackPostfix,
+        ];
+        let front_width: usize =
+            super::factor_tab_bar_spans_from_sequence(elements[..3].to_vec(), &config)
+                .iter()
+                .map(|span| span.width())
+                .sum();
+        let hit = |x| element_tab_index_at(elements.clone(), &config, x);
+
+        assert_eq!(hit(0), Some(0));
+        assert_eq!(hit(front_width), Some(1));
+        assert_eq!(hit(front_width + 2), Some(1));
+        assert_eq!(hit(front_width + 5), Some(1));
+        assert_eq!(hit(front_width + 7), Some(2));
+        assert_eq!(hit(front_width + 12), Some(2));
+        // nothing is hidden on the right side
+        assert_eq!(hit(front_width + 13), None);
+        assert_eq!(hit(1000), None);
+    }
+}
+
 #[cfg(test)]
 mod tests_facator_tab_bar_sequence {
     use crate::config::theme::{tab::TabTheme, tab_raw::TabThemeRaw};
@@ -1,14 +1,24 @@
 mod tui_command_menu;
+mod tui_disk_usage_view;
+mod tui_dual_pane_view;
 mod tui_folder_view;
+mod tui_fuzzy_picker_view;
+mod tui_grep_view;
 mod tui_hsplit_view;
 mod tui_minimal_view;
+mod tui_properties_view;
 mod tui_textfield;
 mod tui_view;
 mod tui_worker_view;
 
 pub use self::tui_command_menu::*;
+pub use self::tui_disk_usage_view::*;
+pub use self::tui_dual_pane_view::*;
 pub use self::tui_folder_view::*;
+pub use self::tui_fuzzy_picker_view::*;
+pub use self::tui_grep_view::*;
 pub use self::tui_hsplit_view::*;
+pub use self::tui_properties_view::*;
 pub use self::tui_textfield::*;
 pub use self::tui_view::*;
 pub use self::tui_worker_view::*;
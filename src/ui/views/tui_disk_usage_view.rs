@@ -0,0 +1,85 @@
+use std::path::Path;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+
+use crate::fs::DiskUsageNode;
+use crate::types::state::AppState;
+use crate::ui::widgets::{TuiDiskUsage, TuiTopBar};
+use crate::utils::format;
+
+pub struct TuiDiskUsageView<'a> {
+    app_state: &'a AppState,
+    path: &'a Path,
+    dir: Option<&'a DiskUsageNode>,
+    cursor: usize,
+    status: &'a str,
+}
+
+impl<'a> TuiDiskUsageView<'a> {
+    pub fn new(
+        app_state: &'a AppState,
+        path: &'a Path,
+        dir: Option<&'a DiskUsageNode>,
+        cursor: usize,
+        status: &'a str,
+    ) -> Self {
+        Self {
+            app_state,
+            path,
+            dir,
+            cursor,
+            status,
+        }
+    }
+}
+
+impl Widget for TuiDiskUsageView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height < 4 {
+            return;
+        }
+
+        let rect = Rect { height: 1, ..area };
+        TuiTopBar::new(self.app_state).render(rect, buf);
+
+        let header_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let header = match self.dir {
+            Some(dir) => format!(
+                "{}  {}  {} items",
+                self.path.to_string_lossy(),
+                format::file_size_to_string(dir.size),
+                dir.children.len()
+            ),
+            None => self.path.to_string_lossy().to_string(),
+        };
+        buf.set_stringn(
+            area.x,
+            area.y + 1,
+            header,
+            area.width as usize,
+            header_style,
+        );
+
+        if let Some(dir) = self.dir {
+            let rect = Rect {
+                y: area.y + 2,
+                height: area.height - 3,
+                ..area
+            };
+            TuiDiskUsage::new(dir, self.cursor).render(rect, buf);
+        }
+
+        buf.set_stringn(
+            area.x,
+            area.y + area.height - 1,
+            self.status,
+            area.width as usize,
+            Style::default(),
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Borders, Paragraph, Widget, Wrap};
+
+use crate::commands::tab_ops;
+use crate::tab::JoshutoTab;
+use crate::types::state::AppState;
+use crate::ui::widgets::{TuiDirListDetailed, TuiFooter, TuiTopBar};
+
+/// Two directory panes side by side, each showing a tab of a pair of tabs
+pub struct TuiDualPaneView<'a> {
+    pub app_state: &'a AppState,
+    pub show_bottom_status: bool,
+}
+
+impl<'a> TuiDualPaneView<'a> {
+    pub fn new(app_state: &'a AppState) -> Self {
+        Self {
+            app_state,
+            show_bottom_status: true,
+        }
+    }
+
+    pub fn header_area(area: &Rect) -> Rect {
+        Rect { height: 1, ..*area }
+    }
+
+    pub fn footer_area(area: &Rect) -> Rect {
+        Rect {
+            y: area.bottom() - 1,
+            height: 1,
+            ..*area
+        }
+    }
+
+    fn render_pane(&self, tab: &JoshutoTab, focused: bool, area: Rect, buf: &mut Buffer) {
+        let title_style = if focused {
+            Style::default()
+                .fg(Color::LightBlue)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let block = Block::default().borders(Borders::ALL).title(Span::styled(
+            format!(" {} ", tab.get_cwd().to_string_lossy()),
+            title_style,
+        ));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if let Some(list) = tab.curr_list_ref() {
+            TuiDirListDetailed::new(
+                &self.app_state.config,
+                list,
+                &self.app_state.config.display_options,
+                tab.option_ref(),
+                focused,
+            )
+            .git(
+                self.app_state
+                    .state
+                    .git_state_ref()
+                    .dir_status(list.file_path()),
+            )
+            .render(inner, buf);
+        }
+    }
+}
+
+impl Widget for TuiDualPaneView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height < 4 {
+            return;
+        }
+        let tab_state = self.app_state.state.tab_state_ref();
+
+        TuiTopBar::new(self.app_state).render(Self::header_area(&area), buf);
+
+        let panes_area = Rect {
+            y: area.top() + 1,
+            height: area.height - 2,
+            ..area
+        };
+        let layout_rect = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+            .split(panes_area);
+
+        // the left pane shows the tab with the even index of the pair
+        let tab_index = tab_state.index;
+        let other_tab_index = tab_ops::pane_partner_index(tab_index);
+        for (index, focused) in [(tab_index, true), (other_tab_index, false)] {
+            let tab = tab_state
+                .tab_order
+                .get(index)
+                .and_then(|id| tab_state.tab_ref(id));
+            if let Some(tab) = tab {
+                self.render_pane(tab, focused, layout_rect[index % 2], buf);
+            }
+        }
+
+        if !self.show_bottom_status {
+            return;
+        }
+        let curr_tab = tab_state.curr_tab_ref();
+        let rect = Self::footer_area(&area);
+        if let Some(msg) = self.app_state.state.worker_state_ref().get_msg() {
+            let message_style = Style::default().fg(Color::Yellow);
+            let text = Span::styled(msg, message_style);
+            Paragraph::new(text)
+                .wrap(Wrap { trim: true })
+                .render(rect, buf);
+        } else if let Some(msg) = self.app_state.state.message_queue_ref().current_message() {
+            let text = Span::styled(msg.content.as_str(), msg.style);
+            Paragraph::new(text)
+                .wrap(Wrap { trim: true })
+                .render(rect, buf);
+        } else if let Some(list) = curr_tab.curr_list_ref() {
+            TuiFooter::new(list, curr_tab.option_ref())
+                .mode(self.app_state.state.mode_stack.last().map(|s| s.as_str()))
+                .dir_view(
+                    self.app_state
+                        .config
+                        .display_options
+                        .dir_views
+                        .get(list.file_path()),
+                )
+                .branch(
+                    self.app_state
+                        .state
+                        .git_state_ref()
+                        .dir_status(list.file_path())
+                        .and_then(|git| git.branch()),
+                )
+                .dir_sizes(self.app_state.state.dir_size_state_ref().status())
+                .render(rect, buf);
+        }
+    }
+}
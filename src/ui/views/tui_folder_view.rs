@@ -157,6 +157,12 @@ impl Widget for TuiFolderView<'_> {
                 curr_tab.option_ref(),
                 true,
             )
+            .git(
+                self.app_state
+                    .state
+                    .git_state_ref()
+                    .dir_status(list.file_path()),
+            )
             .render(layout_rect[1], buf);
 
             let footer_area = Self::footer_area(&area);
@@ -175,7 +181,24 @@ impl Widget for TuiFolderView<'_> {
                         .wrap(Wrap { trim: true })
                         .render(footer_area, buf);
                 } else {
-                    TuiFooter::new(list, curr_tab.option_ref()).render(footer_area, buf);
+                    TuiFooter::new(list, curr_tab.option_ref())
+                        .mode(self.app_state.state.mode_stack.last().map(|s| s.as_str()))
+                        .dir_view(
+                            self.app_state
+                                .config
+                                .display_options
+                                .dir_views
+                                .get(list.file_path()),
+                        )
+                        .branch(
+                            self.app_state
+                                .state
+                                .git_state_ref()
+                                .dir_status(list.file_path())
+                                .and_then(|git| git.branch()),
+                        )
+                        .dir_sizes(self.app_state.state.dir_size_state_ref().status())
+                        .render(footer_area, buf);
                 }
             }
         } else {
@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{Clear, Widget};
+
+use crate::types::state::AppState;
+use crate::ui::views::TuiView;
+use crate::ui::widgets::TuiFuzzyPicker;
+use crate::utils::fuzzy::FuzzyMatch;
+
+/// Fuzzy picker drawn over the folder view
+pub struct TuiFuzzyPickerView<'a> {
+    app_state: &'a AppState,
+    picker: TuiFuzzyPicker<'a>,
+}
+
+impl<'a> TuiFuzzyPickerView<'a> {
+    pub fn new(
+        app_state: &'a AppState,
+        title: &'a str,
+        query: &'a str,
+        items: &'a [String],
+        matches: &'a [(usize, FuzzyMatch)],
+        (cursor, marked): (usize, &'a HashSet<usize>),
+    ) -> Self {
+        Self {
+            app_state,
+            picker: TuiFuzzyPicker::new(title, query, items, matches, cursor, marked),
+        }
+    }
+}
+
+impl Widget for TuiFuzzyPickerView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        TuiView::new(self.app_state).render(area, buf);
+
+        // centered, covering most of the screen
+        let width = (area.width * 4 / 5).max(area.width.min(40));
+        let height = (area.height * 7 / 10).max(area.height.min(8));
+        let rect = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        Clear.render(rect, buf);
+        self.picker.render(rect, buf);
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+
+use crate::fs::GrepMatch;
+use crate::types::state::AppState;
+use crate::ui::widgets::{TuiGrepResults, TuiTopBar};
+
+pub struct TuiGrepView<'a> {
+    app_state: &'a AppState,
+    root: &'a Path,
+    pattern: &'a str,
+    matches: &'a [GrepMatch],
+    cursor: usize,
+    status: &'a str,
+}
+
+impl<'a> TuiGrepView<'a> {
+    pub fn new(
+        app_state: &'a AppState,
+        root: &'a Path,
+        pattern: &'a str,
+        matches: &'a [GrepMatch],
+        cursor: usize,
+        status: &'a str,
+    ) -> Self {
+        Self {
+            app_state,
+            root,
+            pattern,
+            matches,
+            cursor,
+            status,
+        }
+    }
+}
+
+impl Widget for TuiGrepView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height < 4 {
+            return;
+        }
+
+        let rect = Rect { height: 1, ..area };
+        TuiTopBar::new(self.app_state).render(rect, buf);
+
+        let header_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let header = format!("grep {} in {}", self.pattern, self.root.to_string_lossy());
+        buf.set_stringn(
+            area.x,
+            area.y + 1,
+            header,
+            area.width as usize,
+            header_style,
+        );
+
+        let rect = Rect {
+            y: area.y + 2,
+            height: area.height - 3,
+            ..area
+        };
+        TuiGrepResults::new(self.matches, self.cursor).render(rect, buf);
+
+        buf.set_stringn(
+            area.x,
+            area.y + area.height - 1,
+            self.status,
+            area.width as usize,
+            Style::default(),
+        );
+    }
+}
@@ -19,6 +19,18 @@ impl<'a> TuiHSplitView<'a> {
             show_bottom_status: true,
         }
     }
+
+    pub fn header_area(area: &Rect) -> Rect {
+        Rect { height: 1, ..*area }
+    }
+
+    pub fn footer_area(area: &Rect) -> Rect {
+        Rect {
+            y: area.bottom() - 1,
+            height: 1,
+            ..*area
+        }
+    }
 }
 
 impl Widget for TuiHSplitView<'_> {
@@ -82,13 +94,14 @@ impl Widget for TuiHSplitView<'_> {
                     curr_tab.option_ref(),
                     true,
                 )
+                .git(
+                    self.app_state
+                        .state
+                        .git_state_ref()
+                        .dir_status(list.file_path()),
+                )
                 .render(layout_rect, buf);
-                let rect = Rect {
-                    x: 0,
-                    y: area.height - 1,
-                    width: area.width,
-                    height: 1,
-                };
+                let rect = Self::footer_area(&area);
 
                 if self.show_bottom_status {
                     /* draw the bottom status bar */
@@ -106,19 +119,29 @@ impl Widget for TuiHSplitView<'_> {
                             .wrap(Wrap { trim: true })
                             .render(rect, buf);
                     } else {
-                        TuiFooter::new(list, curr_tab.option_ref()).render(rect, buf);
+                        TuiFooter::new(list, curr_tab.option_ref())
+                            .mode(self.app_state.state.mode_stack.last().map(|s| s.as_str()))
+                            .dir_view(
+                                self.app_state
+                                    .config
+                                    .display_options
+                                    .dir_views
+                                    .get(list.file_path()),
+                            )
+                            .branch(
+                                self.app_state
+                                    .state
+                                    .git_state_ref()
+                                    .dir_status(list.file_path())
+                                    .and_then(|git| git.branch()),
+                            )
+                            .dir_sizes(self.app_state.state.dir_size_state_ref().status())
+                            .render(rect, buf);
                     }
                 }
             }
 
-            let topbar_width = area.width;
-            let rect = Rect {
-                x: 0,
-                y: 0,
-                width: topbar_width,
-                height: 1,
-            };
-            TuiTopBar::new(self.app_state).render(rect, buf);
+            TuiTopBar::new(self.app_state).render(Self::header_area(&area), buf);
         }
 
         let other_tab_index = if tab_index % 2 == 0 {
@@ -146,6 +169,12 @@ impl Widget for TuiHSplitView<'_> {
                         curr_tab.option_ref(),
                         false,
                     )
+                    .git(
+                        self.app_state
+                            .state
+                            .git_state_ref()
+                            .dir_status(list.file_path()),
+                    )
                     .render(layout_rect, buf);
                 }
             }
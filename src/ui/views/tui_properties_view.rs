@@ -0,0 +1,289 @@
+use std::os::unix::fs::MetadataExt;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+use crate::commands::properties::{shared_value, PropertiesField, MODE_BITS, MTIME_FORMAT};
+use crate::fs::FileProperties;
+use crate::types::state::AppState;
+use crate::ui::widgets::TuiTopBar;
+use crate::utils::{format, unix};
+
+const LABEL_WIDTH: usize = 10;
+const MODE_ROW_NAMES: [&str; 4] = ["owner", "group", "other", "special"];
+const MODE_COL_NAMES: [[&str; 3]; 4] = [
+    ["read", "write", "execute"],
+    ["read", "write", "execute"],
+    ["read", "write", "execute"],
+    ["setuid", "setgid", "sticky"],
+];
+
+/// The full stat of a file or the properties shared by the selected files
+pub struct TuiPropertiesView<'a> {
+    app_state: &'a AppState,
+    entries: &'a [FileProperties],
+    field: PropertiesField,
+    input: Option<&'a str>,
+    status: &'a str,
+}
+
+impl<'a> TuiPropertiesView<'a> {
+    pub fn new(
+        app_state: &'a AppState,
+        entries: &'a [FileProperties],
+        field: PropertiesField,
+        input: Option<&'a str>,
+        status: &'a str,
+    ) -> Self {
+        Self {
+            app_state,
+            entries,
+            field,
+            input,
+            status,
+        }
+    }
+
+    fn label_line(label: &str, value: String) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(
+                format!("{:<width$}", label, width = LABEL_WIDTH),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(value),
+        ])
+    }
+
+    // an editable field, showing the input while it is being edited
+    fn field_line(&self, label: &str, field: PropertiesField, value: String) -> Line<'static> {
+        let mut line = Self::label_line(label, String::new());
+        if self.field != field {
+            line.spans.push(Span::raw(value));
+            return line;
+        }
+        match self.input {
+            Some(input) => {
+                line.spans.push(Span::raw(input.to_string()));
+                line.spans.push(Span::styled(
+                    " ",
+                    Style::default().add_modifier(Modifier::REVERSED),
+                ));
+            }
+            None => line.spans.push(Span::styled(
+                value,
+                Style::default().add_modifier(Modifier::REVERSED),
+            )),
+        }
+        line
+    }
+
+    fn lines(&self) -> Vec<Line<'static>> {
+        let entries = self.entries;
+        let mut lines = Vec::new();
+
+        let total_size = entries
+            .iter()
+            .map(|e| e.size())
+            .try_fold(0, |total, size| size.map(|s| total + s));
+        let size = match total_size {
+            Some(size) => format!(
+                "{} ({} bytes)",
+                format::file_size_to_string(size).trim_start(),
+                size
+            ),
+            None => "computing...".to_string(),
+        };
+
+        let owner = shared_value(entries, |e| e.uid())
+            .map(|uid| match unix::uid_to_string(uid) {
+                Some(name) => format!("{} ({})", name, uid),
+                None => uid.to_string(),
+            })
+            .unwrap_or_else(|| "(mixed)".to_string());
+        let group = shared_value(entries, |e| e.gid())
+            .map(|gid| match unix::gid_to_string(gid) {
+                Some(name) => format!("{} ({})", name, gid),
+                None => gid.to_string(),
+            })
+            .unwrap_or_else(|| "(mixed)".to_string());
+        let modified = shared_value(entries, |e| e.modified())
+            .map(|time| format::time_to_string_with_format(time, MTIME_FORMAT))
+            .unwrap_or_else(|| "(mixed)".to_string());
+
+        match entries {
+            [entry] => {
+                let metadata = &entry.metadata;
+                let file_type = match entry.mimetype.as_ref() {
+                    Some(mimetype) => format!("{}, {}", entry.file_type_str(), mimetype),
+                    None => entry.file_type_str().to_string(),
+                };
+                lines.push(Self::label_line("Type", file_type));
+                lines.push(Self::label_line("Size", size));
+                lines.push(Self::label_line(
+                    "Inode",
+                    format!(
+                        "{}   Device {}   Links {}",
+                        metadata.ino(),
+                        device_string(metadata.dev()),
+                        metadata.nlink()
+                    ),
+                ));
+                lines.push(Self::label_line(
+                    "Blocks",
+                    format!("{} (IO block {})", metadata.blocks(), metadata.blksize()),
+                ));
+                if !entry.link_chain.is_empty() {
+                    let chain: Vec<String> = entry
+                        .link_chain
+                        .iter()
+                        .map(|p| format!("-> {}", p.to_string_lossy()))
+                        .collect();
+                    lines.push(Self::label_line("Link", chain.join(" ")));
+                }
+            }
+            _ => {
+                lines.push(Self::label_line(
+                    "Selected",
+                    format!("{} files", entries.len()),
+                ));
+                lines.push(Self::label_line("Size", size));
+            }
+        }
+
+        lines.push(self.field_line("Owner", PropertiesField::Owner, owner));
+        lines.push(self.field_line("Group", PropertiesField::Group, group));
+        lines.push(self.field_line("Modified", PropertiesField::Modified, modified));
+
+        if let [entry] = entries {
+            let metadata = &entry.metadata;
+            let accessed = metadata
+                .accessed()
+                .map(|time| format::time_to_string_with_format(time, MTIME_FORMAT))
+                .unwrap_or_default();
+            let created = metadata
+                .created()
+                .map(|time| format::time_to_string_with_format(time, MTIME_FORMAT))
+                .unwrap_or_else(|_| "not supported".to_string());
+            lines.push(Self::label_line("Accessed", accessed));
+            lines.push(Self::label_line(
+                "Changed",
+                format::time_to_string_with_format(entry.changed(), MTIME_FORMAT),
+            ));
+            lines.push(Self::label_line("Created", created));
+            if !entry.acl.is_empty() {
+                lines.push(Self::label_line("ACL", entry.acl.join(" ")));
+            }
+            if !entry.default_acl.is_empty() {
+                lines.push(Self::label_line("Default", entry.default_acl.join(" ")));
+            }
+            for (i, (name, value)) in entry.xattrs.iter().enumerate() {
+                let label = if i == 0 { "Xattrs" } else { "" };
+                lines.push(Self::label_line(label, format!("{} = {}", name, value)));
+            }
+        }
+
+        let mode = shared_value(entries, |e| e.mode().bits() & 0o7777)
+            .map(|bits| format!("{:04o}", bits))
+            .unwrap_or_else(|| "(mixed)".to_string());
+        lines.push(Self::label_line("Mode", mode));
+        for (row, bits) in MODE_BITS.iter().enumerate() {
+            let mut line = Self::label_line("", format!("{:<9}", MODE_ROW_NAMES[row]));
+            for (col, bit) in bits.iter().enumerate() {
+                let set = entries.iter().filter(|e| e.mode().contains(*bit)).count();
+                let checkbox = if set == 0 {
+                    "[ ]"
+                } else if set == entries.len() {
+                    "[x]"
+                } else {
+                    "[-]"
+                };
+                let style = if self.field == (PropertiesField::Mode { row, col }) {
+                    Style::default().add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::default()
+                };
+                line.spans.push(Span::styled(
+                    format!("{} {:<8}", checkbox, MODE_COL_NAMES[row][col]),
+                    style,
+                ));
+                line.spans.push(Span::raw(" "));
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    // the line of the field under the cursor, to keep it in view
+    fn field_line_index(&self, lines: &[Line]) -> usize {
+        let label = match self.field {
+            PropertiesField::Owner => "Owner",
+            PropertiesField::Group => "Group",
+            PropertiesField::Modified => "Modified",
+            PropertiesField::Mode { row, .. } => return lines.len() - MODE_BITS.len() + row,
+        };
+        lines
+            .iter()
+            .position(|line| {
+                line.spans
+                    .first()
+                    .map_or(false, |span| span.content.trim_end() == label)
+            })
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn device_string(dev: u64) -> String {
+    use nix::sys::stat::{major, minor};
+
+    format!("{}:{}", major(dev), minor(dev))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn device_string(dev: u64) -> String {
+    dev.to_string()
+}
+
+impl Widget for TuiPropertiesView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height < 4 {
+            return;
+        }
+
+        let rect = Rect { height: 1, ..area };
+        TuiTopBar::new(self.app_state).render(rect, buf);
+
+        let header_style = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let header = match self.entries {
+            [entry] => entry.path.to_string_lossy().to_string(),
+            _ => "Properties".to_string(),
+        };
+        buf.set_stringn(
+            area.x,
+            area.y + 1,
+            header,
+            area.width as usize,
+            header_style,
+        );
+
+        let lines = self.lines();
+        let height = (area.height - 3) as usize;
+        let skip = (self.field_line_index(&lines) + 1).saturating_sub(height);
+        for (i, line) in lines.iter().skip(skip).take(height).enumerate() {
+            buf.set_line(area.x, area.y + 2 + i as u16, line, area.width);
+        }
+
+        buf.set_stringn(
+            area.x,
+            area.y + area.height - 1,
+            self.status,
+            area.width as usize,
+            Style::default(),
+        );
+    }
+}
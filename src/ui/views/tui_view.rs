@@ -4,7 +4,9 @@ use ratatui::widgets::Widget;
 
 use crate::types::option::display::DisplayMode;
 use crate::types::state::AppState;
-use crate::ui::views::{tui_minimal_view::TuiMinimalView, TuiFolderView, TuiHSplitView};
+use crate::ui::views::{
+    tui_minimal_view::TuiMinimalView, TuiDualPaneView, TuiFolderView, TuiHSplitView,
+};
 
 pub struct TuiView<'a> {
     pub app_state: &'a AppState,
@@ -18,6 +20,26 @@ impl<'a> TuiView<'a> {
             show_bottom_status: true,
         }
     }
+
+    /// The area of the top bar with the tabs, in the layout of the current display mode
+    pub fn header_area(&self, area: &Rect) -> Rect {
+        match self.app_state.config.display_options.mode {
+            DisplayMode::Default => TuiFolderView::header_area(area),
+            DisplayMode::Minimal => TuiMinimalView::header_area(area),
+            DisplayMode::HSplit => TuiHSplitView::header_area(area),
+            DisplayMode::DualPane => TuiDualPaneView::header_area(area),
+        }
+    }
+
+    /// The area of the footer, in the layout of the current display mode
+    pub fn footer_area(&self, area: &Rect) -> Rect {
+        match self.app_state.config.display_options.mode {
+            DisplayMode::Default => TuiFolderView::footer_area(area),
+            DisplayMode::Minimal => TuiMinimalView::footer_area(area),
+            DisplayMode::HSplit => TuiHSplitView::footer_area(area),
+            DisplayMode::DualPane => TuiDualPaneView::footer_area(area),
+        }
+    }
 }
 
 impl Widget for TuiView<'_> {
@@ -33,6 +55,9 @@ impl Widget for TuiView<'_> {
             DisplayMode::HSplit => {
                 TuiHSplitView::new(self.app_state).render(area, buf);
             }
+            DisplayMode::DualPane => {
+                TuiDualPaneView::new(self.app_state).render(area, buf);
+            }
         }
     }
 }
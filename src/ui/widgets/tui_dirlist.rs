@@ -85,7 +85,13 @@ fn print_entry(
     (x, y): (u16, u16),
     drawing_width: usize,
 ) {
-    let name = entry.file_name();
+    // draw the tree guide first
+    let guide_width = entry.tree_guide.width().min(drawing_width);
+    buf.set_stringn(x, y, &entry.tree_guide, guide_width, Style::default());
+    let x = x + guide_width as u16;
+    let drawing_width = drawing_width - guide_width;
+
+    let name = entry.display_name();
     #[cfg(feature = "devicons")]
     let (label, label_width) = {
         if config.display_options.show_icons {
@@ -119,17 +125,18 @@ pub fn get_entry_icon(
     name: &str,
     ext: Option<&str>,
     metadata: &JoshutoMetadata,
-) -> &'static str {
+) -> String {
     use crate::ICONS_T;
 
+    let icons = ICONS_T.get();
     if let FileType::Directory = metadata.file_type() {
-        return ICONS_T
+        return icons
             .directory_exact
             .get(name)
-            .map(|s| s.as_str())
-            .unwrap_or(ICONS_T.default_dir.as_str());
+            .unwrap_or(&icons.default_dir)
+            .clone();
     }
-    ICONS_T
+    icons
         .file_exact
         .get(name)
         .map(|s| s.as_str())
@@ -140,8 +147,9 @@ pub fn get_entry_icon(
                 } else {
                     ext.to_owned()
                 };
-                ICONS_T.ext.get(&ext).map(|s| s.as_str())
+                icons.ext.get(&ext).map(|s| s.as_str())
             })
-            .unwrap_or_else(|| ICONS_T.default_file.as_str())
+            .unwrap_or_else(|| icons.default_file.as_str())
         })
+        .to_string()
 }
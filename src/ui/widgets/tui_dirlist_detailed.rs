@@ -9,8 +9,10 @@ use crate::config::app::AppConfig;
 use crate::fs::{FileType, JoshutoDirEntry, JoshutoDirList, LinkType};
 use crate::tab::TabDisplayOption;
 use crate::types::option::display::DisplayOption;
-use crate::types::option::line_mode::{LineMode, LineModeArgs, LineNumberStyle};
-use crate::utils::format::time_to_string;
+use crate::types::option::line_mode::{
+    ColumnAlignment, ColumnOption, LineModeArgs, LineNumberStyle,
+};
+use crate::types::state::GitDirStatus;
 use crate::utils::string::UnicodeTruncate;
 use crate::utils::style;
 use crate::utils::{format, unix};
@@ -22,12 +24,15 @@ const MIN_LEFT_LABEL_WIDTH: i32 = 15;
 
 const ELLIPSIS: &str = "…";
 
+const SYMLINK_INDICATOR: &str = "-> ";
+
 pub struct TuiDirListDetailed<'a> {
     pub config: &'a AppConfig,
     pub dirlist: &'a JoshutoDirList,
     pub display_options: &'a DisplayOption,
     pub tab_display_options: &'a TabDisplayOption,
     pub focused: bool,
+    pub git: Option<GitDirStatus<'a>>,
 }
 impl<'a> TuiDirListDetailed<'a> {
     pub fn new(
@@ -43,8 +48,14 @@ impl<'a> TuiDirListDetailed<'a> {
             display_options,
             tab_display_options,
             focused,
+            git: None,
         }
     }
+
+    pub fn git(mut self, git: Option<GitDirStatus<'a>>) -> Self {
+        self.git = git;
+        self
+    }
 }
 
 impl Widget for TuiDirListDetailed<'_> {
@@ -64,25 +75,101 @@ impl Widget for TuiDirListDetailed<'_> {
             }
         };
 
+        let show_headers = self.display_options.column_headers;
+        let (y, height) = if show_headers {
+            (y + 1, area.height - 1)
+        } else {
+            (y, area.height)
+        };
+
         let drawing_width = area.width as usize;
         let skip_dist = self.dirlist.first_index_for_viewport();
         let line_num_style = self.display_options.line_number_style;
         // Length (In chars) of the last entry's index on current page.
         // Using this to align all elements
-        let max_index_length = (skip_dist
-            + min(self.dirlist.len() - skip_dist, area.height as usize))
-        .to_string()
-        .len();
+        let max_index_length = (skip_dist + min(self.dirlist.len() - skip_dist, height as usize))
+            .to_string()
+            .len();
 
         let space_fill = " ".repeat(drawing_width);
 
+        // the linemode fields of every visible entry, to size the columns
+        let linemode = self
+            .display_options
+            .dir_views
+            .linemode(self.dirlist.file_path())
+            .unwrap_or(self.tab_display_options.linemode);
+        let mut columns: Vec<Column> = linemode
+            .mode
+            .iter()
+            .take(linemode.size)
+            .map(|arg| Column {
+                arg: *arg,
+                option: self.display_options.column_option(*arg),
+                width: 0,
+            })
+            .collect();
+        let cells: Vec<Vec<String>> = self
+            .dirlist
+            .iter()
+            .skip(skip_dist)
+            .take(height as usize)
+            .map(|entry| {
+                columns
+                    .iter()
+                    .map(|column| column_text(column, entry, self.git))
+                    .collect()
+            })
+            .collect();
+        for (i, column) in columns.iter_mut().enumerate() {
+            column.width = column.option.width.unwrap_or_else(|| {
+                let header_width = if show_headers {
+                    column.option.header.width()
+                } else {
+                    0
+                };
+                cells
+                    .iter()
+                    .map(|row| row[i].width())
+                    .max()
+                    .unwrap_or(0)
+                    .max(header_width)
+            });
+        }
+
+        // room for the symlink indicator, so that the columns line up
+        let symlink_width = if self
+            .dirlist
+            .iter()
+            .skip(skip_dist)
+            .take(height as usize)
+            .any(|entry| matches!(entry.metadata.link_type(), LinkType::Symlink { .. }))
+        {
+            SYMLINK_INDICATOR.width()
+        } else {
+            0
+        };
+
+        if show_headers {
+            let line_number_width = match line_num_style {
+                LineNumberStyle::None => 0,
+                _ => max_index_length + 1,
+            };
+            print_headers(
+                buf,
+                (x + 1 + line_number_width as u16, area.top()),
+                (&columns, symlink_width),
+                drawing_width.saturating_sub(1 + line_number_width),
+            );
+        }
+
         // draw every entry
         self.dirlist
             .iter()
             .skip(skip_dist)
+            .zip(cells.iter())
             .enumerate()
-            .take(area.height as usize)
-            .for_each(|(i, entry)| {
+            .for_each(|(i, (entry, row))| {
                 let ix = skip_dist + i;
 
                 let style = if !self.focused {
@@ -95,7 +182,7 @@ impl Widget for TuiDirListDetailed<'_> {
 
                 buf.set_string(x, y + i as u16, space_fill.as_str(), style);
 
-                let mut prefix = style::entry_prefix(entry).to_string();
+                let mut prefix = style::entry_prefix(entry);
                 let line_number_prefix = match line_num_style {
                     LineNumberStyle::None => "".to_string(),
                     _ if ix == curr_index => format!("{:<1$} ", curr_index + 1, max_index_length),
@@ -107,6 +194,7 @@ impl Widget for TuiDirListDetailed<'_> {
                     ),
                 };
                 prefix.push_str(&line_number_prefix);
+                prefix.push_str(&entry.tree_guide);
 
                 print_entry(
                     self.config,
@@ -114,7 +202,7 @@ impl Widget for TuiDirListDetailed<'_> {
                     entry,
                     style,
                     (x + 1, y + i as u16),
-                    self.tab_display_options.linemode,
+                    (&columns, row, symlink_width),
                     drawing_width - 1,
                     &prefix,
                 );
@@ -122,36 +210,125 @@ impl Widget for TuiDirListDetailed<'_> {
     }
 }
 
+struct Column {
+    arg: LineModeArgs,
+    option: ColumnOption,
+    width: usize,
+}
+
+impl Column {
+    // pads or truncates a value to the width of the column
+    fn fit(&self, text: &str) -> String {
+        let text = if text.width() > self.width {
+            text.trunc(self.width)
+        } else {
+            text.to_string()
+        };
+        let fill = " ".repeat(self.width - text.width());
+        match self.option.align {
+            ColumnAlignment::Left => format!("{}{}", text, fill),
+            ColumnAlignment::Right => format!("{}{}", fill, text),
+        }
+    }
+}
+
 fn get_entry_size_string(entry: &JoshutoDirEntry) -> String {
     match entry.metadata.file_type() {
-        FileType::Directory => entry
-            .metadata
-            .directory_size()
-            .map(|n| n.to_string())
-            .unwrap_or_default(),
+        FileType::Directory => match entry.metadata.recursive_size() {
+            Some(size) => format::file_size_to_string(size),
+            None => entry
+                .metadata
+                .directory_size()
+                .map(|n| n.to_string())
+                .unwrap_or_default(),
+        },
         _ => format::file_size_to_string(entry.metadata.len()),
     }
 }
 
-fn display_line_mode(mode: LineMode, entry: &JoshutoDirEntry) -> String {
+fn get_entry_type_string(entry: &JoshutoDirEntry) -> &'static str {
+    if let LinkType::Symlink { .. } = entry.metadata.link_type() {
+        return "link";
+    }
+    match entry.metadata.file_type() {
+        FileType::Directory => "dir",
+        FileType::File => "file",
+        FileType::Link => "link",
+        FileType::Socket => "socket",
+        FileType::Block => "block",
+        FileType::Character => "char",
+        FileType::Pipe => "pipe",
+    }
+}
+
+fn column_text(column: &Column, entry: &JoshutoDirEntry, git: Option<GitDirStatus>) -> String {
     let metadata = &entry.metadata;
+    let time_format = &column.option.time_format;
+
+    match column.arg {
+        LineModeArgs::Size => get_entry_size_string(entry),
+        LineModeArgs::ModifyTime => time_format.format(metadata.modified()),
+        LineModeArgs::AccessTime => time_format.format(metadata.accessed()),
+        LineModeArgs::ChangeTime => time_format.format(metadata.changed()),
+        LineModeArgs::BirthTime => metadata
+            .created()
+            .map_or_else(|| "-".to_string(), |time| time_format.format(time)),
+        LineModeArgs::User => unix::uid_to_string(metadata.uid).unwrap_or("unknown".into()),
+        LineModeArgs::Group => unix::gid_to_string(metadata.gid).unwrap_or("unknown".into()),
+        LineModeArgs::Permission => unix::mode_to_char_array(metadata.mode, metadata.file_type)
+            .iter()
+            .collect(),
+        LineModeArgs::Octal => format!("{:04o}", metadata.mode.bits() & 0o7777),
+        LineModeArgs::Inode => metadata.inode.to_string(),
+        LineModeArgs::Links => metadata.nlink.to_string(),
+        LineModeArgs::LinkTarget => match metadata.link_type() {
+            LinkType::Symlink { target, .. } => target.clone(),
+            LinkType::Normal => String::new(),
+        },
+        LineModeArgs::FileType => get_entry_type_string(entry).to_string(),
+        LineModeArgs::Mimetype => {
+            if metadata.is_dir() {
+                "inode/directory".to_string()
+            } else {
+                mime_guess::from_path(entry.file_name())
+                    .first_raw()
+                    .unwrap_or("-")
+                    .to_string()
+            }
+        }
+        LineModeArgs::Git => git
+            .and_then(|git| git.status(entry.file_name()))
+            .map_or(" ", |status| status.symbol())
+            .to_string(),
+        LineModeArgs::Null => unreachable!(),
+    }
+}
 
-    mode.mode
+fn print_headers(
+    buf: &mut Buffer,
+    (x, y): (u16, u16),
+    (columns, symlink_width): (&[Column], usize),
+    drawing_width: usize,
+) {
+    let style = Style::default().add_modifier(Modifier::BOLD);
+    let headers: Vec<String> = columns
         .iter()
-        .take(mode.size)
-        .map(|arg| match arg {
-            LineModeArgs::Size => get_entry_size_string(entry),
-            LineModeArgs::ModifyTime => time_to_string(metadata.modified()),
-            LineModeArgs::AccessTime => time_to_string(metadata.accessed()),
-            LineModeArgs::User => unix::uid_to_string(metadata.uid).unwrap_or("unknown".into()),
-            LineModeArgs::Group => unix::gid_to_string(metadata.gid).unwrap_or("unknown".into()),
-            LineModeArgs::Permission => unix::mode_to_char_array(metadata.mode, metadata.file_type)
-                .iter()
-                .collect(),
-            LineModeArgs::Null => unreachable!(),
-        })
-        .collect::<Vec<String>>()
-        .join(" ")
+        .map(|column| column.fit(&column.option.header))
+        .collect();
+    let right_label_original = format!(" {}{} ", " ".repeat(symlink_width), headers.join(" "));
+    let (left_label, right_label) =
+        factor_labels_for_entry("Name".to_string(), right_label_original, drawing_width);
+
+    buf.set_stringn(x, y, left_label, drawing_width, style);
+    if !columns.is_empty() {
+        buf.set_stringn(
+            x + drawing_width as u16 - right_label.width() as u16,
+            y,
+            &right_label,
+            drawing_width,
+            style,
+        );
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -161,16 +338,17 @@ fn print_entry(
     entry: &JoshutoDirEntry,
     style: Style,
     (x, y): (u16, u16),
-    linemode: LineMode,
+    (columns, row, symlink_width): (&[Column], &[String], usize),
     drawing_width: usize,
     prefix: &str,
 ) {
     let symlink_string = match entry.metadata.link_type() {
         LinkType::Normal => "",
-        LinkType::Symlink { .. } => "-> ",
+        LinkType::Symlink { .. } => SYMLINK_INDICATOR,
     };
+    let symlink_string = format!("{:<1$}", symlink_string, symlink_width);
 
-    let name = entry.file_name();
+    let name = entry.display_name();
     #[cfg(feature = "devicons")]
     let label = {
         if config.display_options.show_icons {
@@ -184,9 +362,14 @@ fn print_entry(
     #[cfg(not(feature = "devicons"))]
     let label = name.to_string();
 
+    let cells: Vec<String> = columns
+        .iter()
+        .zip(row)
+        .map(|(column, text)| column.fit(text))
+        .collect();
+
     let left_label_original = label;
-    let right_label_original =
-        format!(" {}{} ", symlink_string, display_line_mode(linemode, entry));
+    let right_label_original = format!(" {}{} ", symlink_string, cells.join(" "));
 
     // draw prefix first
     let prefix_width = prefix.width();
@@ -195,18 +378,34 @@ fn print_entry(
 
     // factor left_label and right_label
     let drawing_width = drawing_width - prefix_width;
-    let (left_label, right_label) =
-        factor_labels_for_entry(left_label_original, right_label_original, drawing_width);
+    let (left_label, right_label) = factor_labels_for_entry(
+        left_label_original,
+        right_label_original.clone(),
+        drawing_width,
+    );
 
     // Draw labels
+    let right_x = x + drawing_width as u16 - right_label.width() as u16;
     buf.set_stringn(x, y, left_label, drawing_width, style);
-    buf.set_stringn(
-        x + drawing_width as u16 - right_label.width() as u16,
-        y,
-        right_label,
-        drawing_width,
-        style,
-    );
+    buf.set_stringn(right_x, y, &right_label, drawing_width, style);
+
+    // columns with their own style are drawn again, unless they were cut off
+    if right_label != right_label_original {
+        return;
+    }
+    let mut cell_x = right_x + 1 + symlink_string.width() as u16;
+    for (column, cell) in columns.iter().zip(cells) {
+        if column.option.style != Style::default() {
+            buf.set_stringn(
+                cell_x,
+                y,
+                &cell,
+                column.width,
+                style.patch(column.option.style),
+            );
+        }
+        cell_x += column.width as u16 + 1;
+    }
 }
 
 fn factor_labels_for_entry(
@@ -0,0 +1,62 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::Widget;
+
+use crate::fs::DiskUsageNode;
+use crate::utils::format;
+use crate::THEME_T;
+
+const BAR_WIDTH: usize = 20;
+
+/// Children of a directory in a disk usage tree with bars proportional to their size
+pub struct TuiDiskUsage<'a> {
+    dir: &'a DiskUsageNode,
+    cursor: usize,
+}
+
+impl<'a> TuiDiskUsage<'a> {
+    pub fn new(dir: &'a DiskUsageNode, cursor: usize) -> Self {
+        Self { dir, cursor }
+    }
+}
+
+impl Widget for TuiDiskUsage<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 {
+            return;
+        }
+        let theme = THEME_T.get();
+        let height = area.height as usize;
+        let skip = (self.cursor + 1).saturating_sub(height);
+
+        for (i, child) in self.dir.children.iter().enumerate().skip(skip).take(height) {
+            let ratio = if self.dir.size == 0 {
+                0.0
+            } else {
+                child.size as f64 / self.dir.size as f64
+            };
+            let filled = (ratio * BAR_WIDTH as f64).round() as usize;
+            let line = format!(
+                "{:>10} [{}{}] {:>5.1}%  {}{}",
+                format::file_size_to_string(child.size),
+                "#".repeat(filled),
+                " ".repeat(BAR_WIDTH - filled),
+                ratio * 100.0,
+                child.name,
+                if child.is_dir { "/" } else { "" },
+            );
+
+            let mut style = if child.is_dir {
+                theme.directory.as_style()
+            } else {
+                theme.regular.as_style()
+            };
+            if i == self.cursor {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            let y = area.y + (i - skip) as u16;
+            buf.set_stringn(area.x, y, line, area.width as usize, style);
+        }
+    }
+}
@@ -4,8 +4,11 @@ use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Widget};
 
+use crate::commands::select::SelectOption;
 use crate::fs::{JoshutoDirList, LinkType};
 use crate::tab::TabDisplayOption;
+use crate::types::command::Command;
+use crate::types::option::dir_view::DirView;
 use crate::utils::format;
 use crate::utils::unix;
 use crate::{THEME_T, TIMEZONE_STR};
@@ -13,6 +16,10 @@ use crate::{THEME_T, TIMEZONE_STR};
 pub struct TuiFooter<'a> {
     dirlist: &'a JoshutoDirList,
     tab_options: &'a TabDisplayOption,
+    mode: Option<&'a str>,
+    branch: Option<&'a str>,
+    dir_sizes: Option<String>,
+    dir_view: Option<&'a DirView>,
 }
 
 impl<'a> TuiFooter<'a> {
@@ -20,114 +27,246 @@ impl<'a> TuiFooter<'a> {
         Self {
             dirlist,
             tab_options,
+            mode: None,
+            branch: None,
+            dir_sizes: None,
+            dir_view: None,
         }
     }
+
+    pub fn mode(mut self, mode: Option<&'a str>) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn branch(mut self, branch: Option<&'a str>) -> Self {
+        self.branch = branch;
+        self
+    }
+
+    pub fn dir_sizes(mut self, dir_sizes: Option<String>) -> Self {
+        self.dir_sizes = dir_sizes;
+        self
+    }
+
+    /// The view remembered for the directory, for the filter it may set
+    pub fn dir_view(mut self, dir_view: Option<&'a DirView>) -> Self {
+        self.dir_view = dir_view;
+        self
+    }
 }
 
-impl Widget for TuiFooter<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
+impl<'a> TuiFooter<'a> {
+    /// The spans of the footer, with the command a click on each one runs
+    fn spans(&self) -> Vec<(Span<'a>, Option<Command>)> {
+        let theme = THEME_T.get();
         let visual_mode_style = Style::default().fg(Color::Black).bg(Color::LightRed);
+        let keymap_mode_style = Style::default().fg(Color::Black).bg(Color::LightBlue);
         let mode_style = Style::default().fg(Color::Cyan);
+        let branch_style = Style::default().fg(Color::Magenta);
 
         // flat and filter commands indicator style
         let indicator_style = Style::default()
             .fg(Color::LightBlue)
-            .add_modifier(THEME_T.selection.modifier);
+            .add_modifier(theme.selection.modifier);
 
         let selection_style = Style::default()
-            .fg(THEME_T.selection.fg)
-            .bg(THEME_T.selection.bg)
-            .add_modifier(THEME_T.selection.modifier);
+            .fg(theme.selection.fg)
+            .bg(theme.selection.bg)
+            .add_modifier(theme.selection.modifier);
         let selected_count = self.dirlist.selected_count();
 
-        match self.dirlist.get_index() {
-            Some(i) if i < self.dirlist.len() => {
-                let entry = &self.dirlist.contents[i];
+        let i = match self.dirlist.get_index() {
+            Some(i) if i < self.dirlist.len() => i,
+            _ => return vec![],
+        };
+        let entry = &self.dirlist.contents[i];
 
-                let mode_str: String =
-                    unix::mode_to_char_array(entry.metadata.mode, entry.metadata.file_type)
-                        .iter()
-                        .collect();
+        let mode_str: String =
+            unix::mode_to_char_array(entry.metadata.mode, entry.metadata.file_type)
+                .iter()
+                .collect();
 
-                let user_str = unix::uid_to_string(entry.metadata.uid).unwrap_or("unknown".into());
-                let group_str = unix::gid_to_string(entry.metadata.gid).unwrap_or("unknown".into());
+        let user_str = unix::uid_to_string(entry.metadata.uid).unwrap_or("unknown".into());
+        let group_str = unix::gid_to_string(entry.metadata.gid).unwrap_or("unknown".into());
 
-                let mtime_str = format::time_to_string(entry.metadata.modified());
-                let size_str = format::file_size_to_string(entry.metadata.len());
+        let mtime_str = format::time_to_string(entry.metadata.modified());
+        let size_str = format::file_size_to_string(
+            entry
+                .metadata
+                .recursive_size()
+                .unwrap_or(entry.metadata.len()),
+        );
 
-                let path = self.dirlist.file_path();
+        let path = self.dirlist.file_path();
+        let dirlist_options = self.tab_options.dirlist_options_ref(&path.to_path_buf());
 
-                let mut text = vec![
-                    Span::styled(
-                        if self.dirlist.get_visual_mode_anchor_index().is_none() {
-                            ""
-                        } else {
-                            "VIS"
-                        },
-                        visual_mode_style,
-                    ),
-                    Span::raw(if self.dirlist.get_visual_mode_anchor_index().is_none() {
+        let mut text = vec![
+            (
+                Span::styled(
+                    if self.dirlist.get_visual_mode_anchor_index().is_none() {
                         ""
                     } else {
-                        " "
-                    }),
-                    Span::styled(mode_str, mode_style),
-                    Span::raw("  "),
-                    Span::raw(user_str),
-                    Span::raw(" "),
-                    Span::raw(group_str),
-                    Span::raw("  "),
-                    Span::raw(format!("{}/{}", i + 1, self.dirlist.len())),
-                    Span::raw("  "),
-                    Span::raw(mtime_str),
-                    Span::raw(TIMEZONE_STR.as_str()),
-                    Span::raw(size_str),
-                    Span::raw("  "),
-                    Span::styled(
-                        match self.tab_options.dirlist_options_ref(&path.to_path_buf()) {
-                            Some(opt) if opt.depth() > 0 => format!("flat:{} ", opt.depth()),
-                            _ => "".to_owned(),
-                        },
-                        indicator_style,
-                    ),
-                    Span::styled(
-                        match self.tab_options.dirlist_options_ref(&path.to_path_buf()) {
-                            Some(opt) if !opt.filter_state_ref().is_none() => {
-                                format!("filter:{} ", opt.filter_state_ref())
+                        "VIS"
+                    },
+                    visual_mode_style,
+                ),
+                Some(Command::ToggleVisualMode),
+            ),
+            (
+                Span::raw(if self.dirlist.get_visual_mode_anchor_index().is_none() {
+                    ""
+                } else {
+                    " "
+                }),
+                None,
+            ),
+            (
+                Span::styled(
+                    self.mode.map(|m| m.to_uppercase()).unwrap_or_default(),
+                    keymap_mode_style,
+                ),
+                Some(Command::PopMode),
+            ),
+            (Span::raw(if self.mode.is_none() { "" } else { " " }), None),
+            (Span::styled(mode_str, mode_style), None),
+            (Span::raw("  "), None),
+            (Span::raw(user_str), None),
+            (Span::raw(" "), None),
+            (Span::raw(group_str), None),
+            (Span::raw("  "), None),
+            (Span::raw(format!("{}/{}", i + 1, self.dirlist.len())), None),
+            (Span::raw("  "), None),
+            (Span::raw(mtime_str), None),
+            (Span::raw(TIMEZONE_STR.as_str()), None),
+            (Span::raw(size_str), None),
+            (Span::raw("  "), None),
+            (
+                Span::styled(
+                    self.branch.map(|b| format!("{} ", b)).unwrap_or_default(),
+                    branch_style,
+                ),
+                None,
+            ),
+            (
+                Span::styled(
+                    self.dir_sizes
+                        .as_ref()
+                        .map(|s| format!("{} ", s))
+                        .unwrap_or_default(),
+                    indicator_style,
+                ),
+                None,
+            ),
+            (
+                Span::styled(
+                    match dirlist_options {
+                        Some(opt) if opt.depth() > 0 => format!("flat:{} ", opt.depth()),
+                        _ => "".to_owned(),
+                    },
+                    indicator_style,
+                ),
+                Some(Command::Flat { depth: 0 }),
+            ),
+            (
+                Span::styled(
+                    match dirlist_options {
+                        Some(opt) if opt.is_tree() => "tree ".to_owned(),
+                        _ => "".to_owned(),
+                    },
+                    indicator_style,
+                ),
+                Some(Command::TreeCollapse),
+            ),
+            (
+                Span::styled(
+                    match dirlist_options {
+                        Some(opt) if !opt.filter_state_ref().is_none() => {
+                            format!("filter:{} ", opt.filter_state_ref())
+                        }
+                        _ => match self.dir_view.and_then(|view| view.filter.as_ref()) {
+                            Some(filter) if !filter.state.is_none() => {
+                                format!("filter:{} ", filter.state)
                             }
                             _ => "".to_owned(),
                         },
-                        indicator_style,
-                    ),
-                    Span::styled(
-                        if selected_count > 0 {
-                            format!("{} selected", selected_count)
-                        } else {
-                            " ".to_string()
-                        },
-                        selection_style,
-                    ),
-                ];
-
-                if let LinkType::Symlink { target, valid } = entry.metadata.link_type() {
-                    let link_style = if *valid {
-                        Style::default()
-                            .fg(THEME_T.link.fg)
-                            .bg(THEME_T.link.bg)
-                            .add_modifier(THEME_T.link.modifier)
+                    },
+                    indicator_style,
+                ),
+                Some(Command::FilterString {
+                    pattern: String::new(),
+                }),
+            ),
+            (
+                Span::styled(
+                    match dirlist_options.and_then(|opt| opt.find_results_ref()) {
+                        Some(find) if find.done => format!("find:{} ", find.pattern),
+                        Some(find) => format!("find:{} (searching) ", find.pattern),
+                        None => "".to_owned(),
+                    },
+                    indicator_style,
+                ),
+                Some(Command::Escape),
+            ),
+            (
+                Span::styled(
+                    if selected_count > 0 {
+                        format!("{} selected", selected_count)
                     } else {
-                        Style::default()
-                            .fg(THEME_T.link_invalid.fg)
-                            .bg(THEME_T.link_invalid.bg)
-                            .add_modifier(THEME_T.link_invalid.modifier)
-                    };
-                    text.push(Span::styled(" -> ", link_style));
-                    text.push(Span::styled(target, link_style));
-                }
-
-                Paragraph::new(Line::from(text)).render(area, buf);
-            }
-            _ => {}
+                        " ".to_string()
+                    },
+                    selection_style,
+                ),
+                (selected_count > 0).then(|| Command::SelectString {
+                    pattern: String::new(),
+                    options: SelectOption {
+                        toggle: false,
+                        all: true,
+                        reverse: true,
+                    },
+                }),
+            ),
+        ];
+
+        if let LinkType::Symlink { target, valid } = entry.metadata.link_type() {
+            let link_style = if *valid {
+                Style::default()
+                    .fg(theme.link.fg)
+                    .bg(theme.link.bg)
+                    .add_modifier(theme.link.modifier)
+            } else {
+                Style::default()
+                    .fg(theme.link_invalid.fg)
+                    .bg(theme.link_invalid.bg)
+                    .add_modifier(theme.link_invalid.modifier)
+            };
+            text.push((Span::styled(" -> ", link_style), None));
+            text.push((Span::styled(target, link_style), None));
+        }
+        text
+    }
+
+    /// The command of the indicator shown at column `x` when rendered into `area`.
+    /// Clicking the indicators of visual mode, the keymap mode, flat, tree, filter and find
+    /// leaves them, clicking the selection count deselects all files.
+    pub fn command_at(&self, area: Rect, x: u16) -> Option<Command> {
+        let x = x.checked_sub(area.x)? as usize;
+        let mut end = 0;
+        self.spans()
+            .into_iter()
+            .find(|(span, _)| {
+                end += span.width();
+                x < end
+            })
+            .and_then(|(_, command)| command)
+    }
+}
+
+impl Widget for TuiFooter<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let text: Vec<Span> = self.spans().into_iter().map(|(span, _)| span).collect();
+        if !text.is_empty() {
+            Paragraph::new(Line::from(text)).render(area, buf);
         }
     }
 }
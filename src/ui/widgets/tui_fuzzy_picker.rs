@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Widget};
+use unicode_width::UnicodeWidthChar;
+
+use crate::utils::fuzzy::FuzzyMatch;
+
+const MARKER: &str = "* ";
+
+/// Prompt and list of fuzzy matches, with the matched characters highlighted
+pub struct TuiFuzzyPicker<'a> {
+    title: &'a str,
+    query: &'a str,
+    items: &'a [String],
+    matches: &'a [(usize, FuzzyMatch)],
+    cursor: usize,
+    // indices into `items`
+    marked: &'a HashSet<usize>,
+}
+
+impl<'a> TuiFuzzyPicker<'a> {
+    pub fn new(
+        title: &'a str,
+        query: &'a str,
+        items: &'a [String],
+        matches: &'a [(usize, FuzzyMatch)],
+        cursor: usize,
+        marked: &'a HashSet<usize>,
+    ) -> Self {
+        Self {
+            title,
+            query,
+            items,
+            matches,
+            cursor,
+            marked,
+        }
+    }
+}
+
+impl Widget for TuiFuzzyPicker<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.height < 2 || inner.width < 4 {
+            return;
+        }
+        let width = inner.width as usize;
+
+        // prompt with the number of matches on the right
+        let count = if self.marked.is_empty() {
+            format!("{}/{}", self.matches.len(), self.items.len())
+        } else {
+            format!(
+                "{}/{} ({})",
+                self.matches.len(),
+                self.items.len(),
+                self.marked.len()
+            )
+        };
+        let (x, _) = buf.set_stringn(
+            inner.x,
+            inner.y,
+            format!("> {}", self.query),
+            width,
+            Style::default(),
+        );
+        buf.set_stringn(
+            x,
+            inner.y,
+            " ",
+            1,
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+        if x as usize + 1 + count.len() < (inner.x as usize + width) {
+            buf.set_string(
+                inner.x + (width - count.len()) as u16,
+                inner.y,
+                count,
+                Style::default().fg(Color::DarkGray),
+            );
+        }
+
+        let height = (inner.height - 1) as usize;
+        let skip = (self.cursor + 1).saturating_sub(height);
+        for (i, (item_index, m)) in self.matches.iter().enumerate().skip(skip).take(height) {
+            let y = inner.y + 1 + (i - skip) as u16;
+            let mut style = Style::default();
+            if i == self.cursor {
+                style = style.add_modifier(Modifier::REVERSED);
+                buf.set_stringn(inner.x, y, " ".repeat(width), width, style);
+            }
+            let marker = if self.marked.contains(item_index) {
+                MARKER
+            } else {
+                "  "
+            };
+            let (mut x, _) = buf.set_stringn(inner.x, y, marker, width, style);
+
+            let highlight = style.fg(Color::Yellow).add_modifier(Modifier::BOLD);
+            let end = inner.x + inner.width;
+            for (ci, c) in self.items[*item_index].chars().enumerate() {
+                let c_width = c.width().unwrap_or(0) as u16;
+                if x + c_width > end {
+                    break;
+                }
+                let c_style = if m.indices.binary_search(&ci).is_ok() {
+                    highlight
+                } else {
+                    style
+                };
+                buf.set_stringn(x, y, c.to_string(), c_width as usize, c_style);
+                x += c_width;
+            }
+        }
+    }
+}
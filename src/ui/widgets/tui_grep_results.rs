@@ -0,0 +1,69 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+
+use crate::fs::GrepMatch;
+
+/// Matching lines of a content search as `file:line: snippet`
+pub struct TuiGrepResults<'a> {
+    matches: &'a [GrepMatch],
+    cursor: usize,
+}
+
+impl<'a> TuiGrepResults<'a> {
+    pub fn new(matches: &'a [GrepMatch], cursor: usize) -> Self {
+        Self { matches, cursor }
+    }
+}
+
+impl Widget for TuiGrepResults<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 {
+            return;
+        }
+        let height = area.height as usize;
+        let skip = (self.cursor + 1).saturating_sub(height);
+        let end = area.x + area.width;
+
+        for (i, m) in self.matches.iter().enumerate().skip(skip).take(height) {
+            let y = area.y + (i - skip) as u16;
+            let mut style = Style::default();
+            if i == self.cursor {
+                style = style.add_modifier(Modifier::REVERSED);
+                buf.set_stringn(
+                    area.x,
+                    y,
+                    " ".repeat(area.width as usize),
+                    area.width as usize,
+                    style,
+                );
+            }
+
+            let (before, rest) = m.snippet.split_at(m.range.start);
+            let (matched, after) = rest.split_at(m.range.end - m.range.start);
+            let parts = [
+                (
+                    m.path.to_string_lossy().to_string(),
+                    style.fg(Color::Magenta),
+                ),
+                (":".to_string(), style),
+                (m.line.to_string(), style.fg(Color::Green)),
+                (": ".to_string(), style),
+                (before.to_string(), style),
+                (
+                    matched.to_string(),
+                    style.fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+                (after.to_string(), style),
+            ];
+            let mut x = area.x;
+            for (text, style) in parts.iter() {
+                if x >= end {
+                    break;
+                }
+                (x, _) = buf.set_stringn(x, y, text, (end - x) as usize, *style);
+            }
+        }
+    }
+}
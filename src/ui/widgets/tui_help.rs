@@ -134,7 +134,7 @@ pub fn get_raw_keymap_table<'a>(
                 let mut sub_rows = get_raw_keymap_table(sub_keymap, "", sort_by);
                 for _ in 0..sub_rows.len() {
                     let mut sub_row = sub_rows.pop().unwrap();
-                    sub_row[0] = key.clone() + &sub_row[0];
+                    sub_row[0] = key.clone() + sub_row[0].as_str();
                     if sub_row[0].contains(search_query)
                         || sub_row[1].contains(search_query)
                         || sub_row[2].to_lowercase().contains(search_query_lowercase)
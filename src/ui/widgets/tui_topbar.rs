@@ -4,6 +4,8 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Widget};
 
+use std::path::Path;
+
 use tab_list_builder::factor_tab_bar_spans;
 
 use crate::types::state::AppState;
@@ -19,11 +21,38 @@ impl<'a> TuiTopBar<'a> {
     pub fn new(app_state: &'a AppState) -> Self {
         Self { app_state }
     }
+
+    fn name_width() -> usize {
+        USERNAME.as_str().len() + HOSTNAME.as_str().len() + 2
+    }
+
+    fn tab_paths(&self) -> Vec<&'a Path> {
+        self.app_state
+            .state
+            .tab_state_ref()
+            .tab_refs_in_order()
+            .into_iter()
+            .map(|tab| tab.get_cwd())
+            .collect()
+    }
+
+    /// The index of the tab shown at column `x` when rendered into `area`
+    pub fn tab_index_at(&self, area: Rect, x: u16) -> Option<usize> {
+        let name_width = Self::name_width();
+        let tab_x = (x.checked_sub(area.x)? as usize).checked_sub(name_width)?;
+        tab_list_builder::tab_index_at(
+            (area.width as usize).saturating_sub(name_width),
+            &self.tab_paths(),
+            self.app_state.state.tab_state_ref().index,
+            &THEME_T.get().tabs,
+            tab_x,
+        )
+    }
 }
 
 impl Widget for TuiTopBar<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let name_width = USERNAME.as_str().len() + HOSTNAME.as_str().len() + 2;
+        let name_width = Self::name_width();
 
         let username_style = if USERNAME.as_str() == "root" {
             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
@@ -41,16 +70,12 @@ impl Widget for TuiTopBar<'_> {
         ];
 
         let available_tab_width = area.width as usize - name_width;
-        let mut paths = Vec::new();
-        let tabs = self.app_state.state.tab_state_ref().tab_refs_in_order();
-        for tab in tabs {
-            paths.push(tab.get_cwd());
-        }
+        let paths = self.tab_paths();
         let tab_bar_spans = factor_tab_bar_spans(
             available_tab_width,
             &paths,
             self.app_state.state.tab_state_ref().index,
-            &THEME_T.tabs,
+            &THEME_T.get().tabs,
         );
         top_bar_spans.extend(tab_bar_spans);
         Paragraph::new(Line::from(top_bar_spans)).render(area, buf);
@@ -0,0 +1,212 @@
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::os::unix::ffi::OsStringExt;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::error::{AppError, AppErrorKind, AppResult};
+use crate::types::option::clipboard::ClipboardBackend;
+
+impl ClipboardBackend {
+    // whether the backend can work in this session at all
+    fn is_available(&self) -> bool {
+        match self {
+            Self::WlCopy => env::var_os("WAYLAND_DISPLAY").is_some(),
+            Self::Xclip | Self::Xsel => env::var_os("DISPLAY").is_some(),
+            Self::Pbcopy => cfg!(target_os = "macos"),
+            Self::Osc52 => true,
+        }
+    }
+
+    fn copy(&self, text: &str) -> io::Result<()> {
+        match self {
+            Self::WlCopy => copy_with_program("wl-copy", &[], text),
+            Self::Xclip => copy_with_program("xclip", &["-selection", "clipboard"], text),
+            Self::Xsel => copy_with_program("xsel", &["--clipboard", "--input"], text),
+            Self::Pbcopy => copy_with_program("pbcopy", &[], text),
+            Self::Osc52 => {
+                // ESC ] 52 ; c ; <base64> BEL
+                let mut stdout = io::stdout();
+                write!(stdout, "\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))?;
+                stdout.flush()
+            }
+        }
+    }
+
+    // prefers a list of files over plain text, if the clipboard holds both
+    fn paste(&self) -> io::Result<String> {
+        match self {
+            Self::WlCopy => {
+                paste_with_program("wl-paste", &["--no-newline", "--type", "text/uri-list"])
+                    .or_else(|_| paste_with_program("wl-paste", &["--no-newline"]))
+            }
+            Self::Xclip => paste_with_program(
+                "xclip",
+                &["-selection", "clipboard", "-o", "-t", "text/uri-list"],
+            )
+            .or_else(|_| paste_with_program("xclip", &["-selection", "clipboard", "-o"])),
+            Self::Xsel => paste_with_program("xsel", &["--clipboard", "--output"]),
+            Self::Pbcopy => paste_with_program("pbpaste", &[]),
+            Self::Osc52 => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "osc52 can only copy",
+            )),
+        }
+    }
+}
+
+fn copy_with_program(program: &str, args: &[&str], text: &str) -> io::Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("{} exited with {}", program, status),
+        ))
+    }
+}
+
+fn paste_with_program(program: &str, args: &[&str]) -> io::Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("{} exited with {}", program, output.status),
+        ))
+    }
+}
+
+/// Copies text with the first backend that works
+pub fn copy_to_clipboard(backends: &[ClipboardBackend], text: &str) -> AppResult {
+    for backend in backends.iter().filter(|b| b.is_available()) {
+        if backend.copy(text).is_ok() {
+            return Ok(());
+        }
+    }
+    Err(AppError::new(
+        AppErrorKind::Clipboard,
+        "Failed to copy to clipboard".to_string(),
+    ))
+}
+
+/// Reads the clipboard with the first backend that works
+pub fn paste_from_clipboard(backends: &[ClipboardBackend]) -> AppResult<String> {
+    for backend in backends.iter().filter(|b| b.is_available()) {
+        if let Ok(text) = backend.paste() {
+            return Ok(text);
+        }
+    }
+    Err(AppError::new(
+        AppErrorKind::Clipboard,
+        "Failed to read from clipboard".to_string(),
+    ))
+}
+
+/// Parses a `text/uri-list` of `file://` URIs, one per line.
+/// Plain absolute paths are accepted as well, anything else is skipped.
+pub fn parse_uri_list(text: &str) -> Vec<PathBuf> {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| match line.strip_prefix("file://") {
+            // the host part is usually empty or localhost
+            Some(uri) => uri
+                .find('/')
+                .map(|i| PathBuf::from(OsString::from_vec(percent_decode(&uri[i..])))),
+            None if line.starts_with('/') => Some(PathBuf::from(line)),
+            None => None,
+        })
+        .collect()
+}
+
+fn percent_decode(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    decoded
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_pads_the_last_chunk() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"it's a file"), "aXQncyBhIGZpbGU=");
+    }
+
+    #[test]
+    fn uri_list_is_decoded() {
+        let text = "# comment\r\n\
+                    file:///home/user/it%27s%20here.txt\r\n\
+                    file://localhost/tmp/a\r\n\
+                    copy\n\
+                    /tmp/plain path\n\
+                    https://example.com/b\n";
+        assert_eq!(
+            parse_uri_list(text),
+            [
+                PathBuf::from("/home/user/it's here.txt"),
+                PathBuf::from("/tmp/a"),
+                PathBuf::from("/tmp/plain path"),
+            ]
+        );
+    }
+}
@@ -23,6 +23,92 @@ pub fn file_size_to_string(file_size: u64) -> String {
 pub fn time_to_string(time: time::SystemTime) -> String {
     const TIME_FORMATTING: &str = "%Y-%m-%d %H:%M";
 
+    time_to_string_with_format(time, TIME_FORMATTING)
+}
+
+pub fn time_to_string_with_format(time: time::SystemTime, format: &str) -> String {
     let datetime: chrono::DateTime<chrono::offset::Local> = time.into();
-    datetime.format(TIME_FORMATTING).to_string()
+    datetime.format(format).to_string()
+}
+
+/// Parses a local time formatted as `%Y-%m-%d %H:%M:%S` or `%Y-%m-%d %H:%M`
+pub fn string_to_time(s: &str) -> Option<time::SystemTime> {
+    use chrono::TimeZone;
+
+    let s = s.trim();
+    let datetime = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M"))
+        .ok()?;
+    let datetime = chrono::offset::Local
+        .from_local_datetime(&datetime)
+        .earliest()?;
+    Some(datetime.into())
+}
+
+/// Formats how long ago `time` was, like "3h ago"
+pub fn time_to_relative_string(time: time::SystemTime, now: time::SystemTime) -> String {
+    const UNITS: [(u64, &str); 6] = [
+        (365 * 24 * 60 * 60, "y"),
+        (30 * 24 * 60 * 60, "mo"),
+        (24 * 60 * 60, "d"),
+        (60 * 60, "h"),
+        (60, "m"),
+        (1, "s"),
+    ];
+
+    let (secs, future) = match now.duration_since(time) {
+        Ok(d) => (d.as_secs(), false),
+        Err(e) => (e.duration().as_secs(), true),
+    };
+    if secs == 0 {
+        return "now".to_string();
+    }
+    let (unit_secs, unit) = UNITS
+        .iter()
+        .find(|(unit_secs, _)| secs >= *unit_secs)
+        .unwrap_or(&UNITS[UNITS.len() - 1]);
+    let count = secs / unit_secs;
+    if future {
+        format!("in {}{}", count, unit)
+    } else {
+        format!("{}{} ago", count, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::{string_to_time, time_to_relative_string, time_to_string_with_format};
+
+    #[test]
+    fn relative_time_uses_the_largest_unit() {
+        let now = SystemTime::now();
+        let ago = |secs| time_to_relative_string(now - Duration::from_secs(secs), now);
+        assert_eq!(ago(0), "now");
+        assert_eq!(ago(59), "59s ago");
+        assert_eq!(ago(3 * 60 * 60 + 59), "3h ago");
+        assert_eq!(ago(2 * 24 * 60 * 60), "2d ago");
+        assert_eq!(ago(400 * 24 * 60 * 60), "1y ago");
+        assert_eq!(
+            time_to_relative_string(now + Duration::from_secs(120), now),
+            "in 2m"
+        );
+    }
+
+    #[test]
+    fn time_strings_round_trip() {
+        const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+        let time = string_to_time("2024-02-29 13:45:10").unwrap();
+        assert_eq!(
+            time_to_string_with_format(time, FORMAT),
+            "2024-02-29 13:45:10"
+        );
+        let time = string_to_time(" 2024-02-29 13:45 ").unwrap();
+        assert_eq!(
+            time_to_string_with_format(time, FORMAT),
+            "2024-02-29 13:45:00"
+        );
+        assert!(string_to_time("yesterday").is_none());
+    }
 }
@@ -0,0 +1,216 @@
+use crate::types::option::search::CaseSensitivity;
+
+const SCORE_MATCH: i64 = 16;
+const SCORE_GAP_START: i64 = -3;
+const SCORE_GAP_EXTENSION: i64 = -1;
+// a match right after the previous one
+const BONUS_CONSECUTIVE: i64 = 8;
+// a match at the start of the text or of a word
+const BONUS_BOUNDARY: i64 = 10;
+// a match at an uppercase letter following a lowercase one
+const BONUS_CAMEL_CASE: i64 = 7;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    /// char indices of the matched characters in the text
+    pub indices: Vec<usize>,
+}
+
+/// Matches the characters of `pattern` in order, but not necessarily next to each other,
+/// against `text`, like fzf.
+/// Consecutive matches and matches at the start of words score higher,
+/// gaps between matches score lower.
+pub fn fuzzy_match(
+    pattern: &str,
+    text: &str,
+    case_sensitivity: CaseSensitivity,
+) -> Option<FuzzyMatch> {
+    let ignore_case = match case_sensitivity {
+        CaseSensitivity::Insensitive => true,
+        CaseSensitivity::Sensitive => false,
+        CaseSensitivity::Smart => !pattern.chars().any(|c| c.is_uppercase()),
+    };
+    let normalize = |c: char| {
+        if ignore_case {
+            c.to_lowercase().next().unwrap_or(c)
+        } else {
+            c
+        }
+    };
+
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(normalize)
+        .collect();
+    let chars: Vec<char> = text.chars().collect();
+    let normalized: Vec<char> = chars.iter().map(|c| normalize(*c)).collect();
+
+    if pattern.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            indices: Vec::new(),
+        });
+    }
+
+    // find where the first occurrence of the whole pattern ends
+    let mut pi = 0;
+    let mut end = None;
+    for (i, c) in normalized.iter().enumerate() {
+        if *c == pattern[pi] {
+            pi += 1;
+            if pi == pattern.len() {
+                end = Some(i);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    // walk back from there to find the shortest match ending at `end`
+    let mut pi = pattern.len();
+    let mut start = end;
+    for i in (0..=end).rev() {
+        if normalized[i] == pattern[pi - 1] {
+            pi -= 1;
+            if pi == 0 {
+                start = i;
+                break;
+            }
+        }
+    }
+
+    let mut indices = Vec::with_capacity(pattern.len());
+    let mut pi = 0;
+    for (i, c) in normalized.iter().enumerate().take(end + 1).skip(start) {
+        if pi < pattern.len() && *c == pattern[pi] {
+            indices.push(i);
+            pi += 1;
+        }
+    }
+
+    let score = score_indices(&chars, &indices);
+    Some(FuzzyMatch { score, indices })
+}
+
+fn score_indices(chars: &[char], indices: &[usize]) -> i64 {
+    let mut score = 0;
+    let mut prev: Option<usize> = None;
+    for &i in indices {
+        score += SCORE_MATCH + char_bonus(chars, i);
+        match prev {
+            Some(p) if p + 1 == i => score += BONUS_CONSECUTIVE,
+            Some(p) => score += SCORE_GAP_START + SCORE_GAP_EXTENSION * (i - p - 2) as i64,
+            None => {}
+        }
+        prev = Some(i);
+    }
+    score
+}
+
+fn char_bonus(chars: &[char], i: usize) -> i64 {
+    let c = chars[i];
+    match i.checked_sub(1).map(|p| chars[p]) {
+        None => BONUS_BOUNDARY,
+        Some(p) if !p.is_alphanumeric() && c.is_alphanumeric() => BONUS_BOUNDARY,
+        Some(p) if p.is_lowercase() && c.is_uppercase() => BONUS_CAMEL_CASE,
+        _ => 0,
+    }
+}
+
+/// Matches all items against `pattern` and returns the indices of the matching items,
+/// best match first
+pub fn fuzzy_filter<S: AsRef<str>>(
+    pattern: &str,
+    items: &[S],
+    case_sensitivity: CaseSensitivity,
+) -> Vec<(usize, FuzzyMatch)> {
+    fuzzy_filter_indices(pattern, items, 0..items.len(), case_sensitivity)
+}
+
+/// Like `fuzzy_filter`, but only matches the items at `candidates`
+pub fn fuzzy_filter_indices<S, I>(
+    pattern: &str,
+    items: &[S],
+    candidates: I,
+    case_sensitivity: CaseSensitivity,
+) -> Vec<(usize, FuzzyMatch)>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = usize>,
+{
+    let mut matches: Vec<(usize, FuzzyMatch)> = candidates
+        .into_iter()
+        .filter_map(|i| fuzzy_match(pattern, items[i].as_ref(), case_sensitivity).map(|m| (i, m)))
+        .collect();
+    sort_matches(&mut matches, items);
+    matches
+}
+
+/// Sorts matches best first, equally good matches by the length and then the order of the items
+pub fn sort_matches<S: AsRef<str>>(matches: &mut [(usize, FuzzyMatch)], items: &[S]) {
+    matches.sort_unstable_by(|(a_i, a), (b_i, b)| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| items[*a_i].as_ref().len().cmp(&items[*b_i].as_ref().len()))
+            .then_with(|| a_i.cmp(b_i))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn characters_match_in_order() {
+        let m = fuzzy_match("fb", "foo_bar", CaseSensitivity::Sensitive).unwrap();
+        assert_eq!(m.indices, [0, 4]);
+        assert!(fuzzy_match("bf", "foo_bar", CaseSensitivity::Sensitive).is_none());
+        assert!(fuzzy_match("", "foo", CaseSensitivity::Sensitive).is_some());
+    }
+
+    #[test]
+    fn shortest_match_is_highlighted() {
+        let m = fuzzy_match("ab", "a_xab", CaseSensitivity::Sensitive).unwrap();
+        assert_eq!(m.indices, [3, 4]);
+    }
+
+    #[test]
+    fn case_sensitivity_is_respected() {
+        assert!(fuzzy_match("FOO", "foo", CaseSensitivity::Sensitive).is_none());
+        assert!(fuzzy_match("FOO", "foo", CaseSensitivity::Insensitive).is_some());
+        assert!(fuzzy_match("foo", "FOO", CaseSensitivity::Smart).is_some());
+        assert!(fuzzy_match("Foo", "foo", CaseSensitivity::Smart).is_none());
+    }
+
+    #[test]
+    fn better_matches_come_first() {
+        let items = ["src/main.rs", "my_archive.tar", "readme.md", "mr"];
+        let order: Vec<usize> = fuzzy_filter("mr", &items, CaseSensitivity::Insensitive)
+            .into_iter()
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(order, [3, 0, 1]);
+    }
+
+    #[test]
+    fn filtering_candidates_matches_filtering_all() {
+        let items = [
+            "src/main.rs",
+            "my_archive.tar",
+            "readme.md",
+            "mr",
+            "Makefile",
+        ];
+        let all = fuzzy_filter("mr", &items, CaseSensitivity::Insensitive);
+        let previous = fuzzy_filter("m", &items, CaseSensitivity::Insensitive);
+        let narrowed = fuzzy_filter_indices(
+            "mr",
+            &items,
+            previous.iter().map(|(i, _)| *i),
+            CaseSensitivity::Insensitive,
+        );
+        assert_eq!(narrowed, all);
+    }
+}
@@ -1,6 +1,8 @@
+pub mod clipboard;
 pub mod cwd;
 pub mod format;
 pub mod fs;
+pub mod fuzzy;
 pub mod keyparse;
 pub mod mimetype;
 pub mod name_resolution;
@@ -25,7 +25,7 @@ where
 
     let pwd = std::env::current_dir()?;
     command.env("PWD", pwd);
-    command.args(entry.get_args());
+    command.args(entry.get_command_args());
     command.args(paths);
 
     let mut child = command.spawn()?;
@@ -55,7 +55,7 @@ where
 
     let pwd = std::env::current_dir()?;
     command.env("PWD", pwd);
-    command.args(entry.get_args());
+    command.args(entry.get_command_args());
     command.args(paths);
 
     if entry.get_pager() {
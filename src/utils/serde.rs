@@ -27,14 +27,9 @@ where
     let mut line_mode = LineMode::empty();
 
     for mode in line_mode_string.split('|').map(|mode| mode.trim()) {
-        match mode {
-            "size" => line_mode.add_mode(LineModeArgs::Size),
-            "mtime" => line_mode.add_mode(LineModeArgs::ModifyTime),
-            "atime" => line_mode.add_mode(LineModeArgs::AccessTime),
-            "user" => line_mode.add_mode(LineModeArgs::User),
-            "group" => line_mode.add_mode(LineModeArgs::Group),
-            "perm" => line_mode.add_mode(LineModeArgs::Permission),
-            e => eprintln!("{e} is an unsupportted line mode, will be ignored"),
+        match mode.parse::<LineModeArgs>() {
+            Ok(mode) => line_mode.add_mode(mode),
+            Err(_) => eprintln!("{mode} is an unsupportted line mode, will be ignored"),
         }
     }
 
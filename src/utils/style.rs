@@ -38,7 +38,7 @@ pub fn entry_style(config: &AppConfig, entry: &JoshutoDirEntry) -> Style {
         return permanent_selected_style();
     }
 
-    match &THEME_T.lscolors {
+    match &THEME_T.get().lscolors {
         Some(lscolors) => {
             let path = entry.file_path();
             lscolors_style(lscolors, path)
@@ -48,13 +48,13 @@ pub fn entry_style(config: &AppConfig, entry: &JoshutoDirEntry) -> Style {
     }
 }
 
-pub fn entry_prefix(entry: &JoshutoDirEntry) -> &str {
+pub fn entry_prefix(entry: &JoshutoDirEntry) -> String {
     if entry.is_visual_mode_selected() {
-        &THEME_T.visual_mode_selection.prefix
+        THEME_T.get().visual_mode_selection.prefix.clone()
     } else if entry.is_permanent_selected() {
-        &THEME_T.selection.prefix
+        THEME_T.get().selection.prefix.clone()
     } else {
-        ""
+        String::new()
     }
 }
 
@@ -64,66 +64,70 @@ fn default_style(
     linktype: &LinkType,
     filetype: FileType,
 ) -> Style {
+    let theme = THEME_T.get();
     match linktype {
         LinkType::Symlink { valid: true, .. } => Style::default()
-            .fg(THEME_T.link.fg)
-            .bg(THEME_T.link.bg)
-            .add_modifier(THEME_T.link.modifier),
+            .fg(theme.link.fg)
+            .bg(theme.link.bg)
+            .add_modifier(theme.link.modifier),
         LinkType::Symlink { valid: false, .. } => Style::default()
-            .fg(THEME_T.link_invalid.fg)
-            .bg(THEME_T.link_invalid.bg)
-            .add_modifier(THEME_T.link_invalid.modifier),
+            .fg(theme.link_invalid.fg)
+            .bg(theme.link_invalid.bg)
+            .add_modifier(theme.link_invalid.modifier),
         LinkType::Normal => match filetype {
             FileType::Directory => Style::default()
-                .fg(THEME_T.directory.fg)
-                .bg(THEME_T.directory.bg)
-                .add_modifier(THEME_T.directory.modifier),
+                .fg(theme.directory.fg)
+                .bg(theme.directory.bg)
+                .add_modifier(theme.directory.modifier),
             FileType::File => file_style(config, entry),
             FileType::Link => Style::default()
-                .fg(THEME_T.link.fg)
-                .bg(THEME_T.link.bg)
-                .add_modifier(THEME_T.link.modifier),
+                .fg(theme.link.fg)
+                .bg(theme.link.bg)
+                .add_modifier(theme.link.modifier),
             _ => Style::default()
-                .fg(THEME_T.socket.fg)
-                .bg(THEME_T.socket.bg)
-                .add_modifier(THEME_T.socket.modifier),
+                .fg(theme.socket.fg)
+                .bg(theme.socket.bg)
+                .add_modifier(theme.socket.modifier),
         },
     }
 }
 
 fn visual_mode_selected_style() -> Style {
+    let theme = THEME_T.get();
     Style::default()
-        .fg(THEME_T.visual_mode_selection.fg)
-        .bg(THEME_T.visual_mode_selection.bg)
-        .add_modifier(THEME_T.visual_mode_selection.modifier)
+        .fg(theme.visual_mode_selection.fg)
+        .bg(theme.visual_mode_selection.bg)
+        .add_modifier(theme.visual_mode_selection.modifier)
 }
 
 fn permanent_selected_style() -> Style {
+    let theme = THEME_T.get();
     Style::default()
-        .fg(THEME_T.selection.fg)
-        .bg(THEME_T.selection.bg)
-        .add_modifier(THEME_T.selection.modifier)
+        .fg(theme.selection.fg)
+        .bg(theme.selection.bg)
+        .add_modifier(theme.selection.modifier)
 }
 
 fn file_style(config: &AppConfig, entry: &JoshutoDirEntry) -> Style {
+    let theme = THEME_T.get();
     let regular_style = Style::default()
-        .fg(THEME_T.regular.fg)
-        .bg(THEME_T.regular.bg)
-        .add_modifier(THEME_T.regular.modifier);
+        .fg(theme.regular.fg)
+        .bg(theme.regular.bg)
+        .add_modifier(theme.regular.modifier);
     let metadata = &entry.metadata;
     if unix::is_executable(metadata.mode) {
         Style::default()
-            .fg(THEME_T.executable.fg)
-            .bg(THEME_T.executable.bg)
-            .add_modifier(THEME_T.executable.modifier)
+            .fg(theme.executable.fg)
+            .bg(theme.executable.bg)
+            .add_modifier(theme.executable.modifier)
     } else {
         entry
             .ext()
             .and_then(|s| {
                 if config.case_insensitive_ext {
-                    THEME_T.ext.get(&s.to_lowercase())
+                    theme.ext.get(&s.to_lowercase())
                 } else {
-                    THEME_T.ext.get(s)
+                    theme.ext.get(s)
                 }
             })
             .map(|theme| {
@@ -83,3 +83,29 @@ pub fn gid_to_string(gid: u32) -> Option<String> {
         _ => None,
     }
 }
+
+/// Looks up a user by name, or takes a numeric uid as is
+pub fn string_to_uid(s: &str) -> Option<u32> {
+    use nix::unistd::User;
+
+    match s.parse::<u32>() {
+        Ok(uid) => Some(uid),
+        Err(_) => match User::from_name(s) {
+            Ok(Some(user)) => Some(user.uid.as_raw()),
+            _ => None,
+        },
+    }
+}
+
+/// Looks up a group by name, or takes a numeric gid as is
+pub fn string_to_gid(s: &str) -> Option<u32> {
+    use nix::unistd::Group;
+
+    match s.parse::<u32>() {
+        Ok(gid) => Some(gid),
+        Err(_) => match Group::from_name(s) {
+            Ok(Some(group)) => Some(group.gid.as_raw()),
+            _ => None,
+        },
+    }
+}